@@ -6,6 +6,8 @@ use core::convert::TryFrom;
 use bitfield::bitfield;
 use num_enum::TryFromPrimitive;
 
+use crate::common::RegisterInfo;
+
 macro_rules! impl_from_enum_to_bool {
     ($enum_name:ident) => {
         impl From<$enum_name> for bool {
@@ -20,9 +22,14 @@ macro_rules! impl_from_enum_to_bool {
     };
 }
 
+/// Internal oscillator frequency
+pub const INTERNAL_OSC_HZ: u32 = 512_000;
+
 /// Register map description
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Register {
     /// ID Control Register (Factory-Programmed, Read-Only)
     ID        = 0x00,
@@ -50,10 +57,58 @@ pub enum Register {
     GPIO      = 0x0B,
 }
 
+impl Register {
+    /// All registers, in address order
+    pub const ALL: [Register; 12] = [
+        Register::ID,
+        Register::CONFIG1,
+        Register::CONFIG2,
+        Register::LOFF,
+        Register::CH1SET,
+        Register::CH2SET,
+        Register::RLD_SENS,
+        Register::LOFF_SENS,
+        Register::LOFF_STAT,
+        Register::RESP1,
+        Register::RESP2,
+        Register::GPIO,
+    ];
+
+    /// Register metadata
+    pub fn info(&self) -> &'static RegisterInfo {
+        &REGISTERS[*self as usize]
+    }
+
+    /// Iterate all registers with their metadata, in address order
+    pub fn iter() -> impl Iterator<Item = (Register, &'static RegisterInfo)> {
+        Self::ALL.iter().map(|reg| (*reg, reg.info()))
+    }
+}
+
+/// Register metadata table, indexed by address
+pub static REGISTERS: [RegisterInfo; 12] = [
+    RegisterInfo::read_only("ID"),
+    RegisterInfo::read_write("CONFIG1", 0x02, 0xFF),
+    RegisterInfo::read_write("CONFIG2", 0x80, 0xFF),
+    RegisterInfo::read_write("LOFF", 0x10, 0xFF),
+    RegisterInfo::read_write("CH1SET", 0x00, 0xFF),
+    RegisterInfo::read_write("CH2SET", 0x00, 0xFF),
+    RegisterInfo::read_write("RLD_SENS", 0x00, 0xFF),
+    RegisterInfo::read_write("LOFF_SENS", 0x00, 0xFF),
+    // Only CLK_DIV writable, the rest is lead-off status
+    RegisterInfo::read_write("LOFF_STAT", 0x00, 0x40),
+    RegisterInfo::read_write("RESP1", 0x00, 0xFF),
+    RegisterInfo::read_write("RESP2", 0x02, 0xFF),
+    // GPIOD bits read the pin state
+    RegisterInfo::read_write("GPIO", 0x0F, 0x0C),
+];
+
 pub mod conf {
     use super::*;
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct Config {
         pub mode:        Mode,
         pub sample_rate: SampleRate,
@@ -69,6 +124,8 @@ pub mod conf {
     }
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum Mode {
         Continuous = 0x00,
@@ -76,6 +133,8 @@ pub mod conf {
     }
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum SampleRate {
         Sps125 = 0b000,
@@ -93,6 +152,36 @@ pub mod conf {
         }
     }
 
+    impl SampleRate {
+        /// Output data rate at nominal `fCLK` = 512 kHz
+        pub const fn as_sps(self) -> u32 {
+            match self {
+                SampleRate::Sps125 => 125,
+                SampleRate::Sps250 => 250,
+                SampleRate::Sps500 => 500,
+                SampleRate::KSps1 => 1_000,
+                SampleRate::KSps2 => 2_000,
+                SampleRate::KSps4 => 4_000,
+                SampleRate::KSps8 => 8_000,
+            }
+        }
+
+        /// Rate running at `sps` with nominal `fCLK`, `Err(sps)` when there is
+        /// none
+        pub const fn try_from_sps(sps: u32) -> Result<Self, u32> {
+            Ok(match sps {
+                125 => SampleRate::Sps125,
+                250 => SampleRate::Sps250,
+                500 => SampleRate::Sps500,
+                1_000 => SampleRate::KSps1,
+                2_000 => SampleRate::KSps2,
+                4_000 => SampleRate::KSps4,
+                8_000 => SampleRate::KSps8,
+                _ => return Err(sps),
+            })
+        }
+    }
+
     // 0x01
     bitfield! {
         /// Configuration for the register that configures each ADC channel sample rate.
@@ -130,6 +219,8 @@ pub mod conf {
 
     /// Various configurations
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct MiscConfig {
         /// Test signal frequency
         pub test_signal_freq:          TestSignalFreq,
@@ -161,6 +252,8 @@ pub mod conf {
 
     /// Test signal frequency
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum TestSignalFreq {
         /// At dc
@@ -187,6 +280,10 @@ pub mod conf {
         pub pdb_refbuf, set_pdb_refbuf: 5;
         /// Power down the lead-off comparators.
         pub pdb_loff_comp, set_pdb_loff_comp: 6;
+        /// Not used
+        ///
+        /// Must be set 1
+        pub must_set_1, _: 7;
     }
 
     impl From<MiscConfig> for Config2Reg {
@@ -206,6 +303,11 @@ pub mod conf {
         type Error = u8;
 
         fn try_from(reg: Config2Reg) -> Result<Self, Self::Error> {
+            // Cleared reserved bit hints at a stuck MISO line
+            if !reg.must_set_1() {
+                return Err(reg.0);
+            }
+
             Ok(MiscConfig {
                 test_signal_freq:          TestSignalFreq::try_from(reg.test_freq() as u8)
                     .map_err(|_| reg.0)?,
@@ -223,14 +325,32 @@ pub mod loff {
     use super::*;
 
     /// Lead-off control configuration
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct LeadOffControl {
         pub frequency:            LeadOffFreq,
         pub magnitude:            LeadOffCurrentMagnitude,
         pub comparator_threshold: LeadOffCompThreshold,
     }
 
+    /// Register reset value
+    impl Default for LeadOffControl {
+        fn default() -> Self {
+            LeadOffControl {
+                frequency:            LeadOffFreq::DC,
+                magnitude:            LeadOffCurrentMagnitude::nA_6,
+                comparator_threshold: LeadOffCompThreshold::PositiveSide(
+                    CompPositiveSide::Pct_95_5,
+                ),
+            }
+        }
+    }
+
     /// Lead-off frequency
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum LeadOffFreq {
         /// DC lead-off detection turned on
@@ -241,6 +361,8 @@ pub mod loff {
     impl_from_enum_to_bool!(LeadOffFreq);
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum LeadOffCurrentMagnitude {
         nA_6  = 0b00,
@@ -251,6 +373,8 @@ pub mod loff {
 
     /// Lead-off comparator threshold
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum LeadOffCompThreshold {
         PositiveSide(CompPositiveSide),
         NegativeSide(CompNegativeSide),
@@ -267,6 +391,8 @@ pub mod loff {
 
     /// Comparator positive side
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum CompPositiveSide {
         Pct_95_5 = 0b000,
@@ -281,6 +407,8 @@ pub mod loff {
 
     /// Comparator negative side
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum CompNegativeSide {
         Pct_5_0  = 0b000,
@@ -302,6 +430,10 @@ pub mod loff {
         pub flead_off, set_flead_off: 0;
         /// Powers down the internal reference buffer so that the external reference can be used.
         pub ilead_off, set_ilead_off: 3, 2;
+        /// Not used
+        ///
+        /// Must be set 1
+        _, set_must_set_1: 4;
         /// Power down the lead-off comparators.
         pub comp_th, set_comp_th: 7, 5;
     }
@@ -311,6 +443,7 @@ pub mod loff {
             let mut reg = LeadOffControlReg(0);
             reg.set_flead_off(param.frequency.into());
             reg.set_ilead_off(param.magnitude as u8);
+            reg.set_must_set_1(true);
             reg.set_comp_th(param.comparator_threshold.into());
             reg
         }
@@ -350,9 +483,54 @@ pub mod loff {
         /// Controls the selection of positive input from channel 1 for lead-off detection
         pub loff1p, set_loff1p: 0;
     }
+
+    /// Lead-off sense setup
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct LeadOffSense {
+        pub ch1_positive: bool,
+        pub ch1_negative: bool,
+        pub ch2_positive: bool,
+        pub ch2_negative: bool,
+        /// Flip channel 1 lead-off current direction
+        pub ch1_flip:     bool,
+        /// Flip channel 2 lead-off current direction
+        pub ch2_flip:     bool,
+    }
+
+    impl From<LeadOffSense> for LoffSense {
+        fn from(param: LeadOffSense) -> Self {
+            let mut reg = LoffSense(0);
+            reg.set_loff1p(param.ch1_positive);
+            reg.set_loff1n(param.ch1_negative);
+            reg.set_loff2p(param.ch2_positive);
+            reg.set_loff2n(param.ch2_negative);
+            reg.set_flip1(param.ch1_flip);
+            reg.set_flip2(param.ch2_flip);
+            reg
+        }
+    }
+
+    impl TryFrom<LoffSense> for LeadOffSense {
+        type Error = u8;
+
+        fn try_from(reg: LoffSense) -> Result<Self, Self::Error> {
+            Ok(LeadOffSense {
+                ch1_positive: reg.loff1p(),
+                ch1_negative: reg.loff1n(),
+                ch2_positive: reg.loff2p(),
+                ch2_negative: reg.loff2n(),
+                ch1_flip:     reg.flip1(),
+                ch2_flip:     reg.flip2(),
+            })
+        }
+    }
     
     // Lead-Off status
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct LeadOffStatus {
         pub ch1_positive_leadoff: bool,
         pub ch1_negative_leadoff: bool,
@@ -378,13 +556,43 @@ pub mod loff {
     
     /// Clock divider selection
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum ClkDiv {
+        /// `fMOD = fCLK / 4`, for the 512 kHz internal oscillator
         Div4 = 0x00,
+        /// `fMOD = fCLK / 16`, for a 2.048 MHz external clock
         Div16 = 0x01,
     }
     impl_from_enum_to_bool!(ClkDiv);
 
+    impl ClkDiv {
+        /// Division ratio `fCLK / fMOD`
+        pub const fn ratio(self) -> u32 {
+            match self {
+                ClkDiv::Div4 => 4,
+                ClkDiv::Div16 => 16,
+            }
+        }
+
+        /// Modulator clock `fMOD` resulting from `fclk_hz`, if within [`FMOD_RANGE_HZ`]
+        pub fn modulator_clock(self, fclk_hz: u32) -> Option<u32> {
+            let fmod_hz = fclk_hz / self.ratio();
+            let (min_hz, max_hz) = FMOD_RANGE_HZ;
+            if fmod_hz >= min_hz && fmod_hz <= max_hz {
+                Some(fmod_hz)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Supported modulator clock range, 128 kHz nominal
+    ///
+    /// Derived from the 1.94 MHz to 2.25 MHz external clock range divided by 16.
+    pub const FMOD_RANGE_HZ: (u32, u32) = (121_250, 140_625);
+
     // 0x08
     bitfield! {
         /// Lead-Off Status register
@@ -435,6 +643,8 @@ pub mod chan {
 
     /// Individual channel settings
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum Chan {
         PowerUp {
             input: ChannelInput,
@@ -453,6 +663,8 @@ pub mod chan {
     }
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum ChannelInput {
         /// Normal electrode input (default)
@@ -478,18 +690,7 @@ pub mod chan {
         Channel3          = 0b1001,
     }
 
-    /// PGA gain
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
-    #[repr(u8)]
-    pub enum ChannelGain {
-        X6  = 0b000,
-        X1  = 0b001,
-        X2  = 0b010,
-        X3  = 0b011,
-        X4  = 0b100,
-        X8  = 0b101,
-        X12 = 0b110,
-    }
+    pub use crate::common::ChannelGain;
 
     // 0x04-0x05
     bitfield! {
@@ -542,6 +743,8 @@ pub mod resp {
     use super::*;
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct Resp1 {
         pub clock:               RespClock,
         pub phase:               RespPhase,
@@ -561,6 +764,8 @@ pub mod resp {
     }
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum RespClock {
         Internal = 0x00,
@@ -569,6 +774,8 @@ pub mod resp {
     impl_from_enum_to_bool!(RespClock);
 
     #[derive(Debug, Clone, Copy, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum RespPhase {
         RespPhase32kHz(RespPhase32kHz),
@@ -591,6 +798,8 @@ pub mod resp {
     }
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum RespPhase32kHz {
         Deg_0      = 0b0000,
@@ -612,6 +821,8 @@ pub mod resp {
     }
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum RespPhase64kHz {
         Deg_0     = 0b0000,
@@ -718,6 +929,10 @@ pub mod resp {
         /// Configuration for the register that controls the respiration and calibration functionality.
         pub struct RespControl2Reg(u8);
         impl Debug;
+        /// Not used
+        ///
+        /// Must be set 1
+        _, set_must_set_1: 0;
         /// Determines the RLDREF signal source.
         /// Can be fed externally (false : 0) or internally by using (AVDD – AVSS) / 2 (true : 1).
         pub rldref_int, set_rldref_int: 1;
@@ -728,9 +943,130 @@ pub mod resp {
         /// Enables offset calibration
         pub calib_on, set_calib_on: 7;
     }
+
+    /// Respiration and calibration control
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Resp2 {
+        /// RLDREF from `(AVDD - AVSS) / 2` instead of the external pin
+        pub rldref_internal:           bool,
+        /// 64 kHz respiration clock, must be set on the ADS1291 and ADS1292
+        pub resp_freq_64khz:           bool,
+        pub offset_calibration_enable: bool,
+    }
+
+    impl Default for Resp2 {
+        fn default() -> Self {
+            Resp2 {
+                rldref_internal:           true,
+                resp_freq_64khz:           false,
+                offset_calibration_enable: false,
+            }
+        }
+    }
+
+    impl From<Resp2> for RespControl2Reg {
+        fn from(param: Resp2) -> Self {
+            let mut reg = RespControl2Reg(0x00);
+            reg.set_must_set_1(true);
+            reg.set_rldref_int(param.rldref_internal);
+            reg.set_resp_freq_64khz(param.resp_freq_64khz);
+            reg.set_calib_on(param.offset_calibration_enable);
+            reg
+        }
+    }
+
+    impl TryFrom<RespControl2Reg> for Resp2 {
+        type Error = u8;
+
+        fn try_from(reg: RespControl2Reg) -> Result<Self, Self::Error> {
+            Ok(Resp2 {
+                rldref_internal:           reg.rldref_int(),
+                resp_freq_64khz:           reg.resp_freq_64khz(),
+                offset_calibration_enable: reg.calib_on(),
+            })
+        }
+    }
+
+    /// Respiration clock frequency
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum RespFrequency {
+        KHz32,
+        /// Required on the ADS1291 and ADS1292
+        KHz64,
+    }
+
+    /// Whole ADS1292R respiration setup, see `configure_respiration`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct RespirationSetup {
+        pub clock:               RespClock,
+        /// Internal clock frequency, `RESP2` RESP_FREQ
+        pub frequency:           RespFrequency,
+        /// Demodulation phase, from the table of `frequency`
+        pub phase:               RespPhase,
+        pub modulation_enable:   bool,
+        pub demodulation_enable: bool,
+        /// Channel 1 setting, the respiration channel
+        pub channel:             chan::Chan,
+    }
+
+    impl Default for RespirationSetup {
+        fn default() -> Self {
+            RespirationSetup {
+                clock:               RespClock::Internal,
+                frequency:           RespFrequency::KHz32,
+                phase:               RespPhase::RespPhase32kHz(RespPhase32kHz::Deg_0),
+                modulation_enable:   false,
+                demodulation_enable: false,
+                channel:             chan::Chan::default(),
+            }
+        }
+    }
+
+    impl RespirationSetup {
+        /// Reason the silicon can't run the setup
+        pub(crate) fn check(&self) -> Result<(), &'static str> {
+            match (self.frequency, self.phase) {
+                (RespFrequency::KHz32, RespPhase::RespPhase32kHz(_))
+                | (RespFrequency::KHz64, RespPhase::RespPhase64kHz(_)) => Ok(()),
+                _ => Err("respiration phase of the other clock frequency"),
+            }
+        }
+
+        /// Reassemble from `RESP1`, `RESP2` and `CH1SET`
+        pub(crate) fn from_regs(
+            resp1: RespControl1Reg,
+            resp2: RespControl2Reg,
+            channel: chan::Chan,
+        ) -> Result<Self, u8> {
+            let (frequency, phase) = if resp2.resp_freq_64khz() {
+                let phase = RespPhase64kHz::try_from(resp1.resp_ph()).map_err(|_| resp1.0)?;
+                (RespFrequency::KHz64, RespPhase::RespPhase64kHz(phase))
+            } else {
+                let phase = RespPhase32kHz::try_from(resp1.resp_ph()).map_err(|_| resp1.0)?;
+                (RespFrequency::KHz32, RespPhase::RespPhase32kHz(phase))
+            };
+            Ok(RespirationSetup {
+                clock: RespClock::try_from(resp1.resp_ctrl() as u8).map_err(|_| resp1.0)?,
+                frequency,
+                phase,
+                modulation_enable: resp1.resp_mod_en(),
+                demodulation_enable: resp1.resp_demod_en(),
+                channel,
+            })
+        }
+    }
 }
 
-#[derive(Debug)]
+/// PGA chop frequency
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum ChopFrequency {
     FmodDiv16 = 0b00,
@@ -759,3 +1095,199 @@ bitfield! {
     /// Controls the selection of positive inputs from channel 1 for right leg drive derivation.
     pub rld1p, set_rld1p: 0;
 }
+
+pub mod rld {
+    use super::*;
+
+    /// RLD_SENS register, alias matching the other `*Reg` bitfields
+    pub use super::RLDSenseSelection as RldSenseReg;
+
+    /// Right leg drive derivation setup
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct RldSense {
+        pub chop:              ChopFrequency,
+        /// RLD buffer powered up
+        pub rld_buffer_enable: bool,
+        /// RLD lead-off sense enabled
+        pub rld_loff_sense:    bool,
+        pub ch1_positive:      bool,
+        pub ch1_negative:      bool,
+        pub ch2_positive:      bool,
+        pub ch2_negative:      bool,
+    }
+
+    impl Default for RldSense {
+        fn default() -> Self {
+            RldSense {
+                chop:              ChopFrequency::FmodDiv16,
+                rld_buffer_enable: false,
+                rld_loff_sense:    false,
+                ch1_positive:      false,
+                ch1_negative:      false,
+                ch2_positive:      false,
+                ch2_negative:      false,
+            }
+        }
+    }
+
+    impl From<RldSense> for RLDSenseSelection {
+        fn from(param: RldSense) -> Self {
+            let mut reg = RLDSenseSelection(0);
+            reg.set_chop(param.chop as u8);
+            reg.set_pbd_rld(param.rld_buffer_enable);
+            reg.set_rld_loff_sense(param.rld_loff_sense);
+            reg.set_rld2n(param.ch2_negative);
+            reg.set_rld2p(param.ch2_positive);
+            reg.set_rld1n(param.ch1_negative);
+            reg.set_rld1p(param.ch1_positive);
+            reg
+        }
+    }
+
+    impl TryFrom<RLDSenseSelection> for RldSense {
+        type Error = u8;
+
+        fn try_from(reg: RLDSenseSelection) -> Result<Self, Self::Error> {
+            let chop = match ChopFrequency::try_from(reg.chop()) {
+                Ok(ChopFrequency::Unknown) | Err(_) => return Err(reg.0),
+                Ok(chop) => chop,
+            };
+
+            Ok(RldSense {
+                chop,
+                rld_buffer_enable: reg.pdb_rld(),
+                rld_loff_sense: reg.rld_loff_sense(),
+                ch1_positive: reg.rld1p(),
+                ch1_negative: reg.rld1n(),
+                ch2_positive: reg.rld2p(),
+                ch2_negative: reg.rld2n(),
+            })
+        }
+    }
+}
+
+pub mod gpio {
+    use super::*;
+
+    /// GPIO configuration
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Gpio {
+        pub mode: [GpioMode; 2],
+        pub data: [bool; 2],
+    }
+
+    impl Default for Gpio {
+        fn default() -> Self {
+            Gpio {
+                mode: [GpioMode::Input; 2],
+                data: [false; 2],
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[repr(u8)]
+    pub enum GpioMode {
+        Output = 0b0,
+        Input  = 0b1,
+    }
+    impl_from_enum_to_bool!(GpioMode);
+
+    // 0x0B
+    bitfield! {
+        /// GPIO: General-Purpose I/O Register
+        ///
+        /// Controls the two GPIO pins, bits [7:4] must be written 0.
+        ///
+        pub struct GpioReg(u8);
+        impl Debug;
+
+        /// GPIO data
+        ///
+        /// Reads return the pin state whether programmed as input or output, a
+        /// write sets the output value and has no effect on inputs.
+        ///
+        pub gpiod1, set_gpiod1 : 0;
+        pub gpiod2, set_gpiod2 : 1;
+
+        /// GPIO control (corresponding GPIOD)
+        ///
+        /// These bits determine if the corresponding GPIOD pin is an input or output.
+        ///
+        ///   - 0 = Output
+        ///   - 1 = Input
+        ///
+        pub gpioc1, set_gpioc1 : 2;
+        pub gpioc2, set_gpioc2 : 3;
+    }
+
+    impl From<Gpio> for GpioReg {
+        fn from(param: Gpio) -> Self {
+            let mut reg = GpioReg(0);
+            reg.set_gpioc1(param.mode[0].into());
+            reg.set_gpioc2(param.mode[1].into());
+
+            reg.set_gpiod1(param.data[0]);
+            reg.set_gpiod2(param.data[1]);
+            reg
+        }
+    }
+
+    impl TryFrom<GpioReg> for Gpio {
+        type Error = u8;
+
+        fn try_from(reg: GpioReg) -> Result<Self, Self::Error> {
+            Ok(Gpio {
+                mode: [
+                    GpioMode::try_from(reg.gpioc1() as u8).map_err(|_| reg.0)?,
+                    GpioMode::try_from(reg.gpioc2() as u8).map_err(|_| reg.0)?,
+                ],
+                data: [reg.gpiod1(), reg.gpiod2()],
+            })
+        }
+    }
+}
+
+/// Complete device configuration
+///
+/// Covers every writable register, applied with `apply_config` in a single
+/// burst. `LOFF_STAT` only takes `clk_div`, its other bits are status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceConfig {
+    pub config:          conf::Config,
+    pub misc:            conf::MiscConfig,
+    pub leadoff_control: loff::LeadOffControl,
+    pub channels:        [chan::Chan; 2],
+    pub rld_sense:       rld::RldSense,
+    pub leadoff_sense:   loff::LeadOffSense,
+    pub clk_div:         loff::ClkDiv,
+    pub resp:            resp::Resp1,
+    pub resp2:           resp::Resp2,
+    pub gpio:            gpio::Gpio,
+}
+
+/// Register reset values, bits the types don't model are left cleared
+impl Default for DeviceConfig {
+    fn default() -> Self {
+        DeviceConfig {
+            config:          conf::Config::default(),
+            misc:            conf::MiscConfig::default(),
+            leadoff_control: loff::LeadOffControl::default(),
+            channels:        [chan::Chan::default(); 2],
+            rld_sense:       rld::RldSense::default(),
+            leadoff_sense:   loff::LeadOffSense::default(),
+            clk_div:         loff::ClkDiv::Div4,
+            resp:            resp::Resp1::default(),
+            resp2:           resp::Resp2::default(),
+            gpio:            gpio::Gpio::default(),
+        }
+    }
+}
@@ -5,6 +5,8 @@ use core::convert::TryFrom;
 use bitfield::bitfield;
 use num_enum::TryFromPrimitive;
 
+use crate::common::RegisterInfo;
+
 macro_rules! impl_from_enum_to_bool {
     ($enum_name:ident) => {
         impl From<$enum_name> for bool {
@@ -22,6 +24,8 @@ macro_rules! impl_from_enum_to_bool {
 /// Register map description
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Register {
     /// ID Control Register (Factory-Programmed, Read-Only)
     ID         = 0x00,
@@ -80,11 +84,88 @@ pub enum Register {
     WCT2       = 0x19,
 }
 
+impl Register {
+    /// All registers, in address order
+    pub const ALL: [Register; 26] = [
+        Register::ID,
+        Register::CONFIG1,
+        Register::CONFIG2,
+        Register::CONFIG3,
+        Register::LOFF,
+        Register::CH1SET,
+        Register::CH2SET,
+        Register::CH3SET,
+        Register::CH4SET,
+        Register::CH5SET,
+        Register::CH6SET,
+        Register::CH7SET,
+        Register::CH8SET,
+        Register::RLD_SENSP,
+        Register::RLD_SENSN,
+        Register::LOFF_SENSP,
+        Register::LOFF_SENSN,
+        Register::LOFF_FLIP,
+        Register::LOFF_STATP,
+        Register::LOFF_STATN,
+        Register::GPIO,
+        Register::PACE,
+        Register::RESP,
+        Register::CONFIG4,
+        Register::WCT1,
+        Register::WCT2,
+    ];
+
+    /// Register metadata
+    pub fn info(&self) -> &'static RegisterInfo {
+        &REGISTERS[*self as usize]
+    }
+
+    /// Iterate all registers with their metadata, in address order
+    pub fn iter() -> impl Iterator<Item = (Register, &'static RegisterInfo)> {
+        Self::ALL.iter().map(|reg| (*reg, reg.info()))
+    }
+}
+
+/// Register metadata table, indexed by address
+pub static REGISTERS: [RegisterInfo; 26] = [
+    RegisterInfo::read_only("ID"),
+    RegisterInfo::read_write("CONFIG1", 0x06, 0xFF),
+    RegisterInfo::read_write("CONFIG2", 0x40, 0xFF),
+    // RLD_STAT reads the RLD lead-off state
+    RegisterInfo::read_write("CONFIG3", 0x40, 0xFE),
+    RegisterInfo::read_write("LOFF", 0x00, 0xFF),
+    RegisterInfo::read_write("CH1SET", 0x00, 0xFF),
+    RegisterInfo::read_write("CH2SET", 0x00, 0xFF),
+    RegisterInfo::read_write("CH3SET", 0x00, 0xFF),
+    RegisterInfo::read_write("CH4SET", 0x00, 0xFF),
+    RegisterInfo::read_write("CH5SET", 0x00, 0xFF),
+    RegisterInfo::read_write("CH6SET", 0x00, 0xFF),
+    RegisterInfo::read_write("CH7SET", 0x00, 0xFF),
+    RegisterInfo::read_write("CH8SET", 0x00, 0xFF),
+    RegisterInfo::read_write("RLD_SENSP", 0x00, 0xFF),
+    RegisterInfo::read_write("RLD_SENSN", 0x00, 0xFF),
+    RegisterInfo::read_write("LOFF_SENSP", 0x00, 0xFF),
+    RegisterInfo::read_write("LOFF_SENSN", 0x00, 0xFF),
+    RegisterInfo::read_write("LOFF_FLIP", 0x00, 0xFF),
+    RegisterInfo::read_only("LOFF_STATP"),
+    RegisterInfo::read_only("LOFF_STATN"),
+    // GPIOD bits read the pin state
+    RegisterInfo::read_write("GPIO", 0x0F, 0x0F),
+    RegisterInfo::read_write("PACE", 0x00, 0xFF),
+    RegisterInfo::read_write("RESP", 0x00, 0xFF),
+    RegisterInfo::read_write("CONFIG4", 0x00, 0xFF),
+    RegisterInfo::read_write("WCT1", 0x00, 0xFF),
+    RegisterInfo::read_write("WCT2", 0x00, 0xFF),
+];
+
 pub mod conf {
     use super::*;
+    use crate::timing::{SclkError, SclkParams};
 
     /// Basic device configuration
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct Config {
         /// Device mode
         pub mode:             Mode,
@@ -104,8 +185,74 @@ pub mod conf {
         }
     }
 
+    impl Config {
+        /// Check the data readback SCLK frequency against the configured data
+        /// rate
+        ///
+        /// `daisy_depth` is the number of devices sharing the frame read, it is
+        /// ignored unless daisy-chain mode is enabled. Register access needs
+        /// SCLK at or below [`SclkParams::command_sclk_max_hz`].
+        pub fn validate_sclk(
+            &self,
+            sclk_hz: u32,
+            fclk_hz: u32,
+            channels: usize,
+            daisy_depth: usize,
+        ) -> Result<(), SclkError> {
+            SclkParams {
+                fclk_hz,
+                data_rate_sps: self.mode.as_sps(),
+                channels,
+                daisy_depth: if self.daisy_chain { daisy_depth.max(1) } else { 1 },
+            }
+            .validate(sclk_hz)
+        }
+    }
+
+    /// Multi-device data readout topology, `CONFIG1` DAISY_EN
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum ReadbackMode {
+        /// DOUT shifts through DAISY_IN of the next device, one nCS for all
+        DaisyChain,
+        /// DOUTs share a line, each device is read on its own nCS assertion
+        MultipleReadback,
+    }
+
+    /// Clocking of a device in a daisy chain
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum DaisyRole {
+        /// Runs from its oscillator and drives the CLK pin of the others
+        ClockMaster,
+        /// Clocked from the master's CLK output, clock output disabled
+        Downstream,
+    }
+
+    impl Config {
+        /// Readout topology selected by `daisy_chain`
+        pub fn readback_mode(&self) -> ReadbackMode {
+            match self.daisy_chain {
+                true => ReadbackMode::DaisyChain,
+                false => ReadbackMode::MultipleReadback,
+            }
+        }
+    }
+
+    /// Exactly one device of a multi-device design drives its oscillator on the CLK pin
+    ///
+    /// The others must be clocked from it (CLKSEL = 0), two enabled outputs would fight on the
+    /// shared clock line and none leaves the downstream devices without clock.
+    pub fn single_clock_source(configs: &[Config]) -> bool {
+        configs.iter().filter(|c| c.osc_clock_output).count() == 1
+    }
+
     /// Device mode
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum Mode {
         HighResolution(SampleRateHR),
         LowPower(SampleRateLP),
@@ -117,8 +264,20 @@ pub mod conf {
         }
     }
 
+    impl Mode {
+        /// Output data rate at nominal `fCLK` = 2.048 MHz
+        pub const fn as_sps(self) -> u32 {
+            match self {
+                Mode::HighResolution(rate) => rate.as_sps(),
+                Mode::LowPower(rate) => rate.as_sps(),
+            }
+        }
+    }
+
     /// Sample rate in high-resolution mode
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum SampleRateHR {
         KSps32 = 0b000,
@@ -130,8 +289,40 @@ pub mod conf {
         Sps500 = 0b110,
     }
 
+    impl SampleRateHR {
+        /// Output data rate at nominal `fCLK` = 2.048 MHz
+        pub const fn as_sps(self) -> u32 {
+            match self {
+                SampleRateHR::KSps32 => 32_000,
+                SampleRateHR::KSps16 => 16_000,
+                SampleRateHR::Sps8k => 8_000,
+                SampleRateHR::Sps4k => 4_000,
+                SampleRateHR::Sps2k => 2_000,
+                SampleRateHR::Sps1k => 1_000,
+                SampleRateHR::Sps500 => 500,
+            }
+        }
+
+        /// Rate running at `sps` with nominal `fCLK`, `Err(sps)` when there is
+        /// none
+        pub const fn try_from_sps(sps: u32) -> Result<Self, u32> {
+            Ok(match sps {
+                32_000 => SampleRateHR::KSps32,
+                16_000 => SampleRateHR::KSps16,
+                8_000 => SampleRateHR::Sps8k,
+                4_000 => SampleRateHR::Sps4k,
+                2_000 => SampleRateHR::Sps2k,
+                1_000 => SampleRateHR::Sps1k,
+                500 => SampleRateHR::Sps500,
+                _ => return Err(sps),
+            })
+        }
+    }
+
     /// Sample rate in low power mode
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum SampleRateLP {
         KSps16 = 0b000,
@@ -143,6 +334,36 @@ pub mod conf {
         Sps250 = 0b110,
     }
 
+    impl SampleRateLP {
+        /// Output data rate at nominal `fCLK` = 2.048 MHz
+        pub const fn as_sps(self) -> u32 {
+            match self {
+                SampleRateLP::KSps16 => 16_000,
+                SampleRateLP::KSps8 => 8_000,
+                SampleRateLP::KSps4 => 4_000,
+                SampleRateLP::KSps2 => 2_000,
+                SampleRateLP::KSps1 => 1_000,
+                SampleRateLP::Sps500 => 500,
+                SampleRateLP::Sps250 => 250,
+            }
+        }
+
+        /// Rate running at `sps` with nominal `fCLK`, `Err(sps)` when there is
+        /// none
+        pub const fn try_from_sps(sps: u32) -> Result<Self, u32> {
+            Ok(match sps {
+                16_000 => SampleRateLP::KSps16,
+                8_000 => SampleRateLP::KSps8,
+                4_000 => SampleRateLP::KSps4,
+                2_000 => SampleRateLP::KSps2,
+                1_000 => SampleRateLP::KSps1,
+                500 => SampleRateLP::Sps500,
+                250 => SampleRateLP::Sps250,
+                _ => return Err(sps),
+            })
+        }
+    }
+
     // 0x01
     bitfield! {
         /// Configuration Register 1
@@ -218,6 +439,8 @@ pub mod conf {
 
     /// Test signal configuration
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct TestSignalConfig {
         /// Test signal frequency
         pub frequency: TestSignalFreq,
@@ -242,6 +465,8 @@ pub mod conf {
 
     /// Test signal frequency settings
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum TestSignalFreq {
         /// Pulsed at `fCLK` / 2**21
@@ -256,6 +481,8 @@ pub mod conf {
 
     /// Test signal amplitude settings
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum TestSignalAmp {
         /// 1 × –(`VREFP`– `VREFN`)/ 2400V
@@ -267,6 +494,8 @@ pub mod conf {
 
     /// Test signal source
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum TestSignalSource {
         /// Test signals are driven externally
@@ -278,6 +507,8 @@ pub mod conf {
 
     /// WCT chopping scheme
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum WctChoppingFreq {
         /// Chopping frequency varies, see datasheet.
@@ -361,6 +592,8 @@ pub mod conf {
     /// Configures multireference and RLD operation
     #[allow(non_snake_case)]
     #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct RldConfig {
         /// RLD lead-off status
         ///
@@ -392,6 +625,8 @@ pub mod conf {
 
     /// Determines the `RLDREF` signal source
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum RldRefSource {
         /// `RLDREF` signal fed externally
@@ -519,6 +754,8 @@ pub mod conf {
 
     /// Various configurations
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct MiscConfig {
         /// Lead-off comparator enable
         pub leadoff_comparator_enable: bool,
@@ -540,6 +777,8 @@ pub mod conf {
 
     /// Respiration modulation frequency
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum ResperationFreq {
         /// 64 kHz modulation clock
@@ -652,6 +891,8 @@ pub mod chan {
 
     /// Individual channel settings
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum Chan {
         PowerUp {
             input: ChannelInput,
@@ -671,6 +912,8 @@ pub mod chan {
 
     /// Channel Input
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum ChannelInput {
         /// Normal electrode input
@@ -691,18 +934,7 @@ pub mod chan {
         RldDrn  = 0b111,
     }
 
-    /// PGA gain
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
-    #[repr(u8)]
-    pub enum ChannelGain {
-        X6  = 0b000,
-        X1  = 0b001,
-        X2  = 0b010,
-        X3  = 0b011,
-        X4  = 0b100,
-        X8  = 0b101,
-        X12 = 0b110,
-    }
+    pub use crate::common::ChannelGain;
 
     bitfield! {
         /// Individual channel settings
@@ -793,6 +1025,8 @@ pub mod loff {
 
     /// Lead-off control configuration
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct LeadOffControl {
         pub frequency:            LeadOffFreq,
         pub magnitude:            LeadOffMagnitude,
@@ -815,6 +1049,8 @@ pub mod loff {
 
     /// Lead-off frequency
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum LeadOffFreq {
         /// Default value
@@ -829,6 +1065,8 @@ pub mod loff {
 
     /// Lead-off current magnitude
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum LeadOffMagnitude {
         nA_6  = 0b00,
@@ -840,6 +1078,8 @@ pub mod loff {
     /// Lead-off detection mode
     #[repr(u8)]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum LeadOffDetectMode {
         CurrentSource = 0b0,
         PullUpDown    = 0b1,
@@ -848,6 +1088,8 @@ pub mod loff {
 
     /// Lead-off comparator threshold
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum LeadOffCompThreshold {
         PositiveSide(CompPositiveSide),
         NegativeSide(CompNegativeSide),
@@ -864,6 +1106,8 @@ pub mod loff {
 
     /// Comparator positive side
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum CompPositiveSide {
         Pct_95_5 = 0b000,
@@ -878,6 +1122,8 @@ pub mod loff {
 
     /// Comparator negative side
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum CompNegativeSide {
         Pct_5_0  = 0b000,
@@ -982,6 +1228,8 @@ pub mod loff {
 
     /// Lead-off sense setup
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct LeadOffSense {
         pub ch1_enable: bool,
         pub ch2_enable: bool,
@@ -1065,6 +1313,8 @@ pub mod loff {
 
     /// Controls the direction of the current used for lead-off derivation
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct LeadOffFlip {
         /// Channel N polarity flip
         pub ch1_flip: bool,
@@ -1150,6 +1400,204 @@ pub mod loff {
             })
         }
     }
+
+    /// Whole lead-off detection setup, see `enable_leadoff`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct LeadOffSetup {
+        /// Current magnitude, AC/DC mode and comparator threshold
+        pub control:            LeadOffControl,
+        /// Sensed `INxP` electrodes
+        pub sense_positive:     LeadOffSense,
+        /// Sensed `INxN` electrodes
+        pub sense_negative:     LeadOffSense,
+        pub flip:               LeadOffFlip,
+        /// Lead-off comparators powered, `CONFIG4` PD_LOFF_COMP
+        pub comparators_enable: bool,
+        /// RLD electrode sensed, `CONFIG3` RLD_LOFF_SENS
+        pub rld_sense:          bool,
+    }
+
+    impl LeadOffSetup {
+        /// Reason the setup can't detect anything on a `channels` device
+        pub(crate) fn check(&self, channels: usize) -> Result<(), &'static str> {
+            let positive = LeadOffSenseReg::from(self.sense_positive).0;
+            let negative = LeadOffSenseReg::from(self.sense_negative).0;
+            if (positive | negative) as u16 >> channels != 0 {
+                return Err("lead-off sense on a missing channel");
+            }
+            if LeadOffFlipReg::from(self.flip).0 as u16 >> channels != 0 {
+                return Err("lead-off flip on a missing channel");
+            }
+
+            let sensed = positive | negative != 0 || self.rld_sense;
+            if sensed && !self.comparators_enable {
+                return Err("lead-off sense with comparators powered down");
+            }
+            if sensed && !matches!(self.control.frequency, LeadOffFreq::AC | LeadOffFreq::DC) {
+                return Err("lead-off sense without AC or DC detection");
+            }
+            Ok(())
+        }
+    }
+
+    /// Electrode lead-off detection result
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum ElectrodeState {
+        Connected,
+        Disconnected,
+        /// Sensing not enabled, or not reported by the source
+        #[default]
+        Unmonitored,
+    }
+
+    impl ElectrodeState {
+        fn new(sensed: bool, off: bool) -> Self {
+            match (sensed, off) {
+                (false, _) => ElectrodeState::Unmonitored,
+                (true, false) => ElectrodeState::Connected,
+                (true, true) => ElectrodeState::Disconnected,
+            }
+        }
+    }
+
+    /// Connection of every electrode
+    ///
+    /// Entries past the device's channel count are always `Unmonitored`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct LeadOffState {
+        /// `INxP` electrodes, channel 1 first
+        pub positive: [ElectrodeState; 8],
+        /// `INxN` electrodes, channel 1 first
+        pub negative: [ElectrodeState; 8],
+        pub rld:      ElectrodeState,
+    }
+
+    impl LeadOffState {
+        /// From the lead-off flags of a frame status word
+        ///
+        /// The status word carries no RLD flag, `rld` is always
+        /// `Unmonitored`.
+        pub fn from_status(status: &crate::data::Status, setup: &LeadOffSetup) -> Self {
+            Self::from_regs(
+                LeadOffSenseReg::from(setup.sense_positive).0,
+                LeadOffSenseReg::from(setup.sense_negative).0,
+                status.leadoff_positive,
+                status.leadoff_negative,
+                (false, false),
+            )
+        }
+
+        /// From `LOFF_SENSP/N`, the `LOFF_STATP/N` flags and `(RLD_LOFF_SENS,
+        /// RLD_STAT)`
+        pub(crate) fn from_regs(
+            sense_positive: u8,
+            sense_negative: u8,
+            off_positive: [bool; 8],
+            off_negative: [bool; 8],
+            rld: (bool, bool),
+        ) -> Self {
+            let mut state = LeadOffState {
+                rld: ElectrodeState::new(rld.0, rld.1),
+                ..Default::default()
+            };
+            for ch in 0..8 {
+                state.positive[ch] =
+                    ElectrodeState::new(sense_positive & 1 << ch != 0, off_positive[ch]);
+                state.negative[ch] =
+                    ElectrodeState::new(sense_negative & 1 << ch != 0, off_negative[ch]);
+            }
+            state
+        }
+
+        /// No sensed electrode is off
+        pub fn all_connected(&self) -> bool {
+            self.positive
+                .iter()
+                .chain(&self.negative)
+                .chain(core::iter::once(&self.rld))
+                .all(|&state| state != ElectrodeState::Disconnected)
+        }
+    }
+}
+
+pub mod rld {
+    use super::*;
+
+    /// Right leg drive derivation setup
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct RldSense {
+        pub ch1_enable: bool,
+        pub ch2_enable: bool,
+        pub ch3_enable: bool,
+        pub ch4_enable: bool,
+        pub ch5_enable: bool,
+        pub ch6_enable: bool,
+        pub ch7_enable: bool,
+        pub ch8_enable: bool,
+    }
+
+    // 0x0D-0x0E
+    bitfield! {
+        /// RLD_SENSP/N : Positive/Negative Signal Derivation Register
+        pub struct RldSenseReg(u8);
+        impl Debug;
+
+        /// INxP/N to RLD
+        ///
+        /// Route INxP/N into the RLD derivation
+        ///
+        ///   - 0: Disabled
+        ///   - 1: Enabled
+        ///
+        pub rld1, set_rld1 : 0;
+        pub rld2, set_rld2 : 1;
+        pub rld3, set_rld3 : 2;
+        pub rld4, set_rld4 : 3;
+        pub rld5, set_rld5 : 4;
+        pub rld6, set_rld6 : 5;
+        pub rld7, set_rld7 : 6;
+        pub rld8, set_rld8 : 7;
+    }
+
+    impl From<RldSense> for RldSenseReg {
+        fn from(param: RldSense) -> Self {
+            let mut reg = RldSenseReg(0);
+            reg.set_rld1(param.ch1_enable);
+            reg.set_rld2(param.ch2_enable);
+            reg.set_rld3(param.ch3_enable);
+            reg.set_rld4(param.ch4_enable);
+            reg.set_rld5(param.ch5_enable);
+            reg.set_rld6(param.ch6_enable);
+            reg.set_rld7(param.ch7_enable);
+            reg.set_rld8(param.ch8_enable);
+            reg
+        }
+    }
+
+    impl TryFrom<RldSenseReg> for RldSense {
+        type Error = u8;
+
+        fn try_from(reg: RldSenseReg) -> Result<Self, Self::Error> {
+            Ok(RldSense {
+                ch1_enable: reg.rld1(),
+                ch2_enable: reg.rld2(),
+                ch3_enable: reg.rld3(),
+                ch4_enable: reg.rld4(),
+                ch5_enable: reg.rld5(),
+                ch6_enable: reg.rld6(),
+                ch7_enable: reg.rld7(),
+                ch8_enable: reg.rld8(),
+            })
+        }
+    }
 }
 
 pub mod gpio {
@@ -1157,6 +1605,8 @@ pub mod gpio {
 
     /// GPIO configuration
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct Gpio {
         pub mode: [GpioMode; 4],
         pub data: [bool; 4],
@@ -1172,6 +1622,8 @@ pub mod gpio {
     }
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum GpioMode {
         Output = 0b0,
@@ -1247,3 +1699,562 @@ pub mod gpio {
         }
     }
 }
+
+pub mod pace {
+    use super::*;
+
+    /// Pace detect buffer routing
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Pace {
+        pub even_channel:  PaceEvenChannel,
+        pub odd_channel:   PaceOddChannel,
+        /// Pace detect buffer powered up
+        pub buffer_enable: bool,
+    }
+
+    impl Default for Pace {
+        fn default() -> Self {
+            Pace {
+                even_channel:  PaceEvenChannel::Ch2,
+                odd_channel:   PaceOddChannel::Ch1,
+                buffer_enable: false,
+            }
+        }
+    }
+
+    /// Channel routed to `TEST_PACE_OUT1`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[repr(u8)]
+    pub enum PaceEvenChannel {
+        Ch2 = 0b00,
+        Ch4 = 0b01,
+        Ch6 = 0b10,
+        Ch8 = 0b11,
+    }
+
+    /// Channel routed to `TEST_PACE_OUT2`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[repr(u8)]
+    pub enum PaceOddChannel {
+        Ch1 = 0b00,
+        Ch3 = 0b01,
+        Ch5 = 0b10,
+        Ch7 = 0b11,
+    }
+
+    // 0x15
+    bitfield! {
+        /// PACE: Pace Detect Register
+        ///
+        /// This register provides the PACE controls that configure the channel signal used to feed
+        /// the external PACE detect circuitry.
+        ///
+        pub struct PaceReg(u8);
+        impl Debug;
+
+        /// Pace detect buffer
+        ///
+        /// This bit is used to enable/disable the pace detect buffer.
+        ///
+        ///   - 0 = Pace detect buffer turned off
+        ///   - 1 = Pace detect buffer turned on
+        ///
+        pub pd_pace, set_pd_pace : 0;
+
+        /// PACE odd channels
+        ///
+        /// These bits control the selection of the odd number channels available on
+        /// `TEST_PACE_OUT2`. Note that only one channel may be selected at any time.
+        ///
+        ///   - 00 = Channel 1
+        ///   - 01 = Channel 3
+        ///   - 10 = Channel 5
+        ///   - 11 = Channel 7
+        ///
+        pub paceo, set_paceo : 2, 1;
+
+        /// PACE even channels
+        ///
+        /// These bits control the selection of the even number channels available on
+        /// `TEST_PACE_OUT1`. Note that only one channel may be selected at any time.
+        ///
+        ///   - 00 = Channel 2
+        ///   - 01 = Channel 4
+        ///   - 10 = Channel 6
+        ///   - 11 = Channel 8
+        ///
+        pub pacee, set_pacee : 4, 3;
+    }
+
+    impl From<Pace> for PaceReg {
+        fn from(param: Pace) -> Self {
+            let mut reg = PaceReg(0);
+            reg.set_pd_pace(param.buffer_enable);
+            reg.set_paceo(param.odd_channel as u8);
+            reg.set_pacee(param.even_channel as u8);
+            reg
+        }
+    }
+
+    impl TryFrom<PaceReg> for Pace {
+        type Error = u8;
+
+        fn try_from(reg: PaceReg) -> Result<Self, Self::Error> {
+            Ok(Pace {
+                even_channel:  PaceEvenChannel::try_from(reg.pacee()).map_err(|_| reg.0)?,
+                odd_channel:   PaceOddChannel::try_from(reg.paceo()).map_err(|_| reg.0)?,
+                buffer_enable: reg.pd_pace(),
+            })
+        }
+    }
+}
+
+pub mod resp {
+    use super::*;
+
+    /// Respiration control (ADS1298R)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Resp {
+        pub demodulation_enable: bool,
+        pub modulation_enable:   bool,
+        pub phase:               RespPhase,
+        pub mode:                RespMode,
+    }
+
+    impl Default for Resp {
+        fn default() -> Self {
+            Resp {
+                demodulation_enable: false,
+                modulation_enable:   false,
+                phase:               RespPhase::Deg_22_5,
+                mode:                RespMode::Disabled,
+            }
+        }
+    }
+
+    /// Respiration demodulation signal phase
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[repr(u8)]
+    pub enum RespPhase {
+        Deg_22_5  = 0b000,
+        Deg_45    = 0b001,
+        Deg_67_5  = 0b010,
+        Deg_90    = 0b011,
+        Deg_112_5 = 0b100,
+        Deg_135   = 0b101,
+        Deg_157_5 = 0b110,
+    }
+
+    /// Respiration circuitry mode
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[repr(u8)]
+    pub enum RespMode {
+        /// No respiration
+        Disabled = 0b00,
+        /// External respiration
+        External = 0b01,
+        /// Internal respiration with internal signals
+        Internal = 0b10,
+        /// User-generated respiration signals on GPIO
+        UserGpio = 0b11,
+    }
+
+    // 0x16
+    bitfield! {
+        /// RESP: Respiration Control Register
+        ///
+        /// This register provides the controls for the respiration circuitry (ADS1298R only).
+        ///
+        pub struct RespReg(u8);
+        impl Debug;
+
+        /// Respiration control
+        ///
+        /// These bits set the mode of the respiration circuitry.
+        ///
+        ///   - 00 = No respiration
+        ///   - 01 = External respiration
+        ///   - 10 = Internal respiration with internal signals
+        ///   - 11 = User-generated respiration
+        ///
+        pub resp_ctrl, set_resp_ctrl : 1, 0;
+
+        /// Respiration phase
+        ///
+        /// These bits control the phase of the respiration demodulation control signal.
+        ///
+        ///   - 000 = 22.5°
+        ///   - 001 = 45°
+        ///   - 010 = 67.5°
+        ///   - 011 = 90°
+        ///   - 100 = 112.5°
+        ///   - 101 = 135°
+        ///   - 110 = 157.5°
+        ///   - 111 = NA
+        ///
+        pub resp_ph, set_resp_ph : 4, 2;
+
+        /// Reserved
+        ///
+        /// Always write 1
+        ///
+        _, set_reserved : 5;
+
+        /// Enables respiration modulation circuitry
+        ///
+        ///   - 0 = RESP modulation circuitry turned off on channel 1
+        ///   - 1 = RESP modulation circuitry turned on on channel 1
+        ///
+        pub resp_mod_en1, set_resp_mod_en1 : 6;
+
+        /// Enables respiration demodulation circuitry
+        ///
+        ///   - 0 = RESP demodulation circuitry turned off on channel 1
+        ///   - 1 = RESP demodulation circuitry turned on on channel 1
+        ///
+        pub resp_demod_en1, set_resp_demod_en1 : 7;
+    }
+
+    impl From<Resp> for RespReg {
+        fn from(param: Resp) -> Self {
+            let mut reg = RespReg(0);
+            reg.set_resp_ctrl(param.mode as u8);
+            reg.set_resp_ph(param.phase as u8);
+            reg.set_reserved(true);
+            reg.set_resp_mod_en1(param.modulation_enable);
+            reg.set_resp_demod_en1(param.demodulation_enable);
+            reg
+        }
+    }
+
+    impl TryFrom<RespReg> for Resp {
+        type Error = u8;
+
+        fn try_from(reg: RespReg) -> Result<Self, Self::Error> {
+            Ok(Resp {
+                demodulation_enable: reg.resp_demod_en1(),
+                modulation_enable:   reg.resp_mod_en1(),
+                phase:               RespPhase::try_from(reg.resp_ph()).map_err(|_| reg.0)?,
+                mode:                RespMode::try_from(reg.resp_ctrl()).map_err(|_| reg.0)?,
+            })
+        }
+    }
+}
+
+pub mod wct {
+    use super::*;
+
+    /// Wilson central terminal amplifier input
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[repr(u8)]
+    pub enum WctInput {
+        In1P = 0b000,
+        In1N = 0b001,
+        In2P = 0b010,
+        In2N = 0b011,
+        In3P = 0b100,
+        In3N = 0b101,
+        In4P = 0b110,
+        In4N = 0b111,
+    }
+
+    /// WCTA amplifier and augmented lead routing
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Wct1 {
+        /// `(WCTA + WCTB) / 2` to channel 6 negative input
+        pub avf_ch6:     bool,
+        /// `(WCTA + WCTC) / 2` to channel 5 negative input
+        pub avl_ch5:     bool,
+        /// `(WCTB + WCTC) / 2` to channel 7 negative input
+        pub avr_ch7:     bool,
+        /// `(WCTB + WCTC) / 2` to channel 4 negative input
+        pub avr_ch4:     bool,
+        /// WCTA amplifier powered up
+        pub wcta_enable: bool,
+        pub wcta:        WctInput,
+    }
+
+    impl Default for Wct1 {
+        fn default() -> Self {
+            Wct1 {
+                avf_ch6:     false,
+                avl_ch5:     false,
+                avr_ch7:     false,
+                avr_ch4:     false,
+                wcta_enable: false,
+                wcta:        WctInput::In1P,
+            }
+        }
+    }
+
+    // 0x18
+    bitfield! {
+        /// WCT1: Wilson Central Terminal and Augmented Lead Control Register
+        pub struct Wct1Reg(u8);
+        impl Debug;
+
+        /// WCT amplifier A channel selection
+        ///
+        /// Typically connected to RA electrode.
+        ///
+        ///   - 000 = Channel 1 positive input connected to WCTA amplifier
+        ///   - 001 = Channel 1 negative input connected to WCTA amplifier
+        ///   - 010 = Channel 2 positive input connected to WCTA amplifier
+        ///   - 011 = Channel 2 negative input connected to WCTA amplifier
+        ///   - 100 = Channel 3 positive input connected to WCTA amplifier
+        ///   - 101 = Channel 3 negative input connected to WCTA amplifier
+        ///   - 110 = Channel 4 positive input connected to WCTA amplifier
+        ///   - 111 = Channel 4 negative input connected to WCTA amplifier
+        ///
+        pub wcta, set_wcta : 2, 0;
+
+        /// Power-down WCTA
+        ///
+        ///   - 0 = Powered down
+        ///   - 1 = Powered on
+        ///
+        pub pd_wcta, set_pd_wcta : 3;
+
+        /// Enable (WCTB + WCTC)/2 to the negative input of channel 4
+        pub avr_ch4, set_avr_ch4 : 4;
+
+        /// Enable (WCTB + WCTC)/2 to the negative input of channel 7
+        pub avr_ch7, set_avr_ch7 : 5;
+
+        /// Enable (WCTA + WCTC)/2 to the negative input of channel 5
+        pub avl_ch5, set_avl_ch5 : 6;
+
+        /// Enable (WCTA + WCTB)/2 to the negative input of channel 6
+        pub avf_ch6, set_avf_ch6 : 7;
+    }
+
+    impl From<Wct1> for Wct1Reg {
+        fn from(param: Wct1) -> Self {
+            let mut reg = Wct1Reg(0);
+            reg.set_wcta(param.wcta as u8);
+            reg.set_pd_wcta(param.wcta_enable);
+            reg.set_avr_ch4(param.avr_ch4);
+            reg.set_avr_ch7(param.avr_ch7);
+            reg.set_avl_ch5(param.avl_ch5);
+            reg.set_avf_ch6(param.avf_ch6);
+            reg
+        }
+    }
+
+    impl TryFrom<Wct1Reg> for Wct1 {
+        type Error = u8;
+
+        fn try_from(reg: Wct1Reg) -> Result<Self, Self::Error> {
+            Ok(Wct1 {
+                avf_ch6:     reg.avf_ch6(),
+                avl_ch5:     reg.avl_ch5(),
+                avr_ch7:     reg.avr_ch7(),
+                avr_ch4:     reg.avr_ch4(),
+                wcta_enable: reg.pd_wcta(),
+                wcta:        WctInput::try_from(reg.wcta()).map_err(|_| reg.0)?,
+            })
+        }
+    }
+
+    /// WCTB/WCTC amplifiers
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Wct2 {
+        /// WCTC amplifier powered up
+        pub wctc_enable: bool,
+        /// WCTB amplifier powered up
+        pub wctb_enable: bool,
+        pub wctb:        WctInput,
+        pub wctc:        WctInput,
+    }
+
+    impl Default for Wct2 {
+        fn default() -> Self {
+            Wct2 {
+                wctc_enable: false,
+                wctb_enable: false,
+                wctb:        WctInput::In1P,
+                wctc:        WctInput::In1P,
+            }
+        }
+    }
+
+    // 0x19
+    bitfield! {
+        /// WCT2: Wilson Central Terminal Control Register
+        pub struct Wct2Reg(u8);
+        impl Debug;
+
+        /// WCT amplifier C channel selection
+        ///
+        /// Typically connected to LL electrode, encoding as in `WCTA`.
+        ///
+        pub wctc, set_wctc : 2, 0;
+
+        /// WCT amplifier B channel selection
+        ///
+        /// Typically connected to LA electrode, encoding as in `WCTA`.
+        ///
+        pub wctb, set_wctb : 5, 3;
+
+        /// Power-down WCTB
+        ///
+        ///   - 0 = Powered down
+        ///   - 1 = Powered on
+        ///
+        pub pd_wctb, set_pd_wctb : 6;
+
+        /// Power-down WCTC
+        ///
+        ///   - 0 = Powered down
+        ///   - 1 = Powered on
+        ///
+        pub pd_wctc, set_pd_wctc : 7;
+    }
+
+    impl From<Wct2> for Wct2Reg {
+        fn from(param: Wct2) -> Self {
+            let mut reg = Wct2Reg(0);
+            reg.set_wctc(param.wctc as u8);
+            reg.set_wctb(param.wctb as u8);
+            reg.set_pd_wctb(param.wctb_enable);
+            reg.set_pd_wctc(param.wctc_enable);
+            reg
+        }
+    }
+
+    impl TryFrom<Wct2Reg> for Wct2 {
+        type Error = u8;
+
+        fn try_from(reg: Wct2Reg) -> Result<Self, Self::Error> {
+            Ok(Wct2 {
+                wctc_enable: reg.pd_wctc(),
+                wctb_enable: reg.pd_wctb(),
+                wctb:        WctInput::try_from(reg.wctb()).map_err(|_| reg.0)?,
+                wctc:        WctInput::try_from(reg.wctc()).map_err(|_| reg.0)?,
+            })
+        }
+    }
+
+    /// Wilson central terminal setup spanning WCT1 and WCT2
+    ///
+    /// Each amplifier input is `None` when the amplifier is powered down.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct WctConfig {
+        /// WCTA input, typically the RA electrode
+        pub wcta:    Option<WctInput>,
+        /// WCTB input, typically the LA electrode
+        pub wctb:    Option<WctInput>,
+        /// WCTC input, typically the LL electrode
+        pub wctc:    Option<WctInput>,
+        /// `(WCTA + WCTB) / 2` to channel 6 negative input
+        pub avf_ch6: bool,
+        /// `(WCTA + WCTC) / 2` to channel 5 negative input
+        pub avl_ch5: bool,
+        /// `(WCTB + WCTC) / 2` to channel 7 negative input
+        pub avr_ch7: bool,
+        /// `(WCTB + WCTC) / 2` to channel 4 negative input
+        pub avr_ch4: bool,
+    }
+
+    impl WctConfig {
+        /// Check that no input feeds two powered amplifiers
+        pub fn validate(&self) -> Result<(), &'static str> {
+            match (self.wcta, self.wctb, self.wctc) {
+                (Some(a), Some(b), _) if a == b => Err("WCTA and WCTB share an input"),
+                (Some(a), _, Some(c)) if a == c => Err("WCTA and WCTC share an input"),
+                (_, Some(b), Some(c)) if b == c => Err("WCTB and WCTC share an input"),
+                _ => Ok(()),
+            }
+        }
+
+        /// Split into register settings
+        pub fn to_regs(&self) -> (Wct1, Wct2) {
+            let wct1 = Wct1 {
+                avf_ch6:     self.avf_ch6,
+                avl_ch5:     self.avl_ch5,
+                avr_ch7:     self.avr_ch7,
+                avr_ch4:     self.avr_ch4,
+                wcta_enable: self.wcta.is_some(),
+                wcta:        self.wcta.unwrap_or(WctInput::In1P),
+            };
+            let wct2 = Wct2 {
+                wctc_enable: self.wctc.is_some(),
+                wctb_enable: self.wctb.is_some(),
+                wctb:        self.wctb.unwrap_or(WctInput::In1P),
+                wctc:        self.wctc.unwrap_or(WctInput::In1P),
+            };
+            (wct1, wct2)
+        }
+
+        /// Assemble from register settings
+        pub fn from_regs(wct1: Wct1, wct2: Wct2) -> Self {
+            WctConfig {
+                wcta:    Some(wct1.wcta).filter(|_| wct1.wcta_enable),
+                wctb:    Some(wct2.wctb).filter(|_| wct2.wctb_enable),
+                wctc:    Some(wct2.wctc).filter(|_| wct2.wctc_enable),
+                avf_ch6: wct1.avf_ch6,
+                avl_ch5: wct1.avl_ch5,
+                avr_ch7: wct1.avr_ch7,
+                avr_ch4: wct1.avr_ch4,
+            }
+        }
+    }
+}
+
+/// Complete device configuration
+///
+/// Covers the registers most setups touch, applied with
+/// `apply_config`. Pace, respiration, RLD sense and WCT registers keep their
+/// own accessors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceConfig<const CH: usize> {
+    pub config:                 conf::Config,
+    pub test_signal:            conf::TestSignalConfig,
+    pub rld:                    conf::RldConfig,
+    pub leadoff_control:        loff::LeadOffControl,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
+    pub channels:               [chan::Chan; CH],
+    pub leadoff_sense_positive: loff::LeadOffSense,
+    pub leadoff_sense_negative: loff::LeadOffSense,
+    pub gpio:                   gpio::Gpio,
+    pub misc:                   conf::MiscConfig,
+}
+
+/// Register reset values, bits the types don't model are left cleared
+impl<const CH: usize> Default for DeviceConfig<CH> {
+    fn default() -> Self {
+        DeviceConfig {
+            config:                 conf::Config::default(),
+            test_signal:            conf::TestSignalConfig::default(),
+            rld:                    conf::RldConfig::default(),
+            leadoff_control:        loff::LeadOffControl::default(),
+            channels:               [chan::Chan::default(); CH],
+            leadoff_sense_positive: loff::LeadOffSense::default(),
+            leadoff_sense_negative: loff::LeadOffSense::default(),
+            gpio:                   gpio::Gpio::default(),
+            misc:                   conf::MiscConfig::default(),
+        }
+    }
+}
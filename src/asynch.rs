@@ -0,0 +1,497 @@
+//! Async driver on `embedded-hal-async`
+//!
+//! [`Ads129xAsync`] talks through an [`SpiDevice`], which owns nCS and frames
+//! each access itself, and awaits the command decode time instead of
+//! blocking. Otherwise it keeps the blocking driver's rules: the device mode
+//! is tracked in the same [`mode`] typestates, written registers go into the
+//! shadow behind the `modify_*` helpers and can be read back, see
+//! `set_write_verification`, and the register accessors come from the same
+//! tables.
+//!
+//! ```ignore
+//! let ads = Ads129xAsync::new_ads1298(spi_device); // Continuous
+//! let mut ads = ads.set_command_mode(&mut delay).await?;
+//! let model = ads.read_id(&mut delay).await?;
+//! let mut ads = ads.set_continuous_mode(&mut delay).await?;
+//! ads.read_data(&mut frame).await?;
+//! ```
+
+use core::convert::TryFrom;
+
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::digital::Wait;
+use embedded_hal_async::spi::{Operation, SpiDevice};
+
+use crate::{ads1292, ads1298, command, common, data, mode, shadow, spi, timing, variant};
+use crate::{Ads1292Family, Ads1298Family, Ads129xError, Ads129xResult};
+
+/// ADS129x async driver
+///
+/// Constructors assume the power-on continuous conversion mode, see [`mode`].
+pub struct Ads129xAsync<SPI, DEV, const CH: usize, MODE = mode::Continuous> {
+    spi:        SPI,
+    shadow:     shadow::Shadow,
+    /// Read back every register write
+    verify:     bool,
+    /// Status word sync check policy
+    validation: data::FrameValidation,
+    /// Sample representation of the frame reads
+    format:     data::SampleFormat,
+    /// Device mode as last set, followed in [`mode::Dynamic`]
+    continuous: bool,
+    /// Register access in continuous mode, [`mode::Dynamic`] only
+    access:     mode::AccessPolicy,
+    _d:         core::marker::PhantomData<(DEV, MODE)>,
+}
+
+impl<SPI: SpiDevice> Ads129xAsync<SPI, Ads1292Family, 2> {
+    /// Create ADS1292 device instance, drives the ADS1292R without respiration
+    pub fn new_ads1292(spi: SPI) -> Self {
+        Self::new(spi)
+    }
+}
+
+impl<SPI: SpiDevice> Ads129xAsync<SPI, Ads1292Family<variant::R>, 2> {
+    /// Create ADS1292R device instance with the respiration API, see
+    /// [`variant`]
+    pub fn new_ads1292r(spi: SPI) -> Self {
+        Self::new(spi)
+    }
+}
+
+impl<SPI: SpiDevice> Ads129xAsync<SPI, Ads1292Family, 1> {
+    /// Create ADS1291 device instance
+    pub fn new_ads1291(spi: SPI) -> Self {
+        Self::new(spi)
+    }
+}
+
+impl<SPI: SpiDevice> Ads129xAsync<SPI, Ads1298Family, 4> {
+    /// Create ADS1294 device instance, drives the ADS1294R without respiration
+    pub fn new_ads1294(spi: SPI) -> Self {
+        Self::new(spi)
+    }
+}
+
+impl<SPI: SpiDevice> Ads129xAsync<SPI, Ads1298Family<variant::R>, 4> {
+    /// Create ADS1294R device instance with the respiration API, see
+    /// [`variant`]
+    pub fn new_ads1294r(spi: SPI) -> Self {
+        Self::new(spi)
+    }
+}
+
+impl<SPI: SpiDevice> Ads129xAsync<SPI, Ads1298Family, 6> {
+    /// Create ADS1296 device instance, drives the ADS1296R without respiration
+    pub fn new_ads1296(spi: SPI) -> Self {
+        Self::new(spi)
+    }
+}
+
+impl<SPI: SpiDevice> Ads129xAsync<SPI, Ads1298Family<variant::R>, 6> {
+    /// Create ADS1296R device instance with the respiration API, see
+    /// [`variant`]
+    pub fn new_ads1296r(spi: SPI) -> Self {
+        Self::new(spi)
+    }
+}
+
+impl<SPI: SpiDevice> Ads129xAsync<SPI, Ads1298Family, 8> {
+    /// Create ADS1298 device instance, drives the ADS1298R without respiration
+    pub fn new_ads1298(spi: SPI) -> Self {
+        Self::new(spi)
+    }
+}
+
+impl<SPI: SpiDevice> Ads129xAsync<SPI, Ads1298Family<variant::R>, 8> {
+    /// Create ADS1298R device instance with the respiration API, see
+    /// [`variant`]
+    pub fn new_ads1298r(spi: SPI) -> Self {
+        Self::new(spi)
+    }
+}
+
+impl<SPI, DEV, const CH: usize> Ads129xAsync<SPI, DEV, CH>
+where
+    SPI: SpiDevice,
+{
+    /// Driver with the power-on defaults
+    fn new(spi: SPI) -> Self {
+        Ads129xAsync {
+            spi,
+            shadow: shadow::Shadow::new(),
+            verify: false,
+            validation: data::FrameValidation::Strict,
+            format: data::SampleFormat::SignExtended,
+            continuous: true,
+            access: mode::AccessPolicy::default(),
+            _d: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<SPI, DEV, MODE, const CH: usize> Ads129xAsync<SPI, DEV, CH, MODE>
+where
+    SPI: SpiDevice,
+    MODE: mode::Mode,
+{
+    /// Read back each register after writing it, see
+    /// `Ads129x::set_write_verification`
+    pub fn set_write_verification(&mut self, enable: bool) {
+        self.verify = enable;
+    }
+
+    /// Choose how frames failing the status word sync check are handled
+    ///
+    /// [`Strict`](data::FrameValidation::Strict) by default.
+    pub fn set_frame_validation(&mut self, validation: data::FrameValidation) {
+        self.validation = validation;
+    }
+
+    /// Choose how frame reads store samples
+    pub fn set_sample_format(&mut self, format: data::SampleFormat) {
+        self.format = format;
+    }
+
+    impl_cmd_async!(wakeup_device, WAKEUP);
+    impl_cmd_async!(set_standby_mode, STANDBY);
+    impl_cmd_async!(start_conv, START);
+    impl_cmd_async!(stop_conv, STOP);
+
+    /// Reset the device with the `RESET` opcode
+    ///
+    /// Waits [`timing::RESET_RECOVERY_US`]. The device comes back in
+    /// continuous mode, in [`mode::Command`] the driver sends `SDATAC`
+    /// afterwards. The written register values are forgotten.
+    pub async fn reset_device(
+        &mut self,
+        delay: &mut impl DelayNs,
+    ) -> Ads129xResult<(), SPI::Error> {
+        self.shadow.clear();
+        self.spi.write(&[command::Command::RESET as u8]).await?;
+        delay.delay_us(timing::RESET_RECOVERY_US).await;
+        self.continuous = true;
+        self.resume_mode(delay).await
+    }
+
+    /// Follow the device mode at run time instead of in the type, see
+    /// `Ads129x::into_dynamic`
+    pub fn into_dynamic(self) -> Ads129xAsync<SPI, DEV, CH, mode::Dynamic> {
+        self.into_mode()
+    }
+
+    pub fn destroy(self) -> SPI {
+        self.spi
+    }
+
+    fn into_mode<M>(self) -> Ads129xAsync<SPI, DEV, CH, M> {
+        Ads129xAsync {
+            spi:        self.spi,
+            shadow:     self.shadow,
+            verify:     self.verify,
+            validation: self.validation,
+            format:     self.format,
+            continuous: self.continuous,
+            access:     self.access,
+            _d:         core::marker::PhantomData,
+        }
+    }
+
+    async fn command(
+        &mut self,
+        command: command::Command,
+        delay: &mut impl DelayNs,
+    ) -> Ads129xResult<(), SPI::Error> {
+        self.spi.write(&[command as u8]).await?;
+        if let Some(continuous) = mode::followed(command as u8) {
+            self.continuous = continuous;
+        }
+        delay.delay_us(timing::DECODE_US).await;
+        Ok(())
+    }
+
+    /// Put the device back into the tracked mode after a reset
+    async fn resume_mode(&mut self, delay: &mut impl DelayNs) -> Ads129xResult<(), SPI::Error> {
+        match mode::resume_opcode::<MODE>(self.continuous) {
+            Some(opcode) => self.command(opcode, delay).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Leave continuous mode ahead of register access when [`mode::Dynamic`]
+    /// believes the device is in it, returns whether `SDATAC` went out
+    async fn stop_for_access(
+        &mut self,
+        delay: &mut impl DelayNs,
+    ) -> Ads129xResult<bool, SPI::Error> {
+        if !mode::stops_for_access::<MODE>(self.continuous, self.access) {
+            return Ok(false);
+        }
+        self.command(command::Command::SDATAC, delay).await?;
+        Ok(true)
+    }
+
+    /// Back to continuous mode after the access when the policy says so
+    async fn resume_after_access(
+        &mut self,
+        stopped: bool,
+        delay: &mut impl DelayNs,
+    ) -> Ads129xResult<(), SPI::Error> {
+        if mode::resumes_after_access(stopped, self.access) {
+            self.command(command::Command::RDATAC, delay).await?;
+        }
+        Ok(())
+    }
+
+    async fn read_register_raw(
+        &mut self,
+        reg: u8,
+        delay: &mut impl DelayNs,
+    ) -> Ads129xResult<u8, SPI::Error> {
+        let stopped = self.stop_for_access(delay).await?;
+        let mut words = [command::Command::RREG as u8 | reg, 0x00, 0xA5];
+        self.spi.transfer_in_place(&mut words).await?;
+        delay.delay_us(timing::DECODE_US).await;
+        self.resume_after_access(stopped, delay).await?;
+        Ok(words[2])
+    }
+
+    /// Write `value` to `reg`, record it and read it back when verification
+    /// is enabled
+    async fn write_register_raw(
+        &mut self,
+        reg: u8,
+        info: &common::RegisterInfo,
+        value: u8,
+        delay: &mut impl DelayNs,
+    ) -> Ads129xResult<(), SPI::Error> {
+        if info.access == common::Access::ReadOnly {
+            return Err(Ads129xError::InvalidArgument("read-only register"));
+        }
+
+        let stopped = self.stop_for_access(delay).await?;
+        let words = [command::Command::WREG as u8 | reg, 0x00, value];
+        self.spi.write(&words).await?;
+        delay.delay_us(timing::DECODE_US).await;
+        self.shadow.store(reg, value);
+        if self.verify {
+            let mut words = [command::Command::RREG as u8 | reg, 0x00, 0xA5];
+            self.spi.transfer_in_place(&mut words).await?;
+            delay.delay_us(timing::DECODE_US).await;
+            crate::verify_register(reg, info, value, words[2])?;
+        }
+        self.resume_after_access(stopped, delay).await
+    }
+
+    /// Clock out the status word and `N` samples in one transaction
+    async fn read_frame_raw<const N: usize>(
+        &mut self,
+        status_word: &mut [u8; 3],
+        data: &mut [i32; N],
+    ) -> Ads129xResult<(), SPI::Error> {
+        let () = data::DataFrame::<N>::CHANNELS_FIT;
+
+        let mut buf = [0x00u8; spi::FRAME_BUF_LEN];
+        let buf = &mut buf[..data::STATUS_LEN + data::SAMPLE_LEN * N];
+        self.spi
+            .transaction(&mut [Operation::TransferInPlace(buf)])
+            .await?;
+
+        status_word.copy_from_slice(&buf[..data::STATUS_LEN]);
+        for (chunk, sample) in buf[data::STATUS_LEN..]
+            .chunks_exact(data::SAMPLE_LEN)
+            .zip(data)
+        {
+            *sample = self.format.from_sign_extended(data::i24_from_be(chunk));
+        }
+
+        self.validation.check(*status_word)
+    }
+}
+
+impl<SPI, DEV, const CH: usize> Ads129xAsync<SPI, DEV, CH, mode::Continuous>
+where
+    SPI: SpiDevice,
+{
+    /// Enter command mode with `SDATAC`
+    ///
+    /// The driver is consumed even when the opcode fails to go out.
+    pub async fn set_command_mode(
+        mut self,
+        delay: &mut impl DelayNs,
+    ) -> Ads129xResult<Ads129xAsync<SPI, DEV, CH, mode::Command>, SPI::Error> {
+        self.command(command::Command::SDATAC, delay).await?;
+        Ok(self.into_mode())
+    }
+}
+
+impl<SPI, DEV, const CH: usize> Ads129xAsync<SPI, DEV, CH, mode::Command>
+where
+    SPI: SpiDevice,
+{
+    /// Enter continuous conversion mode with `RDATAC`
+    ///
+    /// The driver is consumed even when the opcode fails to go out.
+    pub async fn set_continuous_mode(
+        mut self,
+        delay: &mut impl DelayNs,
+    ) -> Ads129xResult<Ads129xAsync<SPI, DEV, CH, mode::Continuous>, SPI::Error> {
+        self.command(command::Command::RDATAC, delay).await?;
+        Ok(self.into_mode())
+    }
+}
+
+impl<SPI, DEV, const CH: usize> Ads129xAsync<SPI, DEV, CH, mode::Dynamic>
+where
+    SPI: SpiDevice,
+{
+    impl_cmd_async!(set_continuous_mode, RDATAC);
+    impl_cmd_async!(set_command_mode, SDATAC);
+
+    /// Choose what register access does in continuous mode
+    ///
+    /// [`Stop`](mode::AccessPolicy::Stop) by default.
+    pub fn set_access_policy(&mut self, policy: mode::AccessPolicy) {
+        self.access = policy;
+    }
+
+    /// Device mode as followed by the driver
+    pub fn is_continuous(&self) -> bool {
+        self.continuous
+    }
+}
+
+impl<SPI, DEV, MODE, const CH: usize> Ads129xAsync<SPI, DEV, CH, MODE>
+where
+    SPI: SpiDevice,
+    DEV: variant::FamilyVariant,
+    MODE: mode::RegisterAccess,
+{
+    /// Identify the device, see `Ads129x::read_id`
+    pub async fn read_id(
+        &mut self,
+        delay: &mut impl DelayNs,
+    ) -> Ads129xResult<common::id::DevModel, SPI::Error> {
+        let raw = self.read_register_raw(0x00, delay).await?;
+        let model = common::id::DevModel::try_from(common::id::IdReg(raw))
+            .map_err(Ads129xError::IdRegRead)?;
+
+        if DEV::RESPIRATION && !model.is_respiration_variant() {
+            return Err(Ads129xError::VariantMismatch { found: model });
+        }
+        Ok(model)
+    }
+}
+
+impl<SPI, V, MODE, const CH: usize> Ads129xAsync<SPI, Ads1292Family<V>, CH, MODE>
+where
+    SPI: SpiDevice,
+    MODE: mode::DataAccess,
+{
+    /// Read single frame in continuous mode, once DRDY went low
+    pub async fn read_data(
+        &mut self,
+        data_frame: &mut data::DataFrame92,
+    ) -> Ads129xResult<(), SPI::Error> {
+        let res = self
+            .read_frame_raw(&mut data_frame.status_word, &mut data_frame.data)
+            .await;
+        for sample in data_frame.data.iter_mut().skip(CH) {
+            *sample = 0;
+        }
+        res
+    }
+
+    /// Wait for `DRDY` falling edge, then read the frame like `read_data`
+    ///
+    /// A failed wait is [`Ads129xError::DrdyPin`].
+    pub async fn read_data_on_drdy<P: Wait>(
+        &mut self,
+        drdy: &mut P,
+        data_frame: &mut data::DataFrame92,
+    ) -> Ads129xResult<(), SPI::Error> {
+        drdy.wait_for_falling_edge()
+            .await
+            .map_err(|_| Ads129xError::DrdyPin)?;
+        self.read_data(data_frame).await
+    }
+}
+
+impl<SPI, V, MODE, const CH: usize> Ads129xAsync<SPI, Ads1292Family<V>, CH, MODE>
+where
+    SPI: SpiDevice,
+    MODE: mode::RegisterAccess,
+{
+    ads1292_registers!(ASYNC:);
+}
+
+impl<SPI, MODE, const CH: usize> Ads129xAsync<SPI, Ads1292Family<variant::R>, CH, MODE>
+where
+    SPI: SpiDevice,
+    MODE: mode::RegisterAccess,
+{
+    ads1292r_registers!(ASYNC:);
+}
+
+impl<SPI, V, MODE, const CH: usize> Ads129xAsync<SPI, Ads1298Family<V>, CH, MODE>
+where
+    SPI: SpiDevice,
+    MODE: mode::DataAccess,
+{
+    /// Read single frame in continuous mode, once DRDY went low
+    pub async fn read_data(
+        &mut self,
+        data_frame: &mut data::DataFrame<CH>,
+    ) -> Ads129xResult<(), SPI::Error> {
+        self.read_frame_raw(&mut data_frame.status_word, &mut data_frame.data)
+            .await
+    }
+
+    /// Wait for `DRDY` falling edge, then read the frame like `read_data`
+    ///
+    /// A failed wait is [`Ads129xError::DrdyPin`].
+    pub async fn read_data_on_drdy<P: Wait>(
+        &mut self,
+        drdy: &mut P,
+        data_frame: &mut data::DataFrame<CH>,
+    ) -> Ads129xResult<(), SPI::Error> {
+        drdy.wait_for_falling_edge()
+            .await
+            .map_err(|_| Ads129xError::DrdyPin)?;
+        self.read_data(data_frame).await
+    }
+}
+
+impl<SPI, V, MODE, const CH: usize> Ads129xAsync<SPI, Ads1298Family<V>, CH, MODE>
+where
+    SPI: SpiDevice,
+    MODE: mode::RegisterAccess,
+{
+    ads1298_registers!(ASYNC:);
+}
+
+// Channels past the fourth only exist on the ADS1296 and ADS1298
+impl<SPI, V, MODE> Ads129xAsync<SPI, Ads1298Family<V>, 6, MODE>
+where
+    SPI: SpiDevice,
+    MODE: mode::RegisterAccess,
+{
+    ads1298_chan_5_6_registers!(ASYNC:);
+}
+
+impl<SPI, V, MODE> Ads129xAsync<SPI, Ads1298Family<V>, 8, MODE>
+where
+    SPI: SpiDevice,
+    MODE: mode::RegisterAccess,
+{
+    ads1298_chan_5_6_registers!(ASYNC:);
+    ads1298_chan_7_8_registers!(ASYNC:);
+}
+
+impl<SPI, MODE, const CH: usize> Ads129xAsync<SPI, Ads1298Family<variant::R>, CH, MODE>
+where
+    SPI: SpiDevice,
+    MODE: mode::RegisterAccess,
+{
+    ads1298r_registers!(ASYNC:);
+}
@@ -0,0 +1,330 @@
+//! Declarative device construction
+//!
+//! [`Ads129xBuilder`] runs the whole bring-up in the order the device needs
+//! it: reset, `SDATAC`, `ID` check, configuration and `RDATAC`.
+//!
+//! ```ignore
+//! let (ads, drdy) = Ads129xBuilder::<_, _, Ads1298Family, 8>::new(spi, ncs)
+//!     .with_reset(reset)
+//!     .with_drdy(drdy)
+//!     .config(config)
+//!     .build_with_drdy(&mut delay)?;
+//! ```
+
+use ehal::blocking::delay::DelayUs;
+use ehal::blocking::spi::{Transfer, Write};
+use ehal::digital::v2::{InputPin, OutputPin};
+use embedded_hal as ehal;
+
+use crate::common::{self, id::DevModel};
+use crate::{ads1292, ads1298, mode, pins, spi, timing, variant};
+use crate::{Ads1292Family, Ads1298Family, Ads129x, Ads129xError, Ads129xResult};
+
+/// Bring-up step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum InitStep {
+    /// Power-on wait, reset and `SDATAC`
+    Reset,
+    /// `ID` read and model check
+    VerifyId,
+    /// Configuration write and settling wait
+    Configure,
+    /// `RDATAC`
+    StartContinuous,
+}
+
+/// Bring-up failure
+#[derive(Debug)]
+pub struct BuildError<E> {
+    /// Step that failed
+    pub step:  InitStep,
+    pub error: Ads129xError<E>,
+}
+
+impl<E> BuildError<E> {
+    fn at(step: InitStep) -> impl FnOnce(Ads129xError<E>) -> Self {
+        move |error| BuildError { step, error }
+    }
+}
+
+impl<E> core::fmt::Display for BuildError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?} step failed: {}", self.step, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for BuildError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<E: core::fmt::Debug> defmt::Format for BuildError<E> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{} step failed: {}", self.step, self.error)
+    }
+}
+
+/// Device family specifics of the bring-up
+#[doc(hidden)]
+pub trait BuildFamily<const CH: usize>: variant::FamilyVariant + Sized {
+    /// Whole device configuration
+    type Config: Default;
+
+    /// `model` is the device the driver was constructed for
+    fn is_model(model: DevModel) -> bool;
+
+    fn apply<SPI, NCS, E, PINS>(
+        driver: &mut Ads129x<SPI, NCS, Self, CH, PINS, mode::Command>,
+        config: &Self::Config,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E>
+    where
+        SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+        NCS: OutputPin,
+        PINS: pins::ControlPinSet;
+}
+
+impl<V: variant::Variant, const CH: usize> BuildFamily<CH> for Ads1292Family<V> {
+    type Config = ads1292::DeviceConfig;
+
+    fn is_model(model: DevModel) -> bool {
+        model.family() == common::Family::Ads1292 && model.channel_count() == CH
+    }
+
+    fn apply<SPI, NCS, E, PINS>(
+        driver: &mut Ads129x<SPI, NCS, Self, CH, PINS, mode::Command>,
+        config: &Self::Config,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E>
+    where
+        SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+        NCS: OutputPin,
+        PINS: pins::ControlPinSet,
+    {
+        driver.apply_config(config, delay)
+    }
+}
+
+impl<V: variant::Variant, const CH: usize> BuildFamily<CH> for Ads1298Family<V> {
+    type Config = ads1298::DeviceConfig<CH>;
+
+    fn is_model(model: DevModel) -> bool {
+        model.family() == common::Family::Ads1298 && model.channel_count() == CH
+    }
+
+    fn apply<SPI, NCS, E, PINS>(
+        driver: &mut Ads129x<SPI, NCS, Self, CH, PINS, mode::Command>,
+        config: &Self::Config,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E>
+    where
+        SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+        NCS: OutputPin,
+        PINS: pins::ControlPinSet,
+    {
+        driver.apply_config(config, delay)
+    }
+}
+
+/// Driver bring-up
+///
+/// Takes the bus and nCS, the device family and channel count are the type
+/// parameters of the matching `Ads129x` constructor, e.g. `Ads1298Family, 8`
+/// for `new_ads1298`. Defaults: register reset values,
+/// [`timing::POWER_UP_US`] power-on wait, nominal reset recovery and no
+/// settling wait.
+pub struct Ads129xBuilder<
+    SPI,
+    NCS,
+    DEV,
+    const CH: usize,
+    PINS = pins::ControlPins,
+    DRDY = pins::NoPin,
+> where
+    DEV: BuildFamily<CH>,
+{
+    driver:           Ads129x<SPI, NCS, DEV, CH, PINS>,
+    drdy:             DRDY,
+    config:           DEV::Config,
+    power_on_wait_us: u32,
+    fclk_hz:          Option<u32>,
+    settle_us:        u32,
+}
+
+impl<SPI, NCS, DEV, E, const CH: usize> Ads129xBuilder<SPI, NCS, DEV, CH>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    DEV: BuildFamily<CH>,
+{
+    pub fn new(spi: SPI, ncs: NCS) -> Self {
+        Ads129xBuilder {
+            driver:           Ads129x::new(spi, ncs),
+            drdy:             pins::NoPin,
+            config:           DEV::Config::default(),
+            power_on_wait_us: timing::POWER_UP_US,
+            fclk_hz:          None,
+            settle_us:        0,
+        }
+    }
+}
+
+impl<SPI, NCS, DEV, const CH: usize, DRDY>
+    Ads129xBuilder<SPI, NCS, DEV, CH, pins::ControlPins, DRDY>
+where
+    DEV: BuildFamily<CH>,
+{
+    /// Hand START/RESET/PWDN pins over to the driver
+    pub fn with_control_pins<PINS>(
+        self,
+        control_pins: PINS,
+    ) -> Ads129xBuilder<SPI, NCS, DEV, CH, PINS, DRDY> {
+        Ads129xBuilder {
+            driver:           self.driver.with_control_pins(control_pins),
+            drdy:             self.drdy,
+            config:           self.config,
+            power_on_wait_us: self.power_on_wait_us,
+            fclk_hz:          self.fclk_hz,
+            settle_us:        self.settle_us,
+        }
+    }
+
+    /// Reset through the RESET pin instead of the opcode
+    pub fn with_reset<P: OutputPin>(
+        self,
+        reset: P,
+    ) -> Ads129xBuilder<SPI, NCS, DEV, CH, pins::ControlPins<pins::NoPin, P>, DRDY> {
+        self.with_control_pins(pins::ControlPins::new().with_reset(reset))
+    }
+}
+
+impl<SPI, NCS, DEV, const CH: usize, PINS> Ads129xBuilder<SPI, NCS, DEV, CH, PINS>
+where
+    DEV: BuildFamily<CH>,
+{
+    /// DRDY pin handed back with the driver by
+    /// [`build_with_drdy`](Ads129xBuilder::build_with_drdy)
+    pub fn with_drdy<P: InputPin>(self, drdy: P) -> Ads129xBuilder<SPI, NCS, DEV, CH, PINS, P> {
+        Ads129xBuilder {
+            driver: self.driver,
+            drdy,
+            config: self.config,
+            power_on_wait_us: self.power_on_wait_us,
+            fclk_hz: self.fclk_hz,
+            settle_us: self.settle_us,
+        }
+    }
+}
+
+impl<SPI, NCS, DEV, const CH: usize, PINS, DRDY> Ads129xBuilder<SPI, NCS, DEV, CH, PINS, DRDY>
+where
+    DEV: BuildFamily<CH>,
+{
+    /// Configuration written after the `ID` check
+    pub fn config(mut self, config: DEV::Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Wait for the supplies and the power-on reset before the first access
+    pub fn power_on_wait_us(mut self, us: u32) -> Self {
+        self.power_on_wait_us = us;
+        self
+    }
+
+    /// Master clock, extends the reset recovery to 18 `tCLK`
+    pub fn fclk_hz(mut self, hz: u32) -> Self {
+        self.fclk_hz = Some(hz);
+        self
+    }
+
+    /// Wait after the configuration, e.g. for the reference to settle
+    pub fn settle_us(mut self, us: u32) -> Self {
+        self.settle_us = us;
+        self
+    }
+}
+
+impl<SPI, NCS, DEV, E, PINS, const CH: usize, DRDY> Ads129xBuilder<SPI, NCS, DEV, CH, PINS, DRDY>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    DEV: BuildFamily<CH>,
+{
+    /// Bring the device up, returning the driver in continuous mode
+    ///
+    /// A device other than the one the driver was built for fails the
+    /// [`InitStep::VerifyId`] step with [`Ads129xError::NotSupported`]. The
+    /// driver is dropped on failure.
+    pub fn build(
+        self,
+        delay: impl DelayUs<u32>,
+    ) -> Result<Ads129x<SPI, NCS, DEV, CH, PINS, mode::Continuous>, BuildError<E>> {
+        self.bring_up(delay).map(|(driver, _)| driver)
+    }
+
+    /// Bring the device up like [`build`](Self::build), also handing back
+    /// the DRDY pin, e.g. for `read_data_when_ready`
+    #[allow(clippy::type_complexity)]
+    pub fn build_with_drdy(
+        self,
+        delay: impl DelayUs<u32>,
+    ) -> Result<(Ads129x<SPI, NCS, DEV, CH, PINS, mode::Continuous>, DRDY), BuildError<E>>
+    where
+        DRDY: InputPin,
+    {
+        self.bring_up(delay)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn bring_up(
+        self,
+        mut delay: impl DelayUs<u32>,
+    ) -> Result<(Ads129x<SPI, NCS, DEV, CH, PINS, mode::Continuous>, DRDY), BuildError<E>> {
+        let Ads129xBuilder {
+            mut driver,
+            drdy,
+            config,
+            power_on_wait_us,
+            fclk_hz,
+            settle_us,
+        } = self;
+
+        delay.delay_us(power_on_wait_us);
+        driver
+            .reset_with_recovery(fclk_hz, spi::DelayRef(&mut delay))
+            .map_err(BuildError::at(InitStep::Reset))?;
+        let mut driver = driver
+            .set_command_mode(spi::DelayRef(&mut delay))
+            .map_err(BuildError::at(InitStep::Reset))?;
+
+        let model = driver
+            .read_id(spi::DelayRef(&mut delay))
+            .map_err(BuildError::at(InitStep::VerifyId))?;
+        if !DEV::is_model(model) {
+            return Err(BuildError {
+                step:  InitStep::VerifyId,
+                error: Ads129xError::NotSupported {
+                    model: Some(model),
+                    what:  "model differs from the driver",
+                },
+            });
+        }
+
+        DEV::apply(&mut driver, &config, spi::DelayRef(&mut delay))
+            .map_err(BuildError::at(InitStep::Configure))?;
+        if settle_us > 0 {
+            delay.delay_us(settle_us);
+        }
+
+        let driver = driver
+            .set_continuous_mode(delay)
+            .map_err(BuildError::at(InitStep::StartContinuous))?;
+        Ok((driver, drdy))
+    }
+}
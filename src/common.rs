@@ -1,9 +1,129 @@
 use bitfield::bitfield;
+use num_enum::TryFromPrimitive;
+
+/// Device sub-family, the driver constructors follow it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum Family {
+    /// 1-2 channel devices (ADS1291, ADS1292, ADS1292R)
+    Ads1292 = 0x00,
+    /// 4-8 channel devices (ADS1294/6/8 and R variants)
+    Ads1298 = 0x01,
+}
+
+/// Device clock source, selected by the CLKSEL pin
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ClockSource {
+    /// Internal oscillator: 512 kHz on the ADS1291/2, 2.048 MHz on the ADS1294/6/8
+    #[default]
+    InternalOscillator,
+    /// External clock on the CLK pin
+    ExternalClock { hz: u32 },
+}
+
+/// PGA gain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum ChannelGain {
+    X6  = 0b000,
+    X1  = 0b001,
+    X2  = 0b010,
+    X3  = 0b011,
+    X4  = 0b100,
+    X8  = 0b101,
+    X12 = 0b110,
+}
+
+impl ChannelGain {
+    /// Gain as a multiplication factor
+    pub const fn factor(self) -> u8 {
+        match self {
+            ChannelGain::X1 => 1,
+            ChannelGain::X2 => 2,
+            ChannelGain::X3 => 3,
+            ChannelGain::X4 => 4,
+            ChannelGain::X6 => 6,
+            ChannelGain::X8 => 8,
+            ChannelGain::X12 => 12,
+        }
+    }
+}
+
+/// Supply power-up sequence settings, see `power_up_sequence`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PowerUpOptions {
+    /// Wait for VCAP1 and the power-on reset before `RESET`
+    pub power_on_wait_us:   u32,
+    /// Power up the internal reference buffer
+    pub internal_reference: bool,
+    /// Wait after enabling the internal reference
+    pub reference_wait_us:  u32,
+}
+
+impl Default for PowerUpOptions {
+    fn default() -> Self {
+        PowerUpOptions {
+            power_on_wait_us:   crate::timing::POWER_UP_US,
+            internal_reference: true,
+            reference_wait_us:  crate::timing::REFERENCE_SETTLE_US,
+        }
+    }
+}
+
+/// Device registers reset to their defaults, e.g. by `power_up`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[must_use = "the configuration has to be written again"]
+pub struct RegistersLost;
+
+/// Register access type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    ReadOnly,
+    ReadWrite,
+}
+
+/// Register metadata
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegisterInfo {
+    /// Datasheet register name
+    pub name:   &'static str,
+    pub access: Access,
+    /// Value after reset, zero for factory programmed registers
+    pub reset:  u8,
+    /// Bits reading back what was written, status and input bits excluded
+    pub mask:   u8,
+}
+
+impl RegisterInfo {
+    pub(crate) const fn read_only(name: &'static str) -> Self {
+        RegisterInfo {
+            name,
+            access: Access::ReadOnly,
+            reset: 0x00,
+            mask: 0x00,
+        }
+    }
+
+    pub(crate) const fn read_write(name: &'static str, reset: u8, mask: u8) -> Self {
+        RegisterInfo {
+            name,
+            access: Access::ReadWrite,
+            reset,
+            mask,
+        }
+    }
+}
 
 pub mod id {
     use super::*;
 
-    #[derive(Debug)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
     pub enum DevModel {
         Ads1291,
         Ads1292,
@@ -16,6 +136,43 @@ pub mod id {
         Ads1298R,
     }
 
+    impl DevModel {
+        /// Number of input channels
+        pub const fn channel_count(self) -> usize {
+            match self {
+                DevModel::Ads1291 => 1,
+                DevModel::Ads1292 | DevModel::Ads1292R => 2,
+                DevModel::Ads1294 | DevModel::Ads1294R => 4,
+                DevModel::Ads1296 | DevModel::Ads1296R => 6,
+                DevModel::Ads1298 | DevModel::Ads1298R => 8,
+            }
+        }
+
+        /// R variant with the respiration circuitry
+        pub const fn is_respiration_variant(self) -> bool {
+            matches!(
+                self,
+                DevModel::Ads1292R | DevModel::Ads1294R | DevModel::Ads1296R | DevModel::Ads1298R
+            )
+        }
+
+        pub const fn family(self) -> Family {
+            match self {
+                DevModel::Ads1291 | DevModel::Ads1292 | DevModel::Ads1292R => Family::Ads1292,
+                _ => Family::Ads1298,
+            }
+        }
+
+        /// Highest output data rate, in high-resolution mode for the ADS1298
+        /// family
+        pub const fn max_sample_rate_sps(self) -> u32 {
+            match self.family() {
+                Family::Ads1292 => 8_000,
+                Family::Ads1298 => 32_000,
+            }
+        }
+    }
+
     bitfield! {
         // 0x00
         pub struct IdReg(u8);
@@ -26,12 +183,27 @@ pub mod id {
     }
 
     #[derive(Debug, Clone, Copy)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
     pub enum IdRegError {
         /// Should always equals to 0b10
         ReservedFieldMismatch(u8),
         Unsupported(u8),
     }
 
+    impl core::fmt::Display for IdRegError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self {
+                Self::ReservedFieldMismatch(id) => {
+                    write!(f, "ID {:#04x} reserved bits mismatch, expected 0b10", id)
+                }
+                Self::Unsupported(id) => write!(f, "unsupported device ID {:#04x}", id),
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for IdRegError {}
+
     impl core::convert::TryFrom<IdReg> for DevModel {
         type Error = IdRegError;
 
@@ -1,17 +1,259 @@
+use crate::ads1292;
+use crate::common::ChannelGain;
 use bitfield::bitfield;
-use core::mem::size_of;
 
+#[cfg(feature = "std")]
+pub mod log;
+mod stats;
+
+pub use stats::{ChannelNoise, ChannelStats, NoiseReport, StatsReport, StatsReportUv};
+
+/// Status word length in bytes
+pub(crate) const STATUS_LEN: usize = 3;
+/// Channel sample length in bytes
+pub(crate) const SAMPLE_LEN: usize = 3;
+
+/// Most channels in a frame, the ADS1298's
+pub const MAX_CHANNELS: usize = 8;
+
+/// Expected status word sync nibble
+const SYNC: u8 = 0b1100;
+
+/// Assemble i24 big endian sample and sign extend it to i32
+#[inline]
+pub(crate) fn i24_from_be(bytes: &[u8]) -> i32 {
+    i32::from_be_bytes([bytes[0], bytes[1], bytes[2], 0x00]) >> 8
+}
+
+/// Truncate sample to i24 big endian
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn i24_to_be(sample: i32) -> [u8; SAMPLE_LEN] {
+    let bb = sample.to_be_bytes();
+    [bb[1], bb[2], bb[3]]
+}
+
+/// Convert a sample code to µV, rounded to nearest
+///
+/// Full scale `±2^23` codes span `±VREF / gain`.
+pub fn code_to_microvolts(code: i32, gain: ChannelGain, vref_uv: u32) -> i32 {
+    let num = code as i64 * vref_uv as i64;
+    let den = (gain.factor() as i64) << 23;
+    // Round half away from zero
+    let half = if num < 0 { -den / 2 } else { den / 2 };
+    ((num + half) / den) as i32
+}
+
+/// One LSB in µV, `2 * VREF / gain / 2^24`
+///
+/// For scaling non-integer statistics, the same scale
+/// [`code_to_microvolts`] rounds with.
+pub fn lsb_microvolts(gain: ChannelGain, vref_uv: u32) -> f32 {
+    2.0 * vref_uv as f32 / gain.factor() as f32 / (1u32 << 24) as f32
+}
+
+/// Sample representation in the frame reads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SampleFormat {
+    /// 24-bit code sign extended to `i32`, full scale `±2^23`
+    #[default]
+    SignExtended,
+    /// 24-bit code in the upper bytes, low byte zero, full scale `±2^31`
+    LeftJustified,
+}
+
+impl SampleFormat {
+    /// Convert a sign extended sample to this format
+    pub const fn from_sign_extended(self, sample: i32) -> i32 {
+        match self {
+            SampleFormat::SignExtended => sample,
+            SampleFormat::LeftJustified => sample << 8,
+        }
+    }
+}
+
+/// Status word sync check policy of the frame reads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FrameValidation {
+    /// Fail with `StatusWordMissmatch`
+    #[default]
+    Strict,
+    /// Return the frame anyway, check `sync_ok()`
+    Lenient,
+}
+
+impl FrameValidation {
+    /// Check the sync nibble of status word `raw` as the policy says
+    pub(crate) fn check<E>(self, raw: [u8; 3]) -> crate::Ads129xResult<(), E> {
+        match self {
+            FrameValidation::Strict if raw[0] >> 4 != 0b1100 => {
+                Err(crate::Ads129xError::StatusWordMissmatch { raw })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Raw frame rejected by `from_bytes`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FrameError {
+    /// Input length doesn't fit the frame
+    Length { expected: usize, actual: usize },
+    /// Status word sync nibble isn't `0b1100`
+    Sync(u8),
+}
+
+impl core::fmt::Display for FrameError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Length { expected, actual } => {
+                write!(f, "frame length {}, expected {}", actual, expected)
+            }
+            Self::Sync(sync) => write!(
+                f,
+                "status word sync mismatch (got {:#X}, expected 0xC)",
+                sync
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FrameError {}
+
+/// Parse frame as clocked out by the device into `frame`
+///
+/// Counterpart of `read_raw_frame`. `bytes` must hold exactly `3 + 3 * CH`
+/// bytes, samples are sign extended. On a status word sync mismatch `frame`
+/// still holds the parsed bytes.
+pub fn parse_frame<const CH: usize>(
+    bytes: &[u8],
+    frame: &mut DataFrame<CH>,
+) -> Result<(), FrameError> {
+    parse_frame_with(bytes, frame, SampleFormat::SignExtended)
+}
+
+/// `parse_frame` storing samples in `format`
+pub fn parse_frame_with<const CH: usize>(
+    bytes: &[u8],
+    frame: &mut DataFrame<CH>,
+    format: SampleFormat,
+) -> Result<(), FrameError> {
+    let expected = DataFrame::<CH>::RAW_LEN;
+    if bytes.len() != expected {
+        return Err(FrameError::Length {
+            expected,
+            actual: bytes.len(),
+        });
+    }
+
+    frame.read_raw(bytes);
+    for sample in frame.data.iter_mut() {
+        *sample = format.from_sign_extended(*sample);
+    }
+    match frame.status_word().sync() {
+        SYNC => Ok(()),
+        sync => Err(FrameError::Sync(sync)),
+    }
+}
+
+/// Serialize status word then little endian samples
+fn write_frame(status_word: &[u8; 3], data: &[i32], buf: &mut [u8]) -> Result<usize, FrameError> {
+    let len = STATUS_LEN + 4 * data.len();
+    if buf.len() < len {
+        return Err(FrameError::Length {
+            expected: len,
+            actual:   buf.len(),
+        });
+    }
+
+    buf[..STATUS_LEN].copy_from_slice(status_word);
+    for (chunk, sample) in buf[STATUS_LEN..].chunks_exact_mut(4).zip(data) {
+        chunk.copy_from_slice(&sample.to_le_bytes());
+    }
+    Ok(len)
+}
+
+/// Counterpart of `write_frame`
+fn read_frame(buf: &[u8], status_word: &mut [u8; 3], data: &mut [i32]) -> Result<(), FrameError> {
+    let len = STATUS_LEN + 4 * data.len();
+    if buf.len() < len {
+        return Err(FrameError::Length {
+            expected: len,
+            actual:   buf.len(),
+        });
+    }
+
+    status_word.copy_from_slice(&buf[..STATUS_LEN]);
+    for (chunk, sample) in buf[STATUS_LEN..].chunks_exact(4).zip(data) {
+        *sample = i32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    Ok(())
+}
 
 bitfield! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
     pub struct DataStatusWord92(u32);
     impl Debug;
 
     pub u8, sync, set_sync : 23, 20;
-    pub u8, loff_stat, set_loff_stat : 19, 16;
-    pub u8, gpio, set_gpio : 15, 14;
+    /// `LOFF_STAT[4:0]`, `RLD_STAT`, `IN2N_OFF`, `IN2P_OFF`, `IN1N_OFF`, `IN1P_OFF`
+    pub u8, loff_stat, set_loff_stat : 19, 15;
+    /// `GPIOD[2:1]`
+    pub u8, gpio, set_gpio : 14, 13;
+
+    /// `RLD` electrode off
+    pub rld_off, _ : 19;
+    /// `IN2N` electrode off
+    pub in2n_off, _ : 18;
+    /// `IN2P` electrode off
+    pub in2p_off, _ : 17;
+    /// `IN1N` electrode off
+    pub in1n_off, _ : 16;
+    /// `IN1P` electrode off
+    pub in1p_off, _ : 15;
+    /// `GPIOD2` level
+    pub gpio2, _ : 14;
+    /// `GPIOD1` level
+    pub gpio1, _ : 13;
+}
+
+impl DataStatusWord92 {
+    /// Sync nibble reads `0b1100`
+    pub fn sync_ok(&self) -> bool {
+        self.sync() == SYNC
+    }
+
+    /// Check the sync nibble, the mismatching nibble is returned
+    pub fn validate(&self) -> Result<(), u8> {
+        match self.sync() {
+            SYNC => Ok(()),
+            sync => Err(sync),
+        }
+    }
+}
+
+/// Decoded ADS1292 status word flags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrameStatus92 {
+    pub ch1_positive_leadoff: bool,
+    pub ch1_negative_leadoff: bool,
+    pub ch2_positive_leadoff: bool,
+    pub ch2_negative_leadoff: bool,
+    pub rld_leadoff:          bool,
+    /// GPIO pin levels, GPIO1 first
+    pub gpio:                 [bool; 2],
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
 pub struct DataFrame92 {
     pub status_word: [u8; 3],
     pub data:        [i32; 2],
@@ -31,10 +273,167 @@ impl DataFrame92 {
         )
     }
 
-    pub fn as_bytes(&self) -> &[u8] {
-        // #SAFETY
-        // It's safe to recast C, packed struct as bytes
-        unsafe { core::slice::from_raw_parts(self as *const _ as *const u8, size_of::<Self>()) }
+    /// Status word sync nibble reads `0b1100`
+    pub fn sync_ok(&self) -> bool {
+        self.status_word().sync_ok()
+    }
+
+    pub fn ch1_positive_leadoff(&self) -> bool {
+        self.status_word().in1p_off()
+    }
+
+    pub fn ch1_negative_leadoff(&self) -> bool {
+        self.status_word().in1n_off()
+    }
+
+    pub fn ch2_positive_leadoff(&self) -> bool {
+        self.status_word().in2p_off()
+    }
+
+    pub fn ch2_negative_leadoff(&self) -> bool {
+        self.status_word().in2n_off()
+    }
+
+    pub fn rld_leadoff(&self) -> bool {
+        self.status_word().rld_off()
+    }
+
+    /// GPIO pin level, `pin` 0 is GPIO1, `false` for an out of range pin
+    pub fn gpio(&self, pin: usize) -> bool {
+        match pin {
+            0 => self.status_word().gpio1(),
+            1 => self.status_word().gpio2(),
+            _ => false,
+        }
+    }
+
+    /// Decode lead-off and GPIO flags
+    pub fn status(&self) -> FrameStatus92 {
+        FrameStatus92 {
+            ch1_positive_leadoff: self.ch1_positive_leadoff(),
+            ch1_negative_leadoff: self.ch1_negative_leadoff(),
+            ch2_positive_leadoff: self.ch2_positive_leadoff(),
+            ch2_negative_leadoff: self.ch2_negative_leadoff(),
+            rld_leadoff:          self.rld_leadoff(),
+            gpio:                 [self.gpio(0), self.gpio(1)],
+        }
+    }
+
+    /// Convert samples to µV, see [`code_to_microvolts`]
+    pub fn to_microvolts(
+        &self,
+        gains: &[ads1292::chan::ChannelGain; 2],
+        vref_uv: u32,
+        out: &mut [i32; 2],
+    ) {
+        for ((uv, &code), gain) in out.iter_mut().zip(&self.data).zip(gains) {
+            *uv = code_to_microvolts(code, *gain, vref_uv);
+        }
+    }
+
+    /// Samples in channel order, ADS1291 reads its channel 2 slot as zero
+    ///
+    /// ```
+    /// use ads129x::data::DataFrame92;
+    ///
+    /// let mut frame = DataFrame92::new();
+    /// frame.data = [-5, 7];
+    /// assert_eq!(frame.channels().sum::<i32>(), 2);
+    /// ```
+    pub fn channels(&self) -> impl Iterator<Item = i32> + '_ {
+        self.data.iter().copied()
+    }
+
+    /// Sample of channel `idx + 1`, `None` past channel 2
+    pub fn get(&self, idx: usize) -> Option<i32> {
+        self.data.get(idx).copied()
+    }
+
+    /// Samples with their channel index, index 0 is channel 1
+    pub fn iter_indexed(&self) -> impl Iterator<Item = (usize, i32)> + '_ {
+        self.channels().enumerate()
+    }
+
+    /// Serialized frame length, see [`write_to`](Self::write_to)
+    pub const fn wire_len() -> usize {
+        STATUS_LEN + 4 * 2
+    }
+
+    /// Serialize frame into `buf`
+    ///
+    /// Layout: the 3 status word bytes as clocked out, then each sample as
+    /// little endian `i32`, no padding. Returns the written length,
+    /// [`wire_len`](Self::wire_len).
+    pub fn write_to(&self, buf: &mut [u8]) -> Result<usize, FrameError> {
+        write_frame(&self.status_word, &self.data, buf)
+    }
+
+    /// Deserialize frame written by [`write_to`](Self::write_to)
+    ///
+    /// Bytes past [`wire_len`](Self::wire_len) are ignored, the status word
+    /// isn't validated.
+    pub fn read_from(buf: &[u8]) -> Result<Self, FrameError> {
+        let mut frame = Self::new();
+        read_frame(buf, &mut frame.status_word, &mut frame.data)?;
+        Ok(frame)
+    }
+
+    /// Frame length as clocked out by the device
+    pub(crate) const RAW_LEN: usize = STATUS_LEN + SAMPLE_LEN * 2;
+
+    /// Frame length as clocked out by the device, see `read_raw_frame`
+    pub const fn raw_frame_len() -> usize {
+        Self::RAW_LEN
+    }
+
+    /// Parse frame as clocked out by the device
+    ///
+    /// `bytes` must hold exactly the status word and both channels, samples
+    /// are sign extended and the status word sync is checked.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FrameError> {
+        if bytes.len() != Self::RAW_LEN {
+            return Err(FrameError::Length {
+                expected: Self::RAW_LEN,
+                actual:   bytes.len(),
+            });
+        }
+
+        let mut frame = Self::new();
+        frame.read_raw(bytes);
+        match frame.status_word().sync() {
+            SYNC => Ok(frame),
+            sync => Err(FrameError::Sync(sync)),
+        }
+    }
+
+    /// Write frame in the device wire layout, `buf` must hold `RAW_LEN` bytes
+    #[cfg(feature = "std")]
+    pub(crate) fn write_raw(&self, buf: &mut [u8]) {
+        buf[..STATUS_LEN].copy_from_slice(&self.status_word);
+        for (chunk, sample) in buf[STATUS_LEN..].chunks_exact_mut(SAMPLE_LEN).zip(&self.data) {
+            chunk.copy_from_slice(&i24_to_be(*sample));
+        }
+    }
+
+    /// Read frame from the device wire layout, `buf` must hold `RAW_LEN` bytes
+    pub(crate) fn read_raw(&mut self, buf: &[u8]) {
+        self.status_word.copy_from_slice(&buf[..STATUS_LEN]);
+        for (chunk, sample) in buf[STATUS_LEN..].chunks_exact(SAMPLE_LEN).zip(&mut self.data) {
+            *sample = i24_from_be(chunk);
+        }
+    }
+}
+
+/// Sample of channel `idx + 1`
+///
+/// # Panics
+///
+/// When `idx` is past channel 2, [`DataFrame92::get`] is the checked access.
+impl core::ops::Index<usize> for DataFrame92 {
+    type Output = i32;
+
+    fn index(&self, idx: usize) -> &i32 {
+        &self.data[idx]
     }
 }
 
@@ -48,6 +447,7 @@ impl Default for DataFrame92 {
 }
 
 bitfield! {
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
     pub struct DataStatusWord(u32);
     impl Debug;
 
@@ -57,9 +457,40 @@ bitfield! {
     pub u8, gpio, set_gpio : 3, 0;
 }
 
+/// Decoded ADS1294/6/8 status word
+///
+/// Entries past the device's channel count are always `false`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Status {
+    /// Sync nibble reads `0b1100`
+    pub sync_ok:          bool,
+    /// `INxP` electrode off, channel 1 first
+    pub leadoff_positive: [bool; 8],
+    /// `INxN` electrode off, channel 1 first
+    pub leadoff_negative: [bool; 8],
+    /// GPIO pin levels, GPIO1 first
+    pub gpio:             [bool; 4],
+}
+
+/// Frame with the time it was captured at, see `read_data_timestamped`
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimestampedFrame<const CH: usize> {
+    /// Timestamp source value sampled as nCS was asserted
+    pub timestamp: u64,
+    pub frame:     DataFrame<CH>,
+}
+
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
 pub struct DataFrame<const CH: usize> {
     pub status_word: [u8; 3],
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
     pub data:        [i32; CH],
 }
 
@@ -76,13 +507,138 @@ impl<const CH: usize> DataFrame<CH> {
                 | (self.status_word[2] as u32) << 0 * 8,
         )
     }
+
+    /// Status word sync nibble reads `0b1100`
+    pub fn sync_ok(&self) -> bool {
+        self.status_word().sync() == SYNC
+    }
+
+    /// Decode sync, lead-off and GPIO flags
+    pub fn status(&self) -> Status {
+        let word = self.status_word();
+        let mut status = Status {
+            sync_ok: word.sync() == SYNC,
+            ..Default::default()
+        };
+        for ch in 0..CH.min(8) {
+            status.leadoff_positive[ch] = word.loff_statp() & 1 << ch != 0;
+            status.leadoff_negative[ch] = word.loff_statn() & 1 << ch != 0;
+        }
+        for (pin, level) in status.gpio.iter_mut().enumerate() {
+            *level = word.gpio() & 1 << pin != 0;
+        }
+        status
+    }
+
+    /// Convert samples to µV, see [`code_to_microvolts`]
+    pub fn to_microvolts(&self, gains: &[ChannelGain; CH], vref_uv: u32, out: &mut [i32; CH]) {
+        for ((uv, &code), gain) in out.iter_mut().zip(&self.data).zip(gains) {
+            *uv = code_to_microvolts(code, *gain, vref_uv);
+        }
+    }
+
+    /// Samples in channel order
+    ///
+    /// ```
+    /// use ads129x::data::DataFrame;
+    ///
+    /// fn sum<const CH: usize>(frame: &DataFrame<CH>) -> i64 {
+    ///     frame.channels().map(i64::from).sum()
+    /// }
+    ///
+    /// let mut frame = DataFrame::<4>::new();
+    /// frame.data = [1, -2, 3, -4];
+    /// assert_eq!(sum(&frame), -2);
+    /// ```
+    pub fn channels(&self) -> impl Iterator<Item = i32> + '_ {
+        self.data.iter().copied()
+    }
+
+    /// Sample of channel `idx + 1`, `None` past the last channel
+    pub fn get(&self, idx: usize) -> Option<i32> {
+        self.data.get(idx).copied()
+    }
+
+    /// Samples with their channel index, index 0 is channel 1
+    pub fn iter_indexed(&self) -> impl Iterator<Item = (usize, i32)> + '_ {
+        self.channels().enumerate()
+    }
 }
 
 impl<const CH: usize> DataFrame<CH> {
-    pub fn as_bytes(&self) -> &[u8] {
-        // #SAFETY
-        // It's safe to recast C, packed struct as bytes
-        unsafe { core::slice::from_raw_parts(self as *const _ as *const u8, size_of::<Self>()) }
+    /// Fails to compile when evaluated for `CH > MAX_CHANNELS`
+    pub(crate) const CHANNELS_FIT: () = assert!(CH <= MAX_CHANNELS, "at most 8 channels");
+
+    /// Serialized frame length, see [`write_to`](Self::write_to)
+    pub const fn wire_len() -> usize {
+        STATUS_LEN + 4 * CH
+    }
+
+    /// Serialize frame into `buf`
+    ///
+    /// Layout: the 3 status word bytes as clocked out, then each sample as
+    /// little endian `i32`, no padding. Returns the written length,
+    /// [`wire_len`](Self::wire_len).
+    pub fn write_to(&self, buf: &mut [u8]) -> Result<usize, FrameError> {
+        write_frame(&self.status_word, &self.data, buf)
+    }
+
+    /// Deserialize frame written by [`write_to`](Self::write_to)
+    ///
+    /// Bytes past [`wire_len`](Self::wire_len) are ignored, the status word
+    /// isn't validated.
+    pub fn read_from(buf: &[u8]) -> Result<Self, FrameError> {
+        let mut frame = Self::new();
+        read_frame(buf, &mut frame.status_word, &mut frame.data)?;
+        Ok(frame)
+    }
+
+    /// Frame length as clocked out by the device
+    pub(crate) const RAW_LEN: usize = STATUS_LEN + SAMPLE_LEN * CH;
+
+    /// Frame length as clocked out by the device, see `read_raw_frame`
+    pub const fn raw_frame_len() -> usize {
+        Self::RAW_LEN
+    }
+
+    /// Parse frame as clocked out by the device
+    ///
+    /// `bytes` must hold exactly `3 + 3 * CH` bytes, samples are sign
+    /// extended and the status word sync is checked.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FrameError> {
+        let mut frame = Self::new();
+        parse_frame(bytes, &mut frame)?;
+        Ok(frame)
+    }
+
+    /// Write frame in the device wire layout, `buf` must hold `RAW_LEN` bytes
+    #[cfg(feature = "std")]
+    pub(crate) fn write_raw(&self, buf: &mut [u8]) {
+        buf[..STATUS_LEN].copy_from_slice(&self.status_word);
+        for (chunk, sample) in buf[STATUS_LEN..].chunks_exact_mut(SAMPLE_LEN).zip(&self.data) {
+            chunk.copy_from_slice(&i24_to_be(*sample));
+        }
+    }
+
+    /// Read frame from the device wire layout, `buf` must hold `RAW_LEN` bytes
+    pub(crate) fn read_raw(&mut self, buf: &[u8]) {
+        self.status_word.copy_from_slice(&buf[..STATUS_LEN]);
+        for (chunk, sample) in buf[STATUS_LEN..].chunks_exact(SAMPLE_LEN).zip(&mut self.data) {
+            *sample = i24_from_be(chunk);
+        }
+    }
+}
+
+/// Sample of channel `idx + 1`
+///
+/// # Panics
+///
+/// When `idx >= CH`, [`DataFrame::get`] is the checked access.
+impl<const CH: usize> core::ops::Index<usize> for DataFrame<CH> {
+    type Output = i32;
+
+    fn index(&self, idx: usize) -> &i32 {
+        &self.data[idx]
     }
 }
 
@@ -108,3 +664,97 @@ impl<const CH: usize> core::fmt::Debug for DataFrame<CH> {
         Ok(())
     }
 }
+
+/// [`DataFrame92`] with its padding spelled out, for byte casts
+///
+/// Every byte is initialized, so the frame is [`bytemuck::Pod`].
+#[cfg(feature = "bytemuck")]
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct PodFrame92 {
+    pub status_word: [u8; 3],
+    _pad:            u8,
+    pub data:        [i32; 2],
+}
+
+/// [`DataFrame`] with its padding spelled out, for byte casts
+///
+/// Every byte is initialized, so the frame is [`bytemuck::Pod`].
+#[cfg(feature = "bytemuck")]
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct PodFrame<const CH: usize> {
+    pub status_word: [u8; 3],
+    _pad:            u8,
+    pub data:        [i32; CH],
+}
+
+#[cfg(feature = "bytemuck")]
+impl PodFrame92 {
+    /// View frame as bytes, in memory layout
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::bytes_of(self)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<const CH: usize> PodFrame<CH> {
+    /// View frame as bytes, in memory layout
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::bytes_of(self)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl From<DataFrame92> for PodFrame92 {
+    fn from(frame: DataFrame92) -> Self {
+        PodFrame92 {
+            status_word: frame.status_word,
+            _pad:        0,
+            data:        frame.data,
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl From<PodFrame92> for DataFrame92 {
+    fn from(frame: PodFrame92) -> Self {
+        DataFrame92 {
+            status_word: frame.status_word,
+            data:        frame.data,
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<const CH: usize> From<DataFrame<CH>> for PodFrame<CH> {
+    fn from(frame: DataFrame<CH>) -> Self {
+        PodFrame {
+            status_word: frame.status_word,
+            _pad:        0,
+            data:        frame.data,
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<const CH: usize> From<PodFrame<CH>> for DataFrame<CH> {
+    fn from(frame: PodFrame<CH>) -> Self {
+        DataFrame {
+            status_word: frame.status_word,
+            data:        frame.data,
+        }
+    }
+}
+
+// #SAFETY
+// Both frames are `repr(C)` built from plain integers with the padding byte
+// spelled out, so every byte is initialized and any bit pattern is valid.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for PodFrame92 {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for PodFrame92 {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<const CH: usize> bytemuck::Zeroable for PodFrame<CH> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<const CH: usize> bytemuck::Pod for PodFrame<CH> {}
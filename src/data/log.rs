@@ -0,0 +1,440 @@
+//! Binary acquisition log
+//!
+//! Recorded samples are only meaningful together with the gains and data rates they were
+//! acquired with, so every log starts with a header carrying the device configuration,
+//! followed by length-prefixed frame records.
+//!
+//! # Format
+//!
+//! All multi-byte integers are little-endian.
+//!
+//! Header (version 1):
+//!
+//! | Offset | Size | Field                                                      |
+//! |--------|------|------------------------------------------------------------|
+//! | 0      | 4    | Magic `b"A129"`                                            |
+//! | 4      | 1    | Format version, currently `1`                              |
+//! | 5      | 1    | Device family, see [`Family`]                              |
+//! | 6      | 1    | Channel count                                              |
+//! | 7      | 1    | Configuration length `N`                                   |
+//! | 8      | `N`  | Device configuration, see [`LogConfig`]                    |
+//!
+//! Record, repeated until the end of the stream:
+//!
+//! | Offset | Size | Field                                                      |
+//! |--------|------|------------------------------------------------------------|
+//! | 0      | 2    | Record length `L`                                          |
+//! | 2      | `L`  | Frame in device wire layout: status word, i24 BE samples   |
+//!
+//! Readers must reject any version they don't know.
+
+use std::io::{self, Read, Write};
+use std::vec::Vec;
+
+use core::convert::{TryFrom, TryInto};
+use core::ops::RangeInclusive;
+
+use super::{DataFrame, DataFrame92};
+use crate::common::Family;
+use crate::{ads1292, ads1298};
+
+/// Log magic
+pub const MAGIC: [u8; 4] = *b"A129";
+/// Current log format version
+pub const VERSION: u8 = 1;
+
+#[derive(Debug)]
+pub enum LogError {
+    /// Underlying stream error
+    Io(io::Error),
+    /// Stream doesn't start with [`MAGIC`]
+    BadMagic([u8; 4]),
+    /// Unknown format version
+    UnsupportedVersion(u8),
+    /// Unknown device family
+    UnknownFamily(u8),
+    /// Frame type doesn't match the log header
+    FrameMismatch { family: Family, channels: u8 },
+    /// Record length doesn't match the frame length
+    BadRecord(u16),
+    /// Configuration doesn't fit into the header
+    ConfigTooLong(usize),
+    /// Configuration doesn't decode for the log family and channel count
+    BadConfig,
+}
+
+impl core::fmt::Display for LogError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "log I/O error: {}", e),
+            Self::BadMagic(magic) => write!(f, "bad log magic {:02x?}", magic),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported log version {}", version)
+            }
+            Self::UnknownFamily(family) => write!(f, "unknown device family {:#04x}", family),
+            Self::FrameMismatch { family, channels } => write!(
+                f,
+                "frame doesn't match the {:?} log with {} channels",
+                family, channels
+            ),
+            Self::BadRecord(len) => write!(f, "record length {} doesn't match the frame", len),
+            Self::ConfigTooLong(len) => write!(f, "configuration of {} bytes too long", len),
+            Self::BadConfig => write!(f, "malformed device configuration"),
+        }
+    }
+}
+
+impl std::error::Error for LogError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for LogError {
+    fn from(e: io::Error) -> Self {
+        LogError::Io(e)
+    }
+}
+
+/// Device configuration carried in the log header
+///
+/// Encoded as the register values `apply_config` writes, in register order.
+/// ADS1291/2: `CONFIG1`..=`GPIO`, with the `LOFF_STAT` clock divider.
+/// ADS1294/6/8: `CONFIG1`..=`LOFF`, `CH1SET`..=`CHnSET`, `LOFF_SENSP`,
+/// `LOFF_SENSN`, `GPIO` and `CONFIG4`.
+pub trait LogConfig: Sized {
+    /// Device family of the configuration
+    const FAMILY: Family;
+
+    /// Channel counts the configuration applies to
+    const CHANNELS: RangeInclusive<u8>;
+
+    /// Append encoded configuration to `buf`
+    fn encode(&self, buf: &mut Vec<u8>);
+
+    /// Decode configuration, `None` for a malformed one
+    fn decode(bytes: &[u8]) -> Option<Self>;
+}
+
+impl LogConfig for ads1292::DeviceConfig {
+    const FAMILY: Family = Family::Ads1292;
+    const CHANNELS: RangeInclusive<u8> = 1..=2;
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        use ads1292::{chan, conf, gpio, loff, resp, rld};
+
+        let status = loff::LeadOffStatus {
+            clk_div: self.clk_div,
+            ..Default::default()
+        };
+        buf.extend_from_slice(&[
+            conf::Config1Reg::from(self.config).0,
+            conf::Config2Reg::from(self.misc).0,
+            loff::LeadOffControlReg::from(self.leadoff_control).0,
+            chan::ChanSetReg::from(self.channels[0]).0,
+            chan::ChanSetReg::from(self.channels[1]).0,
+            rld::RldSenseReg::from(self.rld_sense).0,
+            loff::LoffSense::from(self.leadoff_sense).0,
+            loff::LeadOffStatusReg::from(status).0,
+            resp::RespControl1Reg::from(self.resp).0,
+            resp::RespControl2Reg::from(self.resp2).0,
+            gpio::GpioReg::from(self.gpio).0,
+        ]);
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        use ads1292::{chan, conf, gpio, loff, resp, rld};
+
+        let regs: [u8; 11] = bytes.try_into().ok()?;
+        Some(ads1292::DeviceConfig {
+            config:          conf::Config::try_from(conf::Config1Reg(regs[0])).ok()?,
+            misc:            conf::MiscConfig::try_from(conf::Config2Reg(regs[1])).ok()?,
+            leadoff_control: loff::LeadOffControl::try_from(loff::LeadOffControlReg(regs[2]))
+                .ok()?,
+            channels:        [
+                chan::Chan::try_from(chan::ChanSetReg(regs[3])).ok()?,
+                chan::Chan::try_from(chan::ChanSetReg(regs[4])).ok()?,
+            ],
+            rld_sense:       rld::RldSense::try_from(rld::RldSenseReg(regs[5])).ok()?,
+            leadoff_sense:   loff::LeadOffSense::try_from(loff::LoffSense(regs[6])).ok()?,
+            clk_div:         loff::LeadOffStatus::try_from(loff::LeadOffStatusReg(regs[7]))
+                .ok()?
+                .clk_div,
+            resp:            resp::Resp1::try_from(resp::RespControl1Reg(regs[8])).ok()?,
+            resp2:           resp::Resp2::try_from(resp::RespControl2Reg(regs[9])).ok()?,
+            gpio:            gpio::Gpio::try_from(gpio::GpioReg(regs[10])).ok()?,
+        })
+    }
+}
+
+impl<const CH: usize> LogConfig for ads1298::DeviceConfig<CH> {
+    const FAMILY: Family = Family::Ads1298;
+    const CHANNELS: RangeInclusive<u8> = CH as u8..=CH as u8;
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        use ads1298::{chan, conf, gpio, loff};
+
+        buf.extend_from_slice(&[
+            conf::Config1Reg::from(self.config).0,
+            conf::Config2Reg::from(self.test_signal).0,
+            conf::Config3Reg::from(self.rld).0,
+            loff::LeadOffControlReg::from(self.leadoff_control).0,
+        ]);
+        buf.extend(
+            self.channels
+                .iter()
+                .map(|chan| chan::ChanSetReg::from(*chan).0),
+        );
+        buf.extend_from_slice(&[
+            loff::LeadOffSenseReg::from(self.leadoff_sense_positive).0,
+            loff::LeadOffSenseReg::from(self.leadoff_sense_negative).0,
+            gpio::GpioReg::from(self.gpio).0,
+            conf::Config4Reg::from(self.misc).0,
+        ]);
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        use ads1298::{chan, conf, gpio, loff};
+
+        if bytes.len() != 4 + CH + 4 {
+            return None;
+        }
+        let (head, rest) = bytes.split_at(4);
+        let (chans, tail) = rest.split_at(CH);
+
+        let mut config = ads1298::DeviceConfig::<CH> {
+            config: conf::Config::try_from(conf::Config1Reg(head[0])).ok()?,
+            test_signal: conf::TestSignalConfig::try_from(conf::Config2Reg(head[1])).ok()?,
+            rld: conf::RldConfig::try_from(conf::Config3Reg(head[2])).ok()?,
+            leadoff_control: loff::LeadOffControl::try_from(loff::LeadOffControlReg(head[3]))
+                .ok()?,
+            leadoff_sense_positive: loff::LeadOffSense::try_from(loff::LeadOffSenseReg(tail[0]))
+                .ok()?,
+            leadoff_sense_negative: loff::LeadOffSense::try_from(loff::LeadOffSenseReg(tail[1]))
+                .ok()?,
+            gpio: gpio::Gpio::try_from(gpio::GpioReg(tail[2])).ok()?,
+            misc: conf::MiscConfig::try_from(conf::Config4Reg(tail[3])).ok()?,
+            ..Default::default()
+        };
+        for (chan, reg) in config.channels.iter_mut().zip(chans) {
+            *chan = chan::Chan::try_from(chan::ChanSetReg(*reg)).ok()?;
+        }
+        Some(config)
+    }
+}
+
+/// Log writer
+pub struct Writer<W> {
+    inner:    W,
+    family:   Family,
+    channels: u8,
+}
+
+impl<W: Write> Writer<W> {
+    /// Create writer for a device with `channels` channels and emit the log
+    /// header
+    ///
+    /// The family follows the type of `config`.
+    pub fn new<C: LogConfig>(mut inner: W, channels: u8, config: &C) -> Result<Self, LogError> {
+        let family = C::FAMILY;
+        if !C::CHANNELS.contains(&channels) {
+            return Err(LogError::FrameMismatch { family, channels });
+        }
+        let mut encoded = Vec::new();
+        config.encode(&mut encoded);
+        if encoded.len() > u8::MAX as usize {
+            return Err(LogError::ConfigTooLong(encoded.len()));
+        }
+
+        inner.write_all(&MAGIC)?;
+        inner.write_all(&[VERSION, family as u8, channels, encoded.len() as u8])?;
+        inner.write_all(&encoded)?;
+
+        Ok(Writer {
+            inner,
+            family,
+            channels,
+        })
+    }
+
+    /// Append ADS1294/6/8 frame record
+    pub fn write_frame<const CH: usize>(&mut self, frame: &DataFrame<CH>) -> Result<(), LogError> {
+        self.check(Family::Ads1298, CH..=CH)?;
+
+        let mut buf = std::vec![0u8; DataFrame::<CH>::RAW_LEN];
+        frame.write_raw(&mut buf);
+        self.write_record(&buf)
+    }
+
+    /// Append ADS1291/2 frame record
+    pub fn write_frame92(&mut self, frame: &DataFrame92) -> Result<(), LogError> {
+        self.check(Family::Ads1292, 1..=2)?;
+
+        let mut buf = [0u8; DataFrame92::RAW_LEN];
+        frame.write_raw(&mut buf);
+        self.write_record(&buf)
+    }
+
+    /// Flush and return the underlying stream
+    pub fn finish(mut self) -> Result<W, LogError> {
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+
+    /// Frame of `family` carrying one of `channels` channel counts
+    fn check(&self, family: Family, channels: RangeInclusive<usize>) -> Result<(), LogError> {
+        if self.family != family || !channels.contains(&(self.channels as usize)) {
+            return Err(LogError::FrameMismatch {
+                family:   self.family,
+                channels: self.channels,
+            });
+        }
+        Ok(())
+    }
+
+    fn write_record(&mut self, buf: &[u8]) -> Result<(), LogError> {
+        self.inner.write_all(&(buf.len() as u16).to_le_bytes())?;
+        self.inner.write_all(buf)?;
+        Ok(())
+    }
+}
+
+/// Log reader
+pub struct Reader<R> {
+    inner:    R,
+    family:   Family,
+    channels: u8,
+    config:   Vec<u8>,
+}
+
+impl<R: Read> Reader<R> {
+    /// Create reader and validate the log header
+    pub fn new(mut inner: R) -> Result<Self, LogError> {
+        let mut magic = [0u8; 4];
+        inner.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(LogError::BadMagic(magic));
+        }
+
+        let mut head = [0u8; 4];
+        inner.read_exact(&mut head)?;
+        let [version, family, channels, config_len] = head;
+        if version != VERSION {
+            return Err(LogError::UnsupportedVersion(version));
+        }
+        let family = match family {
+            0x00 => Family::Ads1292,
+            0x01 => Family::Ads1298,
+            v => return Err(LogError::UnknownFamily(v)),
+        };
+
+        let mut config = std::vec![0u8; config_len as usize];
+        inner.read_exact(&mut config)?;
+
+        Ok(Reader {
+            inner,
+            family,
+            channels,
+            config,
+        })
+    }
+
+    /// Device family the log was recorded with
+    pub fn family(&self) -> Family {
+        self.family
+    }
+
+    /// Channel count the log was recorded with
+    pub fn channels(&self) -> u8 {
+        self.channels
+    }
+
+    /// Configuration the log was recorded with
+    ///
+    /// Fails with [`LogError::FrameMismatch`] when `C` doesn't match the
+    /// family and channel count of the log.
+    pub fn config<C: LogConfig>(&self) -> Result<C, LogError> {
+        if self.family != C::FAMILY || !C::CHANNELS.contains(&self.channels) {
+            return Err(LogError::FrameMismatch {
+                family:   self.family,
+                channels: self.channels,
+            });
+        }
+        C::decode(&self.config).ok_or(LogError::BadConfig)
+    }
+
+    /// Read next ADS1294/6/8 frame record, `None` at the end of the stream
+    pub fn read_frame<const CH: usize>(&mut self) -> Result<Option<DataFrame<CH>>, LogError> {
+        self.check(Family::Ads1298, CH..=CH)?;
+
+        let mut buf = std::vec![0u8; DataFrame::<CH>::RAW_LEN];
+        if !self.read_record(&mut buf)? {
+            return Ok(None);
+        }
+
+        let mut frame = DataFrame::<CH>::new();
+        frame.read_raw(&buf);
+        Ok(Some(frame))
+    }
+
+    /// Read next ADS1291/2 frame record, `None` at the end of the stream
+    pub fn read_frame92(&mut self) -> Result<Option<DataFrame92>, LogError> {
+        self.check(Family::Ads1292, 1..=2)?;
+
+        let mut buf = [0u8; DataFrame92::RAW_LEN];
+        if !self.read_record(&mut buf)? {
+            return Ok(None);
+        }
+
+        let mut frame = DataFrame92::new();
+        frame.read_raw(&buf);
+        Ok(Some(frame))
+    }
+
+    /// Iterate over the remaining ADS1294/6/8 frame records
+    ///
+    /// Iteration stops after the first error.
+    pub fn frames<const CH: usize>(
+        &mut self,
+    ) -> impl Iterator<Item = Result<DataFrame<CH>, LogError>> + '_ {
+        let mut failed = false;
+        core::iter::from_fn(move || {
+            if failed {
+                return None;
+            }
+            let res = self.read_frame::<CH>().transpose();
+            failed = matches!(res, Some(Err(_)));
+            res
+        })
+    }
+
+    /// Frame of `family` carrying one of `channels` channel counts
+    fn check(&self, family: Family, channels: RangeInclusive<usize>) -> Result<(), LogError> {
+        if self.family != family || !channels.contains(&(self.channels as usize)) {
+            return Err(LogError::FrameMismatch {
+                family:   self.family,
+                channels: self.channels,
+            });
+        }
+        Ok(())
+    }
+
+    fn read_record(&mut self, buf: &mut [u8]) -> Result<bool, LogError> {
+        let mut len = [0u8; 2];
+        match self.inner.read_exact(&mut len) {
+            Ok(()) => (),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(false),
+            Err(e) => return Err(e.into()),
+        }
+
+        let len = u16::from_le_bytes(len);
+        if len as usize != buf.len() {
+            return Err(LogError::BadRecord(len));
+        }
+        self.inner.read_exact(buf)?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,205 @@
+//! Running per-channel statistics
+
+use super::{lsb_microvolts, DataFrame};
+use crate::common::ChannelGain;
+
+/// Per-channel min/max/mean/RMS accumulator
+///
+/// Keeps running sums only, the window itself isn't stored. Sums don't overflow
+/// for full scale 24-bit samples over far more than 2^20 updates.
+#[derive(Debug, Clone, Copy)]
+pub struct ChannelStats<const CH: usize> {
+    count:  u64,
+    min:    [i32; CH],
+    max:    [i32; CH],
+    sum:    [i64; CH],
+    sum_sq: [u128; CH],
+}
+
+/// Statistics snapshot in raw counts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatsReport<const CH: usize> {
+    /// Number of accumulated frames
+    pub count: u64,
+    /// Mean, rounded to nearest
+    pub mean:  [i32; CH],
+    pub min:   [i32; CH],
+    pub max:   [i32; CH],
+    /// Root mean square, rounded down
+    pub rms:   [u32; CH],
+}
+
+/// Statistics snapshot in µV
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatsReportUv<const CH: usize> {
+    /// Number of accumulated frames
+    pub count: u64,
+    pub mean:  [f32; CH],
+    pub min:   [f32; CH],
+    pub max:   [f32; CH],
+    pub rms:   [f32; CH],
+}
+
+impl<const CH: usize> ChannelStats<CH> {
+    pub fn new() -> Self {
+        ChannelStats {
+            count:  0,
+            min:    [i32::MAX; CH],
+            max:    [i32::MIN; CH],
+            sum:    [0; CH],
+            sum_sq: [0; CH],
+        }
+    }
+
+    /// Accumulate frame samples
+    pub fn update(&mut self, frame: &DataFrame<CH>) {
+        self.count += 1;
+        for (ch, &sample) in frame.data.iter().enumerate() {
+            self.min[ch] = self.min[ch].min(sample);
+            self.max[ch] = self.max[ch].max(sample);
+            self.sum[ch] += sample as i64;
+            self.sum_sq[ch] += (sample as i64 * sample as i64) as u128;
+        }
+    }
+
+    /// Number of accumulated frames
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Current statistics, all zero before the first update
+    pub fn snapshot(&self) -> StatsReport<CH> {
+        let mut report = StatsReport {
+            count: self.count,
+            mean:  [0; CH],
+            min:   [0; CH],
+            max:   [0; CH],
+            rms:   [0; CH],
+        };
+        if self.count == 0 {
+            return report;
+        }
+
+        report.min = self.min;
+        report.max = self.max;
+        let count = self.count as i64;
+        for ch in 0..CH {
+            // Round half away from zero
+            let half = if self.sum[ch] < 0 {
+                -count / 2
+            } else {
+                count / 2
+            };
+            report.mean[ch] = ((self.sum[ch] + half) / count) as i32;
+            report.rms[ch] = (self.sum_sq[ch] / self.count as u128).isqrt() as u32;
+        }
+        report
+    }
+
+    /// Standard deviation around the mean, rounded down, zero before the first
+    /// update
+    pub fn std_dev(&self) -> [u32; CH] {
+        let mut std_dev = [0; CH];
+        if self.count == 0 {
+            return std_dev;
+        }
+
+        let count = self.count as u128;
+        for (ch, dev) in std_dev.iter_mut().enumerate() {
+            let sum = self.sum[ch].unsigned_abs() as u128;
+            // n * Σx² - (Σx)² is never negative
+            let var = (count * self.sum_sq[ch] - sum * sum) / (count * count);
+            *dev = var.isqrt() as u32;
+        }
+        std_dev
+    }
+
+    /// Drop accumulated samples
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<const CH: usize> Default for ChannelStats<CH> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CH: usize> StatsReport<CH> {
+    /// Scale to µV
+    ///
+    /// `gains` holds each channel's PGA gain, see [`lsb_microvolts`].
+    pub fn to_microvolts(&self, vref_uv: u32, gains: &[ChannelGain; CH]) -> StatsReportUv<CH> {
+        let mut report = StatsReportUv {
+            count: self.count,
+            mean:  [0.0; CH],
+            min:   [0.0; CH],
+            max:   [0.0; CH],
+            rms:   [0.0; CH],
+        };
+        for (ch, &gain) in gains.iter().enumerate() {
+            let lsb = lsb_microvolts(gain, vref_uv);
+            report.mean[ch] = self.mean[ch] as f32 * lsb;
+            report.min[ch] = self.min[ch] as f32 * lsb;
+            report.max[ch] = self.max[ch] as f32 * lsb;
+            report.rms[ch] = self.rms[ch] as f32 * lsb;
+        }
+        report
+    }
+}
+
+/// Shorted input measurement of one channel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChannelNoise {
+    /// PGA gain during the measurement
+    pub gain:      ChannelGain,
+    /// Mean in counts, rounded to nearest
+    pub offset:    i32,
+    /// Standard deviation in counts, rounded down
+    pub noise:     u32,
+    pub offset_uv: f32,
+    /// RMS noise in µV
+    pub noise_uv:  f32,
+}
+
+/// Offset and noise of every channel with shorted inputs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseReport<const CH: usize> {
+    /// Number of accumulated frames
+    pub frames:   usize,
+    /// `None` for powered down channels
+    pub channels: [Option<ChannelNoise>; CH],
+}
+
+impl<const CH: usize> NoiseReport<CH> {
+    /// Build from accumulated samples
+    ///
+    /// `gains` holds each channel's PGA gain, `None` for channels left out. µV
+    /// values are scaled with [`lsb_microvolts`].
+    pub fn from_stats(
+        stats: &ChannelStats<CH>,
+        vref_uv: u32,
+        gains: &[Option<ChannelGain>; CH],
+    ) -> Self {
+        let mean = stats.snapshot().mean;
+        let std_dev = stats.std_dev();
+        let mut channels = [None; CH];
+        for (ch, gain) in gains.iter().enumerate() {
+            channels[ch] = gain.map(|gain| {
+                let lsb = lsb_microvolts(gain, vref_uv);
+                ChannelNoise {
+                    gain,
+                    offset: mean[ch],
+                    noise: std_dev[ch],
+                    offset_uv: mean[ch] as f32 * lsb,
+                    noise_uv: std_dev[ch] as f32 * lsb,
+                }
+            });
+        }
+        NoiseReport {
+            frames: stats.count() as usize,
+            channels,
+        }
+    }
+}
@@ -0,0 +1,441 @@
+//! Driver owning its delay provider
+//!
+//! [`WithDelay`] wraps a driver together with a `DelayUs` and offers every
+//! delay taking method without the delay parameter. Other methods are reached
+//! through `Deref`, the per-call API stays on [`Ads129x`] for delays shared
+//! between drivers. Register accessors are expanded from the same tables as
+//! the driver's, the other methods are forwarded one by one.
+//!
+//! ```ignore
+//! let ads = Ads129x::new_ads1298_with_delay(spi, ncs, delay);
+//! let mut ads = ads.set_command_mode()?;
+//! ads.apply_config(&config)?;
+//! let (spi, ncs, delay) = ads.destroy();
+//! ```
+
+use core::ops::{Deref, DerefMut};
+
+use ehal::blocking::delay::DelayUs;
+use ehal::blocking::spi::{Transfer, Write};
+use ehal::digital::v2::{InputPin, OutputPin};
+use embedded_hal as ehal;
+
+use crate::{ads1292, ads1298, common, data, mode, pins, spi, variant};
+use crate::{Ads1292Family, Ads1298Family, Ads129x, Ads129xResult};
+
+/// Typestate driver with a stored delay
+type Driver<SPI, NCS, DEV, const CH: usize, PINS, MODE, D> =
+    WithDelay<Ads129x<SPI, NCS, DEV, CH, PINS, MODE>, D>;
+
+/// Driver and the delay provider it uses
+pub struct WithDelay<DRV, D> {
+    driver: DRV,
+    delay:  D,
+}
+
+impl<DRV, D> WithDelay<DRV, D> {
+    pub fn new(driver: DRV, delay: D) -> Self {
+        WithDelay { driver, delay }
+    }
+
+    /// Stored delay, e.g. for waits between calls
+    pub fn delay(&mut self) -> &mut D {
+        &mut self.delay
+    }
+
+    /// Split into the per-call driver and the delay
+    pub fn into_parts(self) -> (DRV, D) {
+        (self.driver, self.delay)
+    }
+}
+
+impl<DRV, D> Deref for WithDelay<DRV, D> {
+    type Target = DRV;
+
+    fn deref(&self) -> &DRV {
+        &self.driver
+    }
+}
+
+impl<DRV, D> DerefMut for WithDelay<DRV, D> {
+    fn deref_mut(&mut self) -> &mut DRV {
+        &mut self.driver
+    }
+}
+
+impl<SPI, NCS, DEV, D, const CH: usize, MODE>
+    WithDelay<Ads129x<SPI, NCS, DEV, CH, pins::ControlPins, MODE>, D>
+{
+    /// Hand START/RESET/PWDN pins over to the driver
+    pub fn with_control_pins<PINS>(
+        self,
+        control_pins: PINS,
+    ) -> WithDelay<Ads129x<SPI, NCS, DEV, CH, PINS, MODE>, D> {
+        WithDelay::new(self.driver.with_control_pins(control_pins), self.delay)
+    }
+}
+
+impl<SPI, NCS, DEV, D, const CH: usize, STARTP, RESETP, PWDNP, MODE>
+    Driver<SPI, NCS, DEV, CH, pins::ControlPins<STARTP, RESETP, PWDNP>, MODE, D>
+{
+    /// Hand the RESET pin over to the driver, keeping the other pins
+    pub fn with_reset_pin<P: OutputPin>(
+        self,
+        pin: P,
+    ) -> Driver<SPI, NCS, DEV, CH, pins::ControlPins<STARTP, P, PWDNP>, MODE, D> {
+        WithDelay::new(self.driver.with_reset_pin(pin), self.delay)
+    }
+
+    /// Hand the PWDN pin over to the driver, keeping the other pins
+    pub fn with_pwdn_pin<P: OutputPin>(
+        self,
+        pin: P,
+    ) -> Driver<SPI, NCS, DEV, CH, pins::ControlPins<STARTP, RESETP, P>, MODE, D> {
+        WithDelay::new(self.driver.with_pwdn_pin(pin), self.delay)
+    }
+
+    /// Hand the START pin over to the driver, keeping the other pins
+    pub fn with_start_pin<P: OutputPin>(
+        self,
+        pin: P,
+    ) -> Driver<SPI, NCS, DEV, CH, pins::ControlPins<P, RESETP, PWDNP>, MODE, D> {
+        WithDelay::new(self.driver.with_start_pin(pin), self.delay)
+    }
+}
+
+impl<SPI, NCS, DEV, E, PINS, MODE, D, const CH: usize>
+    WithDelay<Ads129x<SPI, NCS, DEV, CH, PINS, MODE>, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::Mode,
+    D: DelayUs<u32>,
+{
+    forward! {
+        wakeup_device() -> ();
+        set_standby_mode() -> ();
+        reset_device() -> ();
+        reset_and_init(fclk_hz: Option<u32>) -> common::id::DevModel;
+        start_conv() -> ();
+        stop_conv() -> ();
+        power_up() -> common::RegistersLost;
+        deep_power_down() -> ();
+        send_command_raw(opcode: u8) -> ();
+    }
+
+    /// Release SPI, nCS and the delay
+    pub fn destroy(self) -> (SPI, NCS, D) {
+        let (spi, ncs) = self.driver.destroy();
+        (spi, ncs, self.delay)
+    }
+
+    /// Follow the device mode at run time instead of in the type
+    pub fn into_dynamic(self) -> WithDelay<Ads129x<SPI, NCS, DEV, CH, PINS, mode::Dynamic>, D> {
+        WithDelay::new(self.driver.into_dynamic(), self.delay)
+    }
+}
+
+impl<SPI, NCS, DEV, E, PINS, MODE, D, const CH: usize>
+    WithDelay<Ads129x<SPI, NCS, DEV, CH, PINS, MODE>, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+    DEV: variant::FamilyVariant,
+    D: DelayUs<u32>,
+{
+    forward! {
+        read_id() -> common::id::DevModel;
+    }
+
+    /// [`Ads129x::verify_device`] with the stored delay
+    pub fn verify_device(&mut self, retries: u8) -> Ads129xResult<common::id::DevModel, E> {
+        self.driver
+            .verify_device(spi::DelayRef(&mut self.delay), retries)
+    }
+}
+
+impl<SPI, NCS, DEV, E, PINS, D, const CH: usize>
+    WithDelay<Ads129x<SPI, NCS, DEV, CH, PINS, mode::Command>, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    D: DelayUs<u32>,
+{
+    /// [`Ads129x::set_continuous_mode`] with the stored delay
+    ///
+    /// The delay is dropped along with the driver when the opcode fails.
+    pub fn set_continuous_mode(
+        mut self,
+    ) -> Ads129xResult<Driver<SPI, NCS, DEV, CH, PINS, mode::Continuous, D>, E> {
+        let driver = self
+            .driver
+            .set_continuous_mode(spi::DelayRef(&mut self.delay))?;
+        Ok(WithDelay::new(driver, self.delay))
+    }
+}
+
+impl<SPI, NCS, DEV, E, PINS, D, const CH: usize>
+    WithDelay<Ads129x<SPI, NCS, DEV, CH, PINS, mode::Continuous>, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    D: DelayUs<u32>,
+{
+    /// [`Ads129x::set_command_mode`] with the stored delay
+    ///
+    /// The delay is dropped along with the driver when the opcode fails.
+    pub fn set_command_mode(
+        mut self,
+    ) -> Ads129xResult<Driver<SPI, NCS, DEV, CH, PINS, mode::Command, D>, E> {
+        let driver = self
+            .driver
+            .set_command_mode(spi::DelayRef(&mut self.delay))?;
+        Ok(WithDelay::new(driver, self.delay))
+    }
+}
+
+impl<SPI, NCS, DEV, E, PINS, D, const CH: usize>
+    WithDelay<Ads129x<SPI, NCS, DEV, CH, PINS, mode::Dynamic>, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    D: DelayUs<u32>,
+{
+    forward! {
+        set_continuous_mode() -> ();
+        set_command_mode() -> ();
+    }
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, D, const CH: usize>
+    WithDelay<Ads129x<SPI, NCS, Ads1292Family<V>, CH, PINS, MODE>, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::Mode,
+    D: DelayUs<u32>,
+{
+    forward! {
+        power_up_and_restore() -> ();
+        hard_reset(restore: bool) -> ();
+        power_up_sequence(options: common::PowerUpOptions) -> common::id::DevModel;
+    }
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, D, const CH: usize>
+    WithDelay<Ads129x<SPI, NCS, Ads1292Family<V>, CH, PINS, MODE>, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::DataAccess,
+    D: DelayUs<u32>,
+{
+    forward! {
+        read_data(data_frame: &mut data::DataFrame92) -> ();
+        read_data_when_ready(drdy: &impl InputPin, data_frame: &mut data::DataFrame92) -> ();
+        read_data_timeout(
+            drdy: &impl InputPin,
+            timeout_us: u32,
+            data_frame: &mut data::DataFrame92
+        ) -> ();
+        read_raw_frame(buf: &mut [u8]) -> ();
+    }
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, D, const CH: usize>
+    WithDelay<Ads129x<SPI, NCS, Ads1292Family<V>, CH, PINS, MODE>, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+    D: DelayUs<u32>,
+{
+    forward! {
+        read_data_by_command(data_frame: &mut data::DataFrame92) -> ();
+        read_register(reg: ads1292::Register) -> u8;
+        read_registers(start: ads1292::Register, buf: &mut [u8]) -> ();
+        dump_registers() -> [u8; 12];
+        write_register(reg: ads1292::Register, value: u8) -> ();
+        write_registers(start: ads1292::Register, values: &[u8]) -> ();
+        apply_config(config: &ads1292::DeviceConfig) -> ();
+        channels() -> [ads1292::chan::Chan; CH];
+        set_all_channels(chan: ads1292::chan::Chan) -> ();
+        enable_offset_calibration(enable: bool) -> ();
+        calibrate_offset(data_rate_sps: u32) -> ();
+        run_offset_calibration() -> ();
+        set_clk_div(div: ads1292::loff::ClkDiv) -> ();
+        set_clock_divider(div: ads1292::loff::ClkDiv) -> ();
+    }
+
+    ads1292_registers!(DELAYED:);
+}
+
+impl<SPI, NCS, E, PINS, MODE, D, const CH: usize>
+    WithDelay<Ads129x<SPI, NCS, Ads1292Family<variant::R>, CH, PINS, MODE>, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+    D: DelayUs<u32>,
+{
+    forward! {
+        configure_respiration(setup: ads1292::resp::RespirationSetup) -> ();
+        respiration_config() -> ads1292::resp::RespirationSetup;
+    }
+
+    ads1292r_registers!(DELAYED:);
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, D, const CH: usize>
+    WithDelay<Ads129x<SPI, NCS, Ads1298Family<V>, CH, PINS, MODE>, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::Mode,
+    D: DelayUs<u32>,
+{
+    forward! {
+        power_up_and_restore() -> ();
+        hard_reset(restore: bool) -> ();
+        power_up_sequence(options: common::PowerUpOptions) -> common::id::DevModel;
+    }
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, D, const CH: usize>
+    WithDelay<Ads129x<SPI, NCS, Ads1298Family<V>, CH, PINS, MODE>, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::DataAccess,
+    D: DelayUs<u32>,
+{
+    forward! {
+        read_data(data_frame: &mut data::DataFrame<CH>) -> ();
+        read_data_timestamped() -> data::TimestampedFrame<CH>;
+        read_data_when_ready(drdy: &impl InputPin, data_frame: &mut data::DataFrame<CH>) -> ();
+        read_data_timeout(
+            drdy: &impl InputPin,
+            timeout_us: u32,
+            data_frame: &mut data::DataFrame<CH>
+        ) -> ();
+        read_raw_frame(buf: &mut [u8]) -> ();
+        read_frames(frames: &mut [data::DataFrame<CH>]) -> usize;
+        read_data_into(samples: &mut [i32; CH]) -> data::DataStatusWord;
+    }
+
+    /// [`Ads129x::read_daisy_frames`] with the stored delay
+    pub fn read_daisy_frames<const N: usize>(
+        &mut self,
+        frames: &mut [data::DataFrame<CH>; N],
+    ) -> Ads129xResult<(), E> {
+        self.driver
+            .read_daisy_frames(frames, spi::DelayRef(&mut self.delay))
+    }
+
+    /// [`Ads129x::read_data_with`] with the stored delay
+    pub fn read_data_with(
+        &mut self,
+        sample: impl FnMut(usize, i32),
+        status: impl FnOnce(data::DataStatusWord),
+    ) -> Ads129xResult<(), E> {
+        self.driver
+            .read_data_with(spi::DelayRef(&mut self.delay), sample, status)
+    }
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, D, const CH: usize>
+    WithDelay<Ads129x<SPI, NCS, Ads1298Family<V>, CH, PINS, MODE>, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+    D: DelayUs<u32>,
+{
+    forward! {
+        read_data_by_command(data_frame: &mut data::DataFrame<CH>) -> ();
+        trigger_single_shot_pin(data_frame: &mut data::DataFrame<CH>) -> ();
+        convert_once(data_frame: &mut data::DataFrame<CH>) -> ();
+        convert_once_when_ready(drdy: &impl InputPin, data_frame: &mut data::DataFrame<CH>) -> ();
+        read_register(reg: ads1298::Register) -> u8;
+        read_registers(start: ads1298::Register, buf: &mut [u8]) -> ();
+        dump_registers() -> [u8; 26];
+        write_register(reg: ads1298::Register, value: u8) -> ();
+        write_registers(start: ads1298::Register, values: &[u8]) -> ();
+        channels() -> [ads1298::chan::Chan; CH];
+        set_all_channels(chan: ads1298::chan::Chan) -> ();
+        enable_clock_output() -> ();
+        disable_clock_output() -> ();
+        configure_multiple_readback() -> ();
+        configure_daisy_chain(role: ads1298::conf::DaisyRole) -> ();
+        readback_mode() -> ads1298::conf::ReadbackMode;
+        apply_config(config: &ads1298::DeviceConfig<CH>) -> ();
+        read_config() -> ads1298::DeviceConfig<CH>;
+        write_chans_range(start: usize, chans: &[ads1298::chan::Chan]) -> ();
+        read_chans_range(start: usize, chans: &mut [ads1298::chan::Chan]) -> ();
+        measure_noise(drdy: &impl InputPin, frames: usize) -> data::NoiseReport<CH>;
+        enable_leadoff(setup: ads1298::loff::LeadOffSetup) -> ();
+        leadoff_state() -> ads1298::loff::LeadOffState;
+        apply_wct_config(config: ads1298::wct::WctConfig) -> ();
+        read_wct_config() -> ads1298::wct::WctConfig;
+    }
+
+    ads1298_registers!(DELAYED:);
+
+    /// [`Ads129x::stream_frames`] with the stored delay
+    pub fn stream_frames(
+        &mut self,
+        drdy: &impl InputPin,
+        on_frame: impl FnMut(&data::DataFrame<CH>) -> core::ops::ControlFlow<()>,
+    ) -> Ads129xResult<usize, E> {
+        self.driver
+            .stream_frames(drdy, spi::DelayRef(&mut self.delay), on_frame)
+    }
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, D> WithDelay<Ads129x<SPI, NCS, Ads1298Family<V>, 6, PINS, MODE>, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+    D: DelayUs<u32>,
+{
+    ads1298_chan_5_6_registers!(DELAYED:);
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, D> WithDelay<Ads129x<SPI, NCS, Ads1298Family<V>, 8, PINS, MODE>, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+    D: DelayUs<u32>,
+{
+    ads1298_chan_5_6_registers!(DELAYED:);
+    ads1298_chan_7_8_registers!(DELAYED:);
+}
+
+impl<SPI, NCS, E, PINS, MODE, D, const CH: usize>
+    WithDelay<Ads129x<SPI, NCS, Ads1298Family<variant::R>, CH, PINS, MODE>, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+    D: DelayUs<u32>,
+{
+    ads1298r_registers!(DELAYED:);
+}
@@ -0,0 +1,105 @@
+//! 12-lead ECG derivation
+//!
+//! A standard 12-lead hookup measures leads I, II and V1..V6, the remaining
+//! limb leads are linear combinations of I and II (Einthoven/Goldberger):
+//!
+//! - `III = II - I`
+//! - `aVR = -(I + II) / 2`
+//! - `aVL = I - II / 2`
+//! - `aVF = II - I / 2`
+//!
+//! with `I = LA - RA` and `II = LL - RA`. Arithmetic is integer, in raw
+//! counts, saturating at the `i32` range; halves truncate toward zero.
+
+use crate::data::DataFrame;
+
+/// Channel carrying each measured lead, 0-based
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeadMapping {
+    /// Lead I, `LA - RA`
+    pub lead_i:  usize,
+    /// Lead II, `LL - RA`
+    pub lead_ii: usize,
+    /// Precordial leads V1..V6
+    pub v:       [usize; 6],
+}
+
+impl Default for LeadMapping {
+    /// I and II on channels 1-2, V1..V6 on channels 3-8
+    fn default() -> Self {
+        LeadMapping {
+            lead_i:  0,
+            lead_ii: 1,
+            v:       [2, 3, 4, 5, 6, 7],
+        }
+    }
+}
+
+/// All twelve leads in raw counts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TwelveLead {
+    pub i:   i32,
+    pub ii:  i32,
+    pub iii: i32,
+    pub avr: i32,
+    pub avl: i32,
+    pub avf: i32,
+    /// V1..V6
+    pub v:   [i32; 6],
+}
+
+/// Lead mapping naming a channel past the frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MappingError {
+    /// Offending 0-based channel
+    pub channel: usize,
+}
+
+impl core::fmt::Display for MappingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "lead mapped to channel {}, past the frame", self.channel)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MappingError {}
+
+/// Derive the twelve leads from the measured ones
+///
+/// Fails with [`MappingError`] when `mapping` names a channel past the
+/// eight of the frame.
+pub fn derive_leads(
+    frame: &DataFrame<8>,
+    mapping: &LeadMapping,
+) -> Result<TwelveLead, MappingError> {
+    let sample = |channel: usize| {
+        frame
+            .data
+            .get(channel)
+            .copied()
+            .ok_or(MappingError { channel })
+    };
+    let i = sample(mapping.lead_i)?;
+    let ii = sample(mapping.lead_ii)?;
+    let (wi, wii) = (i as i64, ii as i64);
+
+    let mut v = [0; 6];
+    for (lead, &ch) in v.iter_mut().zip(&mapping.v) {
+        *lead = sample(ch)?;
+    }
+
+    Ok(TwelveLead {
+        i,
+        ii,
+        iii: ii.saturating_sub(i),
+        avr: saturate(-(wi + wii) / 2),
+        avl: saturate(wi - wii / 2),
+        avf: saturate(wii - wi / 2),
+        v,
+    })
+}
+
+fn saturate(v: i64) -> i32 {
+    v.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
@@ -0,0 +1,169 @@
+//! ADS1298 family GPIO as embedded-hal pins
+//!
+//! [`gpio_pins`](Ads129x::gpio_pins) borrows the driver and a delay into a
+//! [`GpioPins`] port handing out one [`AdsGpioPin`] per GPIO. The pins share
+//! the driver through a `RefCell`, keep them in one execution context.
+//!
+//! Every pin operation is an SPI transaction: writes are a read-modify-write
+//! of `GPIO`, reads read `GPIO`. A pin turns into an output on its first
+//! write and stays one until [`AdsGpioPin::set_input`].
+//!
+//! ```ignore
+//! let port = ads.gpio_pins(DelayRef(&mut delay))?;
+//! let [mut led, button, _, _] = port.pins();
+//! led.set_high()?;
+//! let pressed = button.is_low()?;
+//! ```
+
+use core::cell::RefCell;
+use core::convert::TryFrom;
+
+use ehal::blocking::delay::DelayUs;
+use ehal::blocking::spi::{Transfer, Write};
+use ehal::digital::v2::{InputPin, OutputPin};
+use embedded_hal as ehal;
+
+use crate::ads1298::{self, gpio, resp};
+use crate::{mode, pins, spi};
+use crate::{Ads1298Family, Ads129x, Ads129xError, Ads129xResult};
+
+/// Driver and delay lent to the port
+type Lent<'a, SPI, NCS, V, const CH: usize, PINS, MODE, D> = (
+    &'a mut Ads129x<SPI, NCS, Ads1298Family<V>, CH, PINS, MODE>,
+    D,
+);
+
+/// GPIO port, see [`gpio_pins`](crate::gpio_pins)
+pub struct GpioPins<'a, SPI, NCS, V, const CH: usize, PINS, MODE, D> {
+    inner:    RefCell<Lent<'a, SPI, NCS, V, CH, PINS, MODE, D>>,
+    /// Pins taken by the respiration circuitry
+    reserved: [bool; 4],
+}
+
+/// Single device GPIO
+pub struct AdsGpioPin<'p, 'a, SPI, NCS, V, const CH: usize, PINS, MODE, D> {
+    port:  &'p GpioPins<'a, SPI, NCS, V, CH, PINS, MODE, D>,
+    index: usize,
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, const CH: usize>
+    Ads129x<SPI, NCS, Ads1298Family<V>, CH, PINS, MODE>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+{
+    /// Borrow the driver as a GPIO port, see [`gpio_pins`](crate::gpio_pins)
+    ///
+    /// Reads `RESP` once: in external and user-generated respiration GPIO2 to
+    /// GPIO4 belong to the respiration circuitry and their pins fail with
+    /// [`Ads129xError::NotSupported`].
+    pub fn gpio_pins<D: DelayUs<u32>>(
+        &mut self,
+        mut delay: D,
+    ) -> Ads129xResult<GpioPins<'_, SPI, NCS, V, CH, PINS, MODE, D>, E> {
+        let raw = self.read_register(ads1298::Register::RESP, spi::DelayRef(&mut delay))?;
+        let resp = resp::Resp::try_from(resp::RespReg(raw)).map_err(Ads129xError::ReadInterpret)?;
+        let taken = matches!(
+            resp.mode,
+            resp::RespMode::External | resp::RespMode::UserGpio
+        );
+        Ok(GpioPins {
+            inner:    RefCell::new((self, delay)),
+            reserved: [false, taken, taken, taken],
+        })
+    }
+}
+
+impl<'a, SPI, NCS, V, const CH: usize, PINS, MODE, D> GpioPins<'a, SPI, NCS, V, CH, PINS, MODE, D> {
+    /// GPIO1 to GPIO4
+    pub fn pins(&self) -> [AdsGpioPin<'_, 'a, SPI, NCS, V, CH, PINS, MODE, D>; 4] {
+        [0, 1, 2, 3].map(|index| AdsGpioPin { port: self, index })
+    }
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, D, const CH: usize>
+    AdsGpioPin<'_, '_, SPI, NCS, V, CH, PINS, MODE, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+    D: DelayUs<u32>,
+{
+    /// Turn the pin back into an input
+    pub fn set_input(&mut self) -> Ads129xResult<(), E> {
+        self.modify(|gpio, i| gpio.mode[i] = gpio::GpioMode::Input)
+    }
+
+    fn modify(&mut self, f: impl FnOnce(&mut gpio::Gpio, usize)) -> Ads129xResult<(), E> {
+        self.check()?;
+        let index = self.index;
+        let (driver, delay) = &mut *self.port.inner.borrow_mut();
+        driver.modify_gpio(|gpio| f(gpio, index), spi::DelayRef(delay))
+    }
+
+    fn read(&self) -> Ads129xResult<bool, E> {
+        self.check()?;
+        let (driver, delay) = &mut *self.port.inner.borrow_mut();
+        Ok(driver.gpio(spi::DelayRef(delay))?.data[self.index])
+    }
+
+    fn check(&self) -> Ads129xResult<(), E> {
+        if self.port.reserved[self.index] {
+            return Err(Ads129xError::NotSupported {
+                model: None,
+                what:  "GPIO used by the respiration circuitry",
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, D, const CH: usize> OutputPin
+    for AdsGpioPin<'_, '_, SPI, NCS, V, CH, PINS, MODE, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+    D: DelayUs<u32>,
+{
+    type Error = Ads129xError<E>;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.modify(|gpio, i| {
+            gpio.mode[i] = gpio::GpioMode::Output;
+            gpio.data[i] = false;
+        })
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.modify(|gpio, i| {
+            gpio.mode[i] = gpio::GpioMode::Output;
+            gpio.data[i] = true;
+        })
+    }
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, D, const CH: usize> InputPin
+    for AdsGpioPin<'_, '_, SPI, NCS, V, CH, PINS, MODE, D>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+    D: DelayUs<u32>,
+{
+    type Error = Ads129xError<E>;
+
+    /// Pin state, also of an output
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        self.read()
+    }
+
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        self.read().map(|high| !high)
+    }
+}
@@ -1,303 +1,2949 @@
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::convert::TryFrom;
 
 use ehal::blocking::delay::DelayUs;
 use ehal::blocking::spi::{Transfer, Write};
-use ehal::digital::v2::OutputPin;
-use ehal::spi::FullDuplex;
+use ehal::digital::v2::{InputPin, OutputPin};
 use embedded_hal as ehal;
 
 #[macro_use]
 mod util;
+#[cfg(feature = "async")]
+pub mod asynch;
+pub mod builder;
 pub mod command;
 pub mod common;
 pub mod data;
+pub mod delayed;
+#[cfg(feature = "dsp")]
+pub mod ecg;
+pub mod gpio_pins;
+pub mod mode;
+pub mod pins;
+#[cfg(feature = "serde")]
+mod serde_array;
+mod shadow;
 pub mod spi;
+pub mod split;
+pub mod stats;
+pub mod timing;
+pub mod variant;
 
 pub mod ads1292;
 pub mod ads1298;
 
 #[doc(hidden)]
-pub struct Ads1292Family;
+pub struct Ads1292Family<V = variant::Standard>(core::marker::PhantomData<V>);
 #[doc(hidden)]
-pub struct Ads1298Family;
+pub struct Ads1298Family<V = variant::Standard>(core::marker::PhantomData<V>);
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Ads129xError<E> {
     /// Identification register read problem (probably unsupported device)
     IdRegRead(common::id::IdRegError),
     /// Read bytes is invalid register value
     ReadInterpret(u8),
-    /// Status word missmatch
-    StatusWordMissmatch(u8),
+    /// Status word sync nibble isn't `0b1100`
+    ///
+    /// `raw` holds the status word bytes as clocked out.
+    StatusWordMissmatch { raw: [u8; 3] },
+    /// Argument rejected before touching the bus
+    InvalidArgument(&'static str),
+    /// Operation not available on this device or wiring
+    ///
+    /// `model` is known when the device was identified, `what` names the operation.
+    NotSupported {
+        model: Option<common::id::DevModel>,
+        what:  &'static str,
+    },
+    /// Control pin failed to change its state
+    Pin(pins::ControlPin),
+    /// nCS pin failed to change its state
+    ///
+    /// `high` is the level it failed to take.
+    NcsPin { high: bool },
+    /// DRDY pin read failed
+    DrdyPin,
+    /// Device didn't get ready in time
+    Timeout,
+    /// Register read back differs from the written value
+    WriteVerifyFailed { register: u8, wrote: u8, read: u8 },
+    /// Clock settings yield a modulator clock out of the supported range
+    ClockOutOfRange { fclk_hz: u32, fmod_hz: u32 },
+    /// Identified device has a different channel count than the driver
+    DeviceMismatch {
+        expected_channels: usize,
+        found:             common::id::DevModel,
+    },
+    /// Identified device lacks the respiration circuitry of an R variant
+    /// driver, see [`variant`]
+    VariantMismatch { found: common::id::DevModel },
+    /// Bus held by the other half of a split driver, see [`split`]
+    BusBusy,
     /// Spi transport error
     Spi(E),
 }
 
 pub type Ads129xResult<T, E> = Result<T, Ads129xError<E>>;
 
-pub struct Ads129x<SPI, NCS, DEV, const CH: usize> {
-    spi: spi::SpiDevice<SPI, NCS>,
-    _d:  core::marker::PhantomData<DEV>,
+/// Compare the read back value of `reg` with the written one in the bits
+/// covered by the register's mask
+fn verify_register<E>(
+    reg: u8,
+    info: &common::RegisterInfo,
+    wrote: u8,
+    read: u8,
+) -> Ads129xResult<(), E> {
+    if read & info.mask != wrote & info.mask {
+        return Err(Ads129xError::WriteVerifyFailed {
+            register: reg,
+            wrote,
+            read,
+        });
+    }
+    Ok(())
+}
+
+/// ADS129x driver
+///
+/// Constructors assume the power-on continuous conversion mode, see [`mode`].
+pub struct Ads129x<
+    SPI,
+    NCS,
+    DEV,
+    const CH: usize,
+    PINS = pins::ControlPins,
+    MODE = mode::Continuous,
+> {
+    spi:        spi::SpiDevice<SPI, NCS>,
+    pins:       PINS,
+    shadow:     shadow::Shadow,
+    /// Last value written to or read from each register, backs the channel
+    /// gains. Never replayed.
+    observed:   shadow::Shadow,
+    clock:      common::ClockSource,
+    /// Read back every register write
+    verify:     bool,
+    /// Status word sync check policy
+    validation: data::FrameValidation,
+    /// Sample representation of the frame reads
+    format:     data::SampleFormat,
+    /// Clock sampled once nCS is asserted for a timestamped read
+    timestamp:  Option<fn() -> u64>,
+    /// Device mode as last set, followed in [`mode::Dynamic`]
+    continuous: bool,
+    /// Register access in continuous mode, [`mode::Dynamic`] only
+    access:     mode::AccessPolicy,
+    _d:         core::marker::PhantomData<(DEV, MODE)>,
 }
 
 impl<SPI, NCS, E> Ads129x<SPI, NCS, Ads1292Family, 2>
 where
-    SPI: Write<u8, Error = E> + Transfer<u8, Error = E> + FullDuplex<u8, Error = E>,
-    NCS: OutputPin<Error = core::convert::Infallible>,
-    E: core::fmt::Debug,
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
 {
-    /// Create ADS1292/ADS1292R device instance
+    /// Create ADS1292 device instance, drives the ADS1292R without respiration
     pub fn new_ads1292(spi: SPI, ncs: NCS) -> Self {
-        Self {
-            spi: spi::SpiDevice::new(spi, ncs),
-            _d:  core::marker::PhantomData,
-        }
+        Self::new(spi, ncs)
     }
 
-    // Read data samples from ADC
-    // Data samples are sign extend
-    pub fn read_data(
-        &mut self,
-        data_frame: &mut data::DataFrame92,
-        mut delay: impl DelayUs<u32>,
-    ) -> Ads129xResult<(), E> {
-        // Read status_word/data
-        {
-            let _ = self.spi.ncs.set_low();
-            delay.delay_us(40);
+    /// Create ADS1292 device instance owning `delay`, see [`delayed`]
+    pub fn new_ads1292_with_delay<D: DelayUs<u32>>(
+        spi: SPI,
+        ncs: NCS,
+        delay: D,
+    ) -> delayed::WithDelay<Self, D> {
+        delayed::WithDelay::new(Self::new_ads1292(spi, ncs), delay)
+    }
+}
 
-            // Read status word
-            for idx in 0..data_frame.status_word.len() {
-                nb::block!(self.spi.spi.send(0x00))?;
-                data_frame.status_word[idx] = nb::block!(self.spi.spi.read())?;
-            }
-            // Read channels data, i24 big endian byte order
-            for idx in 0..2 {
-                let mut bb = [0x00u8; 4];
-                nb::block!(self.spi.spi.send(0x00))?;
-                bb[2] = nb::block!(self.spi.spi.read())?;
-                nb::block!(self.spi.spi.send(0x00))?;
-                bb[1] = nb::block!(self.spi.spi.read())?;
-                nb::block!(self.spi.spi.send(0x00))?;
-                bb[0] = nb::block!(self.spi.spi.read())?;
-                // Assemble sample as le
-                data_frame.data[idx] = i32::from_le_bytes(bb);
-                // Sign extend i24 -> i32
-                // On ARM should be optimized to SBFX instruction
-                data_frame.data[idx] = data_frame.data[idx] << 8 >> 8;
-            }
+impl<SPI, NCS, E> Ads129x<SPI, NCS, Ads1292Family<variant::R>, 2>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+{
+    /// Create ADS1292R device instance with the respiration API, see
+    /// [`variant`]
+    pub fn new_ads1292r(spi: SPI, ncs: NCS) -> Self {
+        Ads129x::new_ads1292(spi, ncs).into_family()
+    }
 
-            delay.delay_us(40);
-            let _ = self.spi.ncs.set_high();
-            delay.delay_us(20);
-        }
+    /// Create ADS1292R device instance owning `delay`, see [`delayed`]
+    pub fn new_ads1292r_with_delay<D: DelayUs<u32>>(
+        spi: SPI,
+        ncs: NCS,
+        delay: D,
+    ) -> delayed::WithDelay<Self, D> {
+        delayed::WithDelay::new(Self::new_ads1292r(spi, ncs), delay)
+    }
+}
 
-        // Validate status word
-        let status_word = data_frame.status_word();
-        if status_word.sync() != 0b1100 {
-            return Err(Ads129xError::StatusWordMissmatch(status_word.sync()));
-        }
+impl<SPI, NCS, E> Ads129x<SPI, NCS, Ads1292Family, 1>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+{
+    /// Create ADS1291 device instance
+    pub fn new_ads1291(spi: SPI, ncs: NCS) -> Self {
+        Self::new(spi, ncs)
+    }
 
-        Ok(())
+    /// Create ADS1291 device instance owning `delay`, see [`delayed`]
+    pub fn new_ads1291_with_delay<D: DelayUs<u32>>(
+        spi: SPI,
+        ncs: NCS,
+        delay: D,
+    ) -> delayed::WithDelay<Self, D> {
+        delayed::WithDelay::new(Self::new_ads1291(spi, ncs), delay)
     }
 }
 
 impl<SPI, NCS, E> Ads129x<SPI, NCS, Ads1298Family, 4>
 where
-    SPI: Write<u8, Error = E> + Transfer<u8, Error = E> + FullDuplex<u8, Error = E>,
-    NCS: OutputPin<Error = core::convert::Infallible>,
-    E: core::fmt::Debug,
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
 {
-    /// Create ADS1294/ADS1294R device instance
+    /// Create ADS1294 device instance, drives the ADS1294R without respiration
     pub fn new_ads1294(spi: SPI, ncs: NCS) -> Self {
-        Self {
-            spi: spi::SpiDevice::new(spi, ncs),
-            _d:  core::marker::PhantomData,
-        }
+        Self::new(spi, ncs)
+    }
+
+    /// Create ADS1294 device instance owning `delay`, see [`delayed`]
+    pub fn new_ads1294_with_delay<D: DelayUs<u32>>(
+        spi: SPI,
+        ncs: NCS,
+        delay: D,
+    ) -> delayed::WithDelay<Self, D> {
+        delayed::WithDelay::new(Self::new_ads1294(spi, ncs), delay)
+    }
+}
+
+impl<SPI, NCS, E> Ads129x<SPI, NCS, Ads1298Family<variant::R>, 4>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+{
+    /// Create ADS1294R device instance with the respiration API, see
+    /// [`variant`]
+    pub fn new_ads1294r(spi: SPI, ncs: NCS) -> Self {
+        Ads129x::new_ads1294(spi, ncs).into_family()
+    }
+
+    /// Create ADS1294R device instance owning `delay`, see [`delayed`]
+    pub fn new_ads1294r_with_delay<D: DelayUs<u32>>(
+        spi: SPI,
+        ncs: NCS,
+        delay: D,
+    ) -> delayed::WithDelay<Self, D> {
+        delayed::WithDelay::new(Self::new_ads1294r(spi, ncs), delay)
     }
 }
 
 impl<SPI, NCS, E> Ads129x<SPI, NCS, Ads1298Family, 6>
 where
-    SPI: Write<u8, Error = E> + Transfer<u8, Error = E> + FullDuplex<u8, Error = E>,
-    NCS: OutputPin<Error = core::convert::Infallible>,
-    E: core::fmt::Debug,
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
 {
-    /// Create ADS1296/ADS1296R device instance
+    /// Create ADS1296 device instance, drives the ADS1296R without respiration
     pub fn new_ads1296(spi: SPI, ncs: NCS) -> Self {
-        Self {
-            spi: spi::SpiDevice::new(spi, ncs),
-            _d:  core::marker::PhantomData,
-        }
+        Self::new(spi, ncs)
+    }
+
+    /// Create ADS1296 device instance owning `delay`, see [`delayed`]
+    pub fn new_ads1296_with_delay<D: DelayUs<u32>>(
+        spi: SPI,
+        ncs: NCS,
+        delay: D,
+    ) -> delayed::WithDelay<Self, D> {
+        delayed::WithDelay::new(Self::new_ads1296(spi, ncs), delay)
+    }
+}
+
+impl<SPI, NCS, E> Ads129x<SPI, NCS, Ads1298Family<variant::R>, 6>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+{
+    /// Create ADS1296R device instance with the respiration API, see
+    /// [`variant`]
+    pub fn new_ads1296r(spi: SPI, ncs: NCS) -> Self {
+        Ads129x::new_ads1296(spi, ncs).into_family()
+    }
+
+    /// Create ADS1296R device instance owning `delay`, see [`delayed`]
+    pub fn new_ads1296r_with_delay<D: DelayUs<u32>>(
+        spi: SPI,
+        ncs: NCS,
+        delay: D,
+    ) -> delayed::WithDelay<Self, D> {
+        delayed::WithDelay::new(Self::new_ads1296r(spi, ncs), delay)
     }
 }
 
 impl<SPI, NCS, E> Ads129x<SPI, NCS, Ads1298Family, 8>
 where
-    SPI: Write<u8, Error = E> + Transfer<u8, Error = E> + FullDuplex<u8, Error = E>,
-    NCS: OutputPin<Error = core::convert::Infallible>,
-    E: core::fmt::Debug,
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
 {
-    /// Create ADS1298/ADS1298R device instance
+    /// Create ADS1298 device instance, drives the ADS1298R without respiration
     pub fn new_ads1298(spi: SPI, ncs: NCS) -> Self {
-        Self {
-            spi: spi::SpiDevice::new(spi, ncs),
-            _d:  core::marker::PhantomData,
+        Self::new(spi, ncs)
+    }
+
+    /// Create ADS1298 device instance owning `delay`, see [`delayed`]
+    pub fn new_ads1298_with_delay<D: DelayUs<u32>>(
+        spi: SPI,
+        ncs: NCS,
+        delay: D,
+    ) -> delayed::WithDelay<Self, D> {
+        delayed::WithDelay::new(Self::new_ads1298(spi, ncs), delay)
+    }
+}
+
+impl<SPI, NCS, E> Ads129x<SPI, NCS, Ads1298Family<variant::R>, 8>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+{
+    /// Create ADS1298R device instance with the respiration API, see
+    /// [`variant`]
+    pub fn new_ads1298r(spi: SPI, ncs: NCS) -> Self {
+        Ads129x::new_ads1298(spi, ncs).into_family()
+    }
+
+    /// Create ADS1298R device instance owning `delay`, see [`delayed`]
+    pub fn new_ads1298r_with_delay<D: DelayUs<u32>>(
+        spi: SPI,
+        ncs: NCS,
+        delay: D,
+    ) -> delayed::WithDelay<Self, D> {
+        delayed::WithDelay::new(Self::new_ads1298r(spi, ncs), delay)
+    }
+}
+
+impl<SPI, NCS, DEV, const CH: usize, PINS, MODE> Ads129x<SPI, NCS, DEV, CH, PINS, MODE> {
+    /// Declare the clock source wired by the CLKSEL pin, used to validate clock settings
+    pub fn set_clock_source(&mut self, source: common::ClockSource) {
+        self.clock = source;
+    }
+
+    /// Declared clock source
+    pub fn clock_source(&self) -> common::ClockSource {
+        self.clock
+    }
+
+    /// Read back each register after writing it
+    ///
+    /// A mismatch in the bits covered by the register's
+    /// [`mask`](common::RegisterInfo::mask) fails the write with
+    /// [`Ads129xError::WriteVerifyFailed`]. Off by default.
+    pub fn set_write_verification(&mut self, enable: bool) {
+        self.verify = enable;
+    }
+
+    /// Choose how frames failing the status word sync check are handled
+    ///
+    /// [`Strict`](data::FrameValidation::Strict) by default.
+    pub fn set_frame_validation(&mut self, validation: data::FrameValidation) {
+        self.validation = validation;
+    }
+
+    /// Choose how frame reads store samples
+    ///
+    /// [`SignExtended`](data::SampleFormat::SignExtended) by default, which
+    /// the µV conversion and statistics helpers expect.
+    pub fn set_sample_format(&mut self, format: data::SampleFormat) {
+        self.format = format;
+    }
+
+    /// Clock used by `read_data_timestamped`
+    ///
+    /// Called once per frame with nCS asserted, before the first byte is
+    /// clocked out, so keep it short.
+    pub fn set_timestamp_source(&mut self, source: fn() -> u64) {
+        self.timestamp = Some(source);
+    }
+
+    /// nCS setup/hold waits of every transaction, frame reads included
+    ///
+    /// Defaults to 40/40/20 µs, see [`spi::CsTiming`].
+    pub fn set_cs_timing(&mut self, timing: spi::CsTiming) {
+        self.spi.cs_timing = timing;
+    }
+
+    /// nCS waits in use
+    pub fn cs_timing(&self) -> spi::CsTiming {
+        self.spi.cs_timing
+    }
+
+    /// Bus and acquisition counters, see [`stats`]
+    pub fn stats(&self) -> &stats::DriverStats {
+        &self.spi.stats
+    }
+
+    /// Zero all counters
+    pub fn reset_stats(&mut self) {
+        self.spi.stats = stats::DriverStats::default();
+    }
+
+    /// Same driver state under other type parameters, `map_pins` converts
+    /// the control pins
+    fn rebuild<F, P, M>(self, map_pins: impl FnOnce(PINS) -> P) -> Ads129x<SPI, NCS, F, CH, P, M> {
+        Ads129x {
+            spi:        self.spi,
+            pins:       map_pins(self.pins),
+            shadow:     self.shadow,
+            observed:   self.observed,
+            clock:      self.clock,
+            verify:     self.verify,
+            validation: self.validation,
+            format:     self.format,
+            timestamp:  self.timestamp,
+            continuous: self.continuous,
+            access:     self.access,
+            _d:         core::marker::PhantomData,
         }
     }
 }
 
 impl<SPI, NCS, DEV, E, const CH: usize> Ads129x<SPI, NCS, DEV, CH>
 where
-    SPI: Write<u8, Error = E> + Transfer<u8, Error = E> + FullDuplex<u8, Error = E>,
-    NCS: OutputPin<Error = core::convert::Infallible>,
-    E: core::fmt::Debug,
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+{
+    /// Driver with the power-on defaults and no control pins
+    fn new(spi: SPI, ncs: NCS) -> Self {
+        Ads129x {
+            spi:        spi::SpiDevice::new(spi, ncs),
+            pins:       pins::ControlPins::new(),
+            shadow:     shadow::Shadow::new(),
+            observed:   shadow::Shadow::new(),
+            clock:      common::ClockSource::InternalOscillator,
+            verify:     false,
+            validation: data::FrameValidation::Strict,
+            format:     data::SampleFormat::SignExtended,
+            timestamp:  None,
+            continuous: true,
+            access:     mode::AccessPolicy::Stop,
+            _d:         core::marker::PhantomData,
+        }
+    }
+}
+
+impl<SPI, NCS, DEV, const CH: usize, MODE> Ads129x<SPI, NCS, DEV, CH, pins::ControlPins, MODE> {
+    /// Hand START/RESET/PWDN pins over to the driver
+    ///
+    /// Connected pins replace the corresponding opcodes, see [`pins`].
+    pub fn with_control_pins<PINS>(
+        self,
+        control_pins: PINS,
+    ) -> Ads129x<SPI, NCS, DEV, CH, PINS, MODE> {
+        self.rebuild(|_| control_pins)
+    }
+}
+
+impl<SPI, NCS, DEV, const CH: usize, STARTP, RESETP, PWDNP, MODE>
+    Ads129x<SPI, NCS, DEV, CH, pins::ControlPins<STARTP, RESETP, PWDNP>, MODE>
+{
+    /// Hand the RESET pin over to the driver
+    ///
+    /// Replaces the RESET slot of the control pins, the others are kept. See
+    /// `hard_reset`.
+    pub fn with_reset_pin<P: OutputPin>(
+        self,
+        pin: P,
+    ) -> Ads129x<SPI, NCS, DEV, CH, pins::ControlPins<STARTP, P, PWDNP>, MODE> {
+        self.rebuild(|pins| pins.with_reset(pin))
+    }
+
+    /// Hand the PWDN pin over to the driver
+    ///
+    /// Replaces the PWDN slot of the control pins, the others are kept. See
+    /// `power_down`.
+    pub fn with_pwdn_pin<P: OutputPin>(
+        self,
+        pin: P,
+    ) -> Ads129x<SPI, NCS, DEV, CH, pins::ControlPins<STARTP, RESETP, P>, MODE> {
+        self.rebuild(|pins| pins.with_pwdn(pin))
+    }
+
+    /// Hand the START pin over to the driver
+    ///
+    /// Replaces the START slot of the control pins, the others are kept.
+    /// `destroy_with_pins` gives it back.
+    pub fn with_start_pin<P: OutputPin>(
+        self,
+        pin: P,
+    ) -> Ads129x<SPI, NCS, DEV, CH, pins::ControlPins<P, RESETP, PWDNP>, MODE> {
+        self.rebuild(|pins| pins.with_start(pin))
+    }
+}
+
+impl<SPI, NCS, DEV, E, PINS, MODE, const CH: usize> Ads129x<SPI, NCS, DEV, CH, PINS, MODE>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::Mode,
 {
     impl_cmd!(wakeup_device, WAKEUP);
     impl_cmd!(set_standby_mode, STANDBY);
-    impl_cmd!(reset_device, RESET);
-    impl_cmd!(start_conv, START);
-    impl_cmd!(stop_conv, STOP);
-    impl_cmd!(set_continuous_mode, RDATAC);
-    impl_cmd!(set_command_mode, SDATAC);
 
-    pub fn read_id(&mut self, delay: impl DelayUs<u32>) -> Ads129xResult<common::id::DevModel, E> {
-        let mut words = [command::Command::RREG as u8 | 0x00, 0x00, 0xA5];
-        let res = self.spi.transfer(&mut words, delay)?;
+    /// Reset the device
+    ///
+    /// Pulses the RESET pin low for [`timing::RESET_PULSE_US`] when connected, sends the
+    /// `RESET` opcode otherwise. Either way waits [`timing::RESET_RECOVERY_US`] before the
+    /// device is accessed again.
+    /// The device comes back in continuous mode, in [`mode::Command`] the
+    /// driver sends `SDATAC` afterwards. The written register values are
+    /// forgotten.
+    pub fn reset_device(&mut self, mut delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        self.forget_registers();
+        self.pulse_reset(spi::DelayRef(&mut delay))?;
+        self.resume_mode(true, delay)
+    }
 
-        let model = common::id::DevModel::try_from(common::id::IdReg(res[2]))
-            .map_err(|e| Ads129xError::IdRegRead(e))?;
+    /// Reset through the pin or opcode, then wait for the recovery
+    fn pulse_reset(&mut self, mut delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        if self.pins.is_connected(pins::ControlPin::Reset) {
+            self.set_pin(pins::ControlPin::Reset, false)?;
+            delay.delay_us(timing::RESET_PULSE_US);
+            self.set_pin(pins::ControlPin::Reset, true)?;
+        } else {
+            self.spi.write(&[command::Command::RESET as u8], spi::DelayRef(&mut delay))?;
+        }
+        delay.delay_us(timing::RESET_RECOVERY_US);
+        self.continuous = true;
+        Ok(())
+    }
 
+    /// Reset the device and make it ready for register access
+    ///
+    /// Resets as [`reset_device`](Self::reset_device), extending the recovery
+    /// wait to 18 `tCLK` at `fclk_hz` when given, sends `SDATAC` since the
+    /// device comes back in continuous mode, then identifies it. In
+    /// [`mode::Continuous`] `RDATAC` follows.
+    pub fn reset_and_init(
+        &mut self,
+        fclk_hz: Option<u32>,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<common::id::DevModel, E> {
+        self.forget_registers();
+        self.reset_with_recovery(fclk_hz, spi::DelayRef(&mut delay))?;
+        self.command(command::Command::SDATAC, spi::DelayRef(&mut delay))?;
+        let model = self.identify(spi::DelayRef(&mut delay))?;
+        self.resume_mode(false, delay)?;
         Ok(model)
     }
 
+    /// Start or restart (synchronize) conversions
+    ///
+    /// Drives the START pin high when connected, sends the `START` opcode otherwise. The
+    /// device ignores the opcode while the pin is high, so both are never mixed.
+    pub fn start_conv(&mut self, mut delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        if self.pins.is_connected(pins::ControlPin::Start) {
+            self.set_pin(pins::ControlPin::Start, true)?;
+            delay.delay_us(timing::START_SETUP_US);
+        } else {
+            self.spi.write(&[command::Command::START as u8], delay)?;
+        }
+        Ok(())
+    }
+
+    /// Stop conversions
+    ///
+    /// Drives the START pin low when connected, sends the `STOP` opcode otherwise.
+    pub fn stop_conv(&mut self, delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        if self.pins.is_connected(pins::ControlPin::Start) {
+            self.set_pin(pins::ControlPin::Start, false)?;
+        } else {
+            self.spi.write(&[command::Command::STOP as u8], delay)?;
+        }
+        Ok(())
+    }
+
+    /// Enter power-down by driving the PWDN pin low
+    ///
+    /// Register contents are lost, see [`power_up`](Self::power_up). Fails with
+    /// [`Ads129xError::NotSupported`] when PWDN isn't connected.
+    pub fn power_down(&mut self) -> Ads129xResult<(), E> {
+        self.require_pin(pins::ControlPin::Pwdn, "power-down without PWDN pin")?;
+        self.set_pin(pins::ControlPin::Pwdn, false)
+    }
+
+    /// Leave power-down
+    ///
+    /// Drives PWDN high, waits [`timing::POWER_UP_US`] (`tPOR`) and resets the
+    /// device as required after power-up, sending `SDATAC` in
+    /// [`mode::Command`]. The registers are back at their defaults and the
+    /// driver forgets the written values, re-apply the configuration or use
+    /// `power_up_and_restore` instead. Fails with
+    /// [`Ads129xError::NotSupported`] when PWDN isn't connected.
+    pub fn power_up(
+        &mut self,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<common::RegistersLost, E> {
+        self.leave_power_down(spi::DelayRef(&mut delay))?;
+        self.forget_registers();
+        self.resume_mode(true, delay)?;
+        Ok(common::RegistersLost)
+    }
+
+    /// Send raw single byte command
+    ///
+    /// Escape hatch for opcodes missing from [`command::Command`], e.g. factory/test commands
+    /// or commands of future silicon revisions. The opcode is sent as is inside the usual nCS
+    /// framing, the driver doesn't know what it does to the device: you're on your own.
+    ///
+    /// Opcodes colliding with `RREG` (0x20-0x3F) and `WREG` (0x40-0x5F) are rejected with
+    /// [`Ads129xError::InvalidArgument`], the device would treat the following bytes as
+    /// register access and could corrupt the configuration.
+    pub fn send_command_raw(
+        &mut self,
+        opcode: u8,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        if let 0x20..=0x5F = opcode {
+            return Err(Ads129xError::InvalidArgument("opcode collides with RREG/WREG"));
+        }
+        self.spi.write(&[opcode], delay)?;
+        self.follow(opcode);
+        Ok(())
+    }
+
     pub fn destroy(self) -> (SPI, NCS) {
         self.spi.destroy()
     }
-}
 
-impl<SPI, NCS, E> Ads129x<SPI, NCS, Ads1292Family, 2>
-where
-    SPI: Write<u8, Error = E> + Transfer<u8, Error = E> + FullDuplex<u8, Error = E>,
-    NCS: OutputPin<Error = core::convert::Infallible>,
-    E: core::fmt::Debug,
-{
-    read_reg!(FAM: ads1292, FN: config, REG: CONFIG1 (conf::Config <= conf::Config1Reg));
-    write_reg!(FAM: ads1292, FN: set_config, REG: CONFIG1 (conf::Config => conf::Config1Reg));
+    /// Follow the device mode at run time instead of in the type
+    ///
+    /// Every method becomes available, register access in continuous mode
+    /// sends `SDATAC` first, see [`mode::AccessPolicy`].
+    pub fn into_dynamic(self) -> Ads129x<SPI, NCS, DEV, CH, PINS, mode::Dynamic> {
+        self.into_mode()
+    }
+
+    /// Stop conversions, then enter power-down by driving the PWDN pin low
+    ///
+    /// Lowest power state. Unlike [`power_down`](Self::power_down) conversions are stopped
+    /// first like `stop_conv`, so a START pin isn't left high into the unpowered device and
+    /// conversions don't restart on power-up before the configuration is back. The device loses
+    /// its registers, the driver keeps the last written value of each, resume with
+    /// `power_up_and_restore`. Fails with [`Ads129xError::NotSupported`] before anything is
+    /// sent when PWDN isn't connected.
+    pub fn deep_power_down(&mut self, delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        self.require_pin(pins::ControlPin::Pwdn, "power-down without PWDN pin")?;
+        self.stop_conv(delay)?;
+        self.set_pin(pins::ControlPin::Pwdn, false)
+    }
 
-    read_reg!(FAM: ads1292, FN: misc_config, REG: CONFIG2 (conf::MiscConfig <= conf::Config2Reg));
-    write_reg!(FAM: ads1292, FN: set_misc_config, REG: CONFIG2 (conf::MiscConfig => conf::Config2Reg));
+    /// Power up, leave `RDATAC` and replay the written registers, verifying each by read back
+    fn power_up_and_replay(
+        &mut self,
+        registers: &'static [common::RegisterInfo],
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.leave_power_down(spi::DelayRef(&mut delay))?;
+        self.command(command::Command::SDATAC, spi::DelayRef(&mut delay))?;
+        self.replay_registers(registers, spi::DelayRef(&mut delay))?;
+        self.resume_mode(false, delay)
+    }
 
-    read_reg!(FAM: ads1292, FN: chan_1, REG: CH1SET (chan::Chan <= chan::ChanSetReg));
-    read_reg!(FAM: ads1292, FN: chan_2, REG: CH2SET (chan::Chan <= chan::ChanSetReg));
-    write_reg!(FAM: ads1292, FN: set_chan_1, REG: CH1SET (chan::Chan => chan::ChanSetReg));
-    write_reg!(FAM: ads1292, FN: set_chan_2, REG: CH2SET (chan::Chan => chan::ChanSetReg));
+    /// Pulse RESET, send `SDATAC` and replay `registers` when given
+    fn hard_reset_and_replay(
+        &mut self,
+        registers: Option<&'static [common::RegisterInfo]>,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.require_pin(pins::ControlPin::Reset, "hard reset without RESET pin")?;
+        self.pulse_reset(spi::DelayRef(&mut delay))?;
+        self.command(command::Command::SDATAC, spi::DelayRef(&mut delay))?;
+        match registers {
+            Some(registers) => self.replay_registers(registers, spi::DelayRef(&mut delay))?,
+            None => self.forget_registers(),
+        }
+        self.resume_mode(false, delay)
+    }
 
-    read_reg!(FAM: ads1292, FN: loff_status, REG: LOFF_STAT (loff::LeadOffStatus <= loff::LeadOffStatusReg));
-    write_reg!(FAM: ads1292, FN: set_loff_status, REG: LOFF_STAT (loff::LeadOffStatus => loff::LeadOffStatusReg));
+    /// Write back every register written through the driver, verifying each
+    fn replay_registers(
+        &mut self,
+        registers: &'static [common::RegisterInfo],
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        // Registers only read are back to their reset values
+        self.observed = self.shadow.clone();
+        for (reg, info) in registers.iter().enumerate() {
+            let reg = reg as u8;
+            let wrote = match self.shadow.get(reg) {
+                Some(val) => val,
+                None => continue,
+            };
 
-    read_reg!(FAM: ads1292, FN: leadoff_control, REG: LOFF (loff::LeadOffControl <= loff::LeadOffControlReg));
-    write_reg!(FAM: ads1292, FN: set_leadoff_control, REG: LOFF (loff::LeadOffControl => loff::LeadOffControlReg));
+            let words = [command::Command::WREG as u8 | reg, 0x00, wrote];
+            self.spi.write(&words, spi::DelayRef(&mut delay))?;
+            self.check_register(reg, info, wrote, spi::DelayRef(&mut delay))?;
+        }
+        Ok(())
+    }
 
-    read_reg!(FAM: ads1292, FN: resp, REG: RESP1 (resp::Resp1 <= resp::RespControl1Reg));
-    write_reg!(FAM: ads1292, FN: set_resp, REG: RESP1 (resp::Resp1 => resp::RespControl1Reg));
-}
+    /// Read `reg` back and compare the bits under `info.mask` to `wrote`
+    fn check_register(
+        &mut self,
+        reg: u8,
+        info: &common::RegisterInfo,
+        wrote: u8,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        let read = self.read_register_raw(reg, delay)?;
+        let res = verify_register(reg, info, wrote, read);
+        if res.is_err() {
+            self.spi.stats.write_verify_failure();
+        }
+        res
+    }
 
-impl<SPI, NCS, E, const CH: usize> Ads129x<SPI, NCS, Ads1298Family, CH>
-where
-    SPI: Write<u8, Error = E> + Transfer<u8, Error = E> + FullDuplex<u8, Error = E>,
-    NCS: OutputPin<Error = core::convert::Infallible>,
-    E: core::fmt::Debug,
-{
-    // Read data samples from ADC
-    // Data samples are sign extend
-    pub fn read_data(
+    /// Record written value, read it back when verification is enabled
+    fn written(
         &mut self,
-        data_frame: &mut data::DataFrame<CH>,
+        reg: u8,
+        info: &common::RegisterInfo,
+        wrote: u8,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.shadow.store(reg, wrote);
+        self.observed.store(reg, wrote);
+        if self.verify {
+            self.check_register(reg, info, wrote, delay)?;
+        }
+        Ok(())
+    }
+
+    /// Record channel settings read from the device, see `channel_gains`
+    fn observe(&mut self, first: u8, values: &[u8]) {
+        for (reg, &value) in (first..).zip(values) {
+            self.observed.store(reg, value);
+        }
+    }
+
+    /// Drop the written and observed values, the device is back to its reset
+    /// values
+    fn forget_registers(&mut self) {
+        self.shadow.clear();
+        self.observed.clear();
+    }
+
+    fn read_register_raw(&mut self, reg: u8, mut delay: impl DelayUs<u32>) -> Ads129xResult<u8, E> {
+        let stopped = self.stop_for_access(spi::DelayRef(&mut delay))?;
+        let mut words = [command::Command::RREG as u8 | reg, 0x00, 0xA5];
+        let value = self.spi.transfer(&mut words, spi::DelayRef(&mut delay))?[2];
+        self.resume_after_access(stopped, delay)?;
+        Ok(value)
+    }
+
+    /// Burst read `buf.len()` registers starting at `start`, `count` is the
+    /// register file size
+    fn read_registers_raw(
+        &mut self,
+        start: u8,
+        count: usize,
+        buf: &mut [u8],
         mut delay: impl DelayUs<u32>,
     ) -> Ads129xResult<(), E> {
-        // Read status_word/data
-        {
-            let _ = self.spi.ncs.set_low();
-            delay.delay_us(40);
+        if buf.is_empty() || start as usize + buf.len() > count {
+            return Err(Ads129xError::InvalidArgument(
+                "register range out of bounds",
+            ));
+        }
 
-            // Read status word
-            for idx in 0..data_frame.status_word.len() {
-                nb::block!(self.spi.spi.send(0x00))?;
-                data_frame.status_word[idx] = nb::block!(self.spi.spi.read())?;
+        let stopped = self.stop_for_access(spi::DelayRef(&mut delay))?;
+        let header = [command::Command::RREG as u8 | start, buf.len() as u8 - 1];
+        self.spi
+            .write_read(&header, buf, spi::DelayRef(&mut delay))?;
+        self.resume_after_access(stopped, delay)
+    }
+
+    /// Burst write `values` starting at `start`, `registers` is the register
+    /// map, read-only registers in the range are rejected
+    fn write_registers_raw(
+        &mut self,
+        start: u8,
+        registers: &[common::RegisterInfo],
+        values: &[u8],
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        let range = start as usize..start as usize + values.len();
+        let infos = match registers.get(range) {
+            Some(infos) if !infos.is_empty() => infos,
+            _ => {
+                return Err(Ads129xError::InvalidArgument(
+                    "register range out of bounds",
+                ))
             }
-            // Read channels data, i24 big endian byte order
-            for idx in 0..CH {
-                let mut bb = [0x00u8; 4];
-                nb::block!(self.spi.spi.send(0x00))?;
-                bb[2] = nb::block!(self.spi.spi.read())?;
-                nb::block!(self.spi.spi.send(0x00))?;
-                bb[1] = nb::block!(self.spi.spi.read())?;
-                nb::block!(self.spi.spi.send(0x00))?;
-                bb[0] = nb::block!(self.spi.spi.read())?;
-                // Assemble sample as le
-                data_frame.data[idx] = i32::from_le_bytes(bb);
-                // Sign extend i24 -> i32
-                // On ARM should be optimized to SBFX instruction
-                data_frame.data[idx] = data_frame.data[idx] << 8 >> 8;
+        };
+        if infos
+            .iter()
+            .any(|info| info.access == common::Access::ReadOnly)
+        {
+            return Err(Ads129xError::InvalidArgument("read-only register"));
+        }
+
+        let stopped = self.stop_for_access(spi::DelayRef(&mut delay))?;
+        let header = [command::Command::WREG as u8 | start, values.len() as u8 - 1];
+        self.spi
+            .write_burst(&header, values, spi::DelayRef(&mut delay))?;
+        for ((reg, info), &value) in (start..).zip(infos).zip(values) {
+            self.written(reg, info, value, spi::DelayRef(&mut delay))?;
+        }
+        self.resume_after_access(stopped, delay)
+    }
+
+    fn write_register_raw(
+        &mut self,
+        reg: u8,
+        info: &common::RegisterInfo,
+        value: u8,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        if info.access == common::Access::ReadOnly {
+            return Err(Ads129xError::InvalidArgument("read-only register"));
+        }
+
+        let stopped = self.stop_for_access(spi::DelayRef(&mut delay))?;
+        let words = [command::Command::WREG as u8 | reg, 0x00, value];
+        self.spi.write(&words, spi::DelayRef(&mut delay))?;
+        self.written(reg, info, value, spi::DelayRef(&mut delay))?;
+        self.resume_after_access(stopped, delay)
+    }
+
+    /// Destroy driver, releasing the control pins too
+    pub fn destroy_with_pins(self) -> (SPI, NCS, PINS) {
+        let (spi, ncs) = self.spi.destroy();
+        (spi, ncs, self.pins)
+    }
+
+    /// Raise PWDN, wait `tPOR` and reset, leaving the device in `RDATAC`
+    fn leave_power_down(&mut self, mut delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        self.require_pin(pins::ControlPin::Pwdn, "power-up without PWDN pin")?;
+        self.set_pin(pins::ControlPin::Pwdn, true)?;
+        delay.delay_us(timing::POWER_UP_US);
+        self.pulse_reset(delay)
+    }
+
+    /// Reset, extending the recovery wait to 18 `tCLK` at `fclk_hz` when given
+    fn reset_with_recovery(
+        &mut self,
+        fclk_hz: Option<u32>,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        if fclk_hz == Some(0) {
+            return Err(Ads129xError::InvalidArgument("zero clock frequency"));
+        }
+
+        self.pulse_reset(spi::DelayRef(&mut delay))?;
+        if let Some(hz) = fclk_hz {
+            let remaining = timing::reset_recovery_us(hz).saturating_sub(timing::RESET_RECOVERY_US);
+            if remaining > 0 {
+                delay.delay_us(remaining);
             }
+        }
+        Ok(())
+    }
 
-            delay.delay_us(40);
-            let _ = self.spi.ncs.set_high();
-            delay.delay_us(20);
+    /// Put the device back into the tracked mode, `continuous` is its current
+    fn resume_mode(&mut self, continuous: bool, delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        match mode::resume_opcode::<MODE>(continuous) {
+            Some(opcode) => self.command(opcode, delay),
+            None => Ok(()),
         }
+    }
 
-        // Validate status word
-        let status_word = data_frame.status_word();
-        if status_word.sync() != 0b1100 {
-            return Err(Ads129xError::StatusWordMissmatch(status_word.sync()));
+    fn identify(&mut self, delay: impl DelayUs<u32>) -> Ads129xResult<common::id::DevModel, E> {
+        let mut words = [command::Command::RREG as u8 | 0x00, 0x00, 0xA5];
+        let res = self.spi.transfer(&mut words, delay)?;
+
+        let model = common::id::DevModel::try_from(common::id::IdReg(res[2]))
+            .map_err(|e| Ads129xError::IdRegRead(e))?;
+
+        Ok(model)
+    }
+
+    fn command(
+        &mut self,
+        command: command::Command,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.spi.write(&[command as u8], delay)?;
+        self.follow(command as u8);
+        Ok(())
+    }
+
+    /// Follow the device mode through a sent opcode
+    fn follow(&mut self, opcode: u8) {
+        if let Some(continuous) = mode::followed(opcode) {
+            self.continuous = continuous;
+        }
+    }
+
+    /// Leave continuous mode ahead of register access when [`mode::Dynamic`]
+    /// believes the device is in it, returns whether `SDATAC` went out
+    fn stop_for_access(&mut self, delay: impl DelayUs<u32>) -> Ads129xResult<bool, E> {
+        if !mode::stops_for_access::<MODE>(self.continuous, self.access) {
+            return Ok(false);
+        }
+        self.command(command::Command::SDATAC, delay)?;
+        Ok(true)
+    }
+
+    /// Back to continuous mode after the access when the policy says so
+    fn resume_after_access(
+        &mut self,
+        stopped: bool,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        if mode::resumes_after_access(stopped, self.access) {
+            self.command(command::Command::RDATAC, delay)?;
+        }
+        Ok(())
+    }
+
+    fn into_mode<M>(self) -> Ads129x<SPI, NCS, DEV, CH, PINS, M> {
+        self.rebuild(|pins| pins)
+    }
+
+    fn into_family<F>(self) -> Ads129x<SPI, NCS, F, CH, PINS, MODE> {
+        self.rebuild(|pins| pins)
+    }
+
+    fn set_pin(&mut self, pin: pins::ControlPin, high: bool) -> Ads129xResult<(), E> {
+        self.pins.set(pin, high).map_err(|_| Ads129xError::Pin(pin))
+    }
+
+    /// Shift `len` bytes into the front of `buf` in one transaction
+    fn transfer_raw(
+        &mut self,
+        buf: &mut [u8],
+        len: usize,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        let buf = buf.get_mut(..len).ok_or(Ads129xError::InvalidArgument(
+            "buffer shorter than the frame",
+        ))?;
+        buf.fill(0x00);
+        self.spi.transfer(buf, delay)?;
+        Ok(())
+    }
+
+    /// Count the frame, reject a sync mismatch under strict validation
+    fn check_sync(&mut self, raw: [u8; 3]) -> Ads129xResult<(), E> {
+        self.spi.stats.frame(raw);
+        self.validation.check(raw)
+    }
+
+    fn require_pin(&self, pin: pins::ControlPin, what: &'static str) -> Ads129xResult<(), E> {
+        if !self.pins.is_connected(pin) {
+            return Err(Ads129xError::NotSupported { model: None, what });
         }
+        Ok(())
+    }
 
+    /// Reject `what` while the device is known to be in continuous mode
+    fn require_command_mode(&self, what: &'static str) -> Ads129xResult<(), E> {
+        if self.continuous {
+            return Err(Ads129xError::NotSupported { model: None, what });
+        }
         Ok(())
     }
+}
+
+impl<SPI, NCS, DEV, E, PINS, MODE, const CH: usize> Ads129x<SPI, NCS, DEV, CH, PINS, MODE>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+    DEV: variant::FamilyVariant,
+{
+    /// Identify the device
+    ///
+    /// A driver of an R variant, see [`variant`], fails with
+    /// [`Ads129xError::VariantMismatch`] on a device without respiration.
+    pub fn read_id(
+        &mut self,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<common::id::DevModel, E> {
+        let stopped = self.stop_for_access(spi::DelayRef(&mut delay))?;
+        let model = self.identify(spi::DelayRef(&mut delay))?;
+        self.resume_after_access(stopped, delay)?;
 
-    read_reg!(FAM: ads1298, FN: config, REG: CONFIG1 (conf::Config <= conf::Config1Reg));
-    write_reg!(FAM: ads1298, FN: set_config, REG: CONFIG1 (conf::Config => conf::Config1Reg));
-    read_reg!(FAM: ads1298, FN: test_signal_config, REG: CONFIG2 (conf::TestSignalConfig <= conf::Config2Reg));
-    write_reg!(FAM: ads1298, FN: set_test_signal_config, REG: CONFIG2 (conf::TestSignalConfig => conf::Config2Reg));
-    read_reg!(FAM: ads1298, FN: test_rld_config, REG: CONFIG3 (conf::RldConfig <= conf::Config3Reg));
-    write_reg!(FAM: ads1298, FN: set_rld_config, REG: CONFIG3 (conf::RldConfig => conf::Config3Reg));
-
-    read_reg!(FAM: ads1298, FN: leadoff_control, REG: LOFF (loff::LeadOffControl <= loff::LeadOffControlReg));
-    write_reg!(FAM: ads1298, FN: set_leadoff_control, REG: LOFF (loff::LeadOffControl => loff::LeadOffControlReg));
-
-    read_reg!(FAM: ads1298, FN: chan_1, REG: CH1SET (chan::Chan <= chan::ChanSetReg));
-    read_reg!(FAM: ads1298, FN: chan_2, REG: CH2SET (chan::Chan <= chan::ChanSetReg));
-    read_reg!(FAM: ads1298, FN: chan_3, REG: CH3SET (chan::Chan <= chan::ChanSetReg));
-    read_reg!(FAM: ads1298, FN: chan_4, REG: CH4SET (chan::Chan <= chan::ChanSetReg));
-    read_reg!(FAM: ads1298, FN: chan_5, REG: CH5SET (chan::Chan <= chan::ChanSetReg));
-    read_reg!(FAM: ads1298, FN: chan_6, REG: CH6SET (chan::Chan <= chan::ChanSetReg));
-    read_reg!(FAM: ads1298, FN: chan_7, REG: CH7SET (chan::Chan <= chan::ChanSetReg));
-    read_reg!(FAM: ads1298, FN: chan_8, REG: CH8SET (chan::Chan <= chan::ChanSetReg));
-
-    write_reg!(FAM: ads1298, FN: set_chan_1, REG: CH1SET (chan::Chan => chan::ChanSetReg));
-    write_reg!(FAM: ads1298, FN: set_chan_2, REG: CH2SET (chan::Chan => chan::ChanSetReg));
-    write_reg!(FAM: ads1298, FN: set_chan_3, REG: CH3SET (chan::Chan => chan::ChanSetReg));
-    write_reg!(FAM: ads1298, FN: set_chan_4, REG: CH4SET (chan::Chan => chan::ChanSetReg));
-    write_reg!(FAM: ads1298, FN: set_chan_5, REG: CH5SET (chan::Chan => chan::ChanSetReg));
-    write_reg!(FAM: ads1298, FN: set_chan_6, REG: CH6SET (chan::Chan => chan::ChanSetReg));
-    write_reg!(FAM: ads1298, FN: set_chan_7, REG: CH7SET (chan::Chan => chan::ChanSetReg));
-    write_reg!(FAM: ads1298, FN: set_chan_8, REG: CH8SET (chan::Chan => chan::ChanSetReg));
-
-    read_reg!(FAM: ads1298, FN: leadoff_sense_positive, REG: LOFF_SENSP (loff::LeadOffSense <= loff::LeadOffSenseReg));
-    write_reg!(FAM: ads1298, FN: set_leadoff_sense_positive, REG: LOFF_SENSP (loff::LeadOffSense => loff::LeadOffSenseReg));
-    read_reg!(FAM: ads1298, FN: leadoff_sense_negative, REG: LOFF_SENSN (loff::LeadOffSense <= loff::LeadOffSenseReg));
-    write_reg!(FAM: ads1298, FN: set_leadoff_sense_negative, REG: LOFF_SENSN (loff::LeadOffSense => loff::LeadOffSenseReg));
-    read_reg!(FAM: ads1298, FN: leadoff_flip, REG: LOFF_FLIP (loff::LeadOffFlip <= loff::LeadOffFlipReg));
-    write_reg!(FAM: ads1298, FN: set_leadoff_flip, REG: LOFF_FLIP (loff::LeadOffFlip => loff::LeadOffFlipReg));
-
-    read_reg!(FAM: ads1298, FN: gpio, REG: GPIO (gpio::Gpio <= gpio::GpioReg));
-    write_reg!(FAM: ads1298, FN: set_gpio, REG: GPIO (gpio::Gpio => gpio::GpioReg));
-
-    read_reg!(FAM: ads1298, FN: misc_config, REG: CONFIG4 (conf::MiscConfig <= conf::Config4Reg));
-    write_reg!(FAM: ads1298, FN: set_misc_config, REG: CONFIG4 (conf::MiscConfig => conf::Config4Reg));
+        if DEV::RESPIRATION && !model.is_respiration_variant() {
+            return Err(Ads129xError::VariantMismatch { found: model });
+        }
+        Ok(model)
+    }
+
+    /// Identify the device, tolerating garbage right after power-up or reset
+    ///
+    /// Retries an unrecognized `ID` up to `retries` times,
+    /// [`timing::ID_RETRY_US`] apart. The model must match the variant as
+    /// [`read_id`](Self::read_id) checks and have `CH` channels,
+    /// [`Ads129xError::DeviceMismatch`] otherwise.
+    pub fn verify_device(
+        &mut self,
+        mut delay: impl DelayUs<u32>,
+        retries: u8,
+    ) -> Ads129xResult<common::id::DevModel, E> {
+        let mut attempt = 0;
+        let model = loop {
+            match self.read_id(spi::DelayRef(&mut delay)) {
+                Err(Ads129xError::IdRegRead(_)) if attempt < retries => {
+                    attempt += 1;
+                    delay.delay_us(timing::ID_RETRY_US);
+                }
+                res => break res?,
+            }
+        };
+
+        if model.channel_count() != CH {
+            return Err(Ads129xError::DeviceMismatch {
+                expected_channels: CH,
+                found:             model,
+            });
+        }
+        Ok(model)
+    }
+}
+
+impl<SPI, NCS, DEV, E, PINS, const CH: usize> Ads129x<SPI, NCS, DEV, CH, PINS, mode::Command>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+{
+    /// Enter continuous conversion mode with `RDATAC`
+    ///
+    /// Registers are out of reach until `set_command_mode`. The driver is
+    /// consumed even when the opcode fails to go out.
+    pub fn set_continuous_mode(
+        mut self,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<Ads129x<SPI, NCS, DEV, CH, PINS, mode::Continuous>, E> {
+        self.command(command::Command::RDATAC, delay)?;
+        Ok(self.into_mode())
+    }
+}
+
+impl<SPI, NCS, DEV, E, PINS, const CH: usize> Ads129x<SPI, NCS, DEV, CH, PINS, mode::Continuous>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+{
+    /// Enter command mode with `SDATAC`
+    ///
+    /// Frames are then read with `read_data_by_command`. The driver is
+    /// consumed even when the opcode fails to go out.
+    pub fn set_command_mode(
+        mut self,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<Ads129x<SPI, NCS, DEV, CH, PINS, mode::Command>, E> {
+        self.command(command::Command::SDATAC, delay)?;
+        Ok(self.into_mode())
+    }
+}
+
+impl<SPI, NCS, DEV, E, PINS, const CH: usize> Ads129x<SPI, NCS, DEV, CH, PINS, mode::Dynamic>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+{
+    /// Spi command RDATAC
+    pub fn set_continuous_mode(&mut self, delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        self.command(command::Command::RDATAC, delay)
+    }
+
+    /// Spi command SDATAC
+    pub fn set_command_mode(&mut self, delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        self.command(command::Command::SDATAC, delay)
+    }
+
+    /// Choose what register access does in continuous mode
+    ///
+    /// [`Stop`](mode::AccessPolicy::Stop) by default.
+    pub fn set_access_policy(&mut self, policy: mode::AccessPolicy) {
+        self.access = policy;
+    }
+
+    /// Device mode as followed by the driver
+    ///
+    /// Set by resets, `set_continuous_mode`/`set_command_mode` and raw
+    /// `RDATAC`/`SDATAC`.
+    pub fn is_continuous(&self) -> bool {
+        self.continuous
+    }
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, const CH: usize>
+    Ads129x<SPI, NCS, Ads1292Family<V>, CH, PINS, MODE>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::Mode,
+{
+    /// Leave deep power-down and restore the configuration
+    ///
+    /// Raises PWDN, waits `tPOR`, resets the device, sends `SDATAC` and replays
+    /// every register written through the driver. Each register is read
+    /// back, a mismatch is reported as [`Ads129xError::WriteVerifyFailed`].
+    /// The device is left in command mode, or back in `RDATAC` in
+    /// [`mode::Continuous`].
+    pub fn power_up_and_restore(&mut self, delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        self.power_up_and_replay(&ads1292::REGISTERS, delay)
+    }
+
+    /// PGA gain of every channel as last written to or read from `CHnSET`
+    ///
+    /// Channels not accessed since construction or the last reset report the
+    /// reset value ×6, as do raw writes of a reserved gain.
+    pub fn channel_gains(&self) -> [ads1292::chan::ChannelGain; CH] {
+        core::array::from_fn(|ch| {
+            self.observed
+                .get(ads1292::Register::CH1SET as u8 + ch as u8)
+                .and_then(|reg| {
+                    ads1292::chan::ChannelGain::try_from(ads1292::chan::ChanSetReg(reg).gain()).ok()
+                })
+                .unwrap_or(ads1292::chan::ChannelGain::X6)
+        })
+    }
+
+    /// Convert samples to µV with the [`channel_gains`](Self::channel_gains)
+    ///
+    /// The ADS1291 channel 2 slot converts at ×6.
+    pub fn frame_to_microvolts(&self, frame: &data::DataFrame92, vref_uv: u32) -> [i32; 2] {
+        let mut gains = [ads1292::chan::ChannelGain::X6; 2];
+        for (gain, cached) in gains.iter_mut().zip(self.channel_gains()) {
+            *gain = cached;
+        }
+        let mut uv = [0; 2];
+        frame.to_microvolts(&gains, vref_uv, &mut uv);
+        uv
+    }
+
+    /// Reset through the RESET pin, e.g. to recover a glitched device
+    ///
+    /// Pulses RESET low for [`timing::RESET_PULSE_US`], waits the 18 `tCLK`
+    /// recovery and sends `SDATAC`. With `restore` every register written
+    /// through the driver is replayed and verified like in
+    /// `power_up_and_restore`, otherwise the registers are left at their
+    /// defaults. The device is left in command mode, or back in `RDATAC` in
+    /// [`mode::Continuous`]. Fails with [`Ads129xError::NotSupported`] when
+    /// RESET isn't connected.
+    pub fn hard_reset(&mut self, restore: bool, delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        let registers = if restore {
+            Some(&ads1292::REGISTERS[..])
+        } else {
+            None
+        };
+        self.hard_reset_and_replay(registers, delay)
+    }
+
+    /// Bring the device up after the supplies were applied
+    ///
+    /// Waits for the power-on reset, resets the device, sends `SDATAC`,
+    /// optionally powers up the internal reference buffer (`CONFIG2`, other
+    /// bits at their defaults) and waits for it to settle. Returns the model
+    /// read from `ID`.
+    pub fn power_up_sequence(
+        &mut self,
+        options: common::PowerUpOptions,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<common::id::DevModel, E> {
+        delay.delay_us(options.power_on_wait_us);
+        self.pulse_reset(spi::DelayRef(&mut delay))?;
+        self.command(command::Command::SDATAC, spi::DelayRef(&mut delay))?;
+
+        if options.internal_reference {
+            let config = ads1292::conf::MiscConfig {
+                ref_buffer_enable: true,
+                ..Default::default()
+            };
+            self.write_register_raw(
+                ads1292::Register::CONFIG2 as u8,
+                ads1292::Register::CONFIG2.info(),
+                ads1292::conf::Config2Reg::from(config).0,
+                spi::DelayRef(&mut delay),
+            )?;
+            delay.delay_us(options.reference_wait_us);
+        }
+
+        let model = self.identify(spi::DelayRef(&mut delay))?;
+        self.resume_mode(false, delay)?;
+        Ok(model)
+    }
+
+    /// Clock out a frame, optionally preceded by `opcode` in the same transaction
+    fn read_frame(
+        &mut self,
+        data_frame: &mut data::DataFrame92,
+        opcode: Option<command::Command>,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        // Read status_word/data
+        self.spi.stats.transaction(opcode.is_some() as usize + 9);
+        let format = self.format;
+        self.spi.transaction(delay, |spi| {
+            spi::clock_frame(
+                spi,
+                opcode.map(|op| op as u8),
+                format,
+                &mut data_frame.status_word,
+                &mut data_frame.data,
+            )
+        })?;
+        for sample in data_frame.data.iter_mut().skip(CH) {
+            *sample = 0;
+        }
+
+        // Validate status word
+        self.check_sync(data_frame.status_word)
+    }
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, const CH: usize>
+    Ads129x<SPI, NCS, Ads1292Family<V>, CH, PINS, MODE>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::DataAccess,
+{
+    // Read data samples from ADC
+    // Data samples are sign extended unless set_sample_format says otherwise
+    // ADS1291 clocks out channel 2 as well, the slot is left zeroed
+    pub fn read_data(
+        &mut self,
+        data_frame: &mut data::DataFrame92,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.read_frame(data_frame, None, delay)
+    }
+
+    /// Wait for `DRDY`, then read the frame like `read_data`
+    ///
+    /// Busy-waits, see [`pins::wait_drdy`].
+    pub fn read_data_when_ready<P: InputPin>(
+        &mut self,
+        drdy: &P,
+        data_frame: &mut data::DataFrame92,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        pins::wait_drdy(drdy).map_err(|_| Ads129xError::DrdyPin)?;
+        self.read_frame(data_frame, None, delay)
+    }
+
+    /// Wait up to `timeout_us` for `DRDY`, then read the frame like `read_data`
+    ///
+    /// Fails with [`Ads129xError::Timeout`] when `DRDY` stays high, see
+    /// [`pins::wait_drdy_timeout`].
+    pub fn read_data_timeout<P: InputPin>(
+        &mut self,
+        drdy: &P,
+        timeout_us: u32,
+        data_frame: &mut data::DataFrame92,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        if !pins::wait_drdy_timeout(drdy, timeout_us, &mut delay)
+            .map_err(|_| Ads129xError::DrdyPin)?
+        {
+            return Err(Ads129xError::Timeout);
+        }
+        self.read_frame(data_frame, None, delay)
+    }
+
+    /// Clock out a raw frame into the front of `buf`
+    ///
+    /// Asserts nCS, shifts `raw_frame_len` bytes and releases nCS. Nothing is
+    /// validated, parse the bytes with `DataFrame92::from_bytes`.
+    pub fn read_raw_frame(
+        &mut self,
+        buf: &mut [u8],
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.transfer_raw(buf, data::DataFrame92::raw_frame_len(), delay)
+    }
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, const CH: usize>
+    Ads129x<SPI, NCS, Ads1292Family<V>, CH, PINS, MODE>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+{
+    /// Read single frame with the `RDATA` command
+    ///
+    /// For command mode, after `SDATAC`, once DRDY went low.
+    pub fn read_data_by_command(
+        &mut self,
+        data_frame: &mut data::DataFrame92,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.read_frame(data_frame, Some(command::Command::RDATA), delay)
+    }
+
+    /// Read register as raw byte
+    ///
+    /// Escape hatch for settings the typed API doesn't cover.
+    pub fn read_register(
+        &mut self,
+        reg: ads1292::Register,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<u8, E> {
+        self.read_register_raw(reg as u8, delay)
+    }
+
+    /// Read consecutive registers starting at `start` in one transaction
+    ///
+    /// Fails with [`Ads129xError::InvalidArgument`] when `buf` is empty or
+    /// runs past the last register.
+    pub fn read_registers(
+        &mut self,
+        start: ads1292::Register,
+        buf: &mut [u8],
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.read_registers_raw(start as u8, ads1292::REGISTERS.len(), buf, delay)
+    }
+
+    /// Read the whole register file, in register order
+    ///
+    /// Sends `SDATAC` first so the dump works in continuous mode too, the
+    /// device is left in command mode.
+    pub fn dump_registers(&mut self, mut delay: impl DelayUs<u32>) -> Ads129xResult<[u8; 12], E> {
+        self.command(command::Command::SDATAC, spi::DelayRef(&mut delay))?;
+        let mut regs = [0x00; 12];
+        self.read_registers(ads1292::Register::ID, &mut regs, delay)?;
+        Ok(regs)
+    }
+
+    /// Write consecutive registers starting at `start` in one transaction
+    ///
+    /// Fails with [`Ads129xError::InvalidArgument`] when `values` is empty,
+    /// runs past the last register or covers a read-only register.
+    pub fn write_registers(
+        &mut self,
+        start: ads1292::Register,
+        values: &[u8],
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.write_registers_raw(start as u8, &ads1292::REGISTERS, values, delay)
+    }
+
+    /// Write the whole configuration
+    ///
+    /// `CONFIG1` through `GPIO` go out in a single burst.
+    pub fn apply_config(
+        &mut self,
+        config: &ads1292::DeviceConfig,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        use ads1292::{chan, conf, gpio, loff, resp, rld};
+
+        let status = loff::LeadOffStatus {
+            clk_div: config.clk_div,
+            ..Default::default()
+        };
+        let regs = [
+            conf::Config1Reg::from(config.config).0,
+            conf::Config2Reg::from(config.misc).0,
+            loff::LeadOffControlReg::from(config.leadoff_control).0,
+            chan::ChanSetReg::from(config.channels[0]).0,
+            chan::ChanSetReg::from(config.channels[1]).0,
+            rld::RldSenseReg::from(config.rld_sense).0,
+            loff::LoffSense::from(config.leadoff_sense).0,
+            loff::LeadOffStatusReg::from(status).0,
+            resp::RespControl1Reg::from(config.resp).0,
+            resp::RespControl2Reg::from(config.resp2).0,
+            gpio::GpioReg::from(config.gpio).0,
+        ];
+        self.write_registers(ads1292::Register::CONFIG1, &regs, delay)
+    }
+
+    /// Read every channel setting with a single burst
+    pub fn channels(
+        &mut self,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<[ads1292::chan::Chan; CH], E> {
+        let mut regs = [0x00; CH];
+        self.read_registers(ads1292::Register::CH1SET, &mut regs, delay)?;
+        self.observe(ads1292::Register::CH1SET as u8, &regs);
+
+        let mut chans = [ads1292::chan::Chan::default(); CH];
+        for (chan, reg) in chans.iter_mut().zip(regs) {
+            *chan = ads1292::chan::Chan::try_from(ads1292::chan::ChanSetReg(reg))
+                .map_err(Ads129xError::ReadInterpret)?;
+        }
+        Ok(chans)
+    }
+
+    /// Write the same setting to every channel with a single burst `WREG`
+    pub fn set_all_channels(
+        &mut self,
+        chan: ads1292::chan::Chan,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        let regs = [ads1292::chan::ChanSetReg::from(chan).0; CH];
+        self.write_registers(ads1292::Register::CH1SET, &regs, delay)
+    }
+
+    /// Set or clear `RESP2` CALIB_ON
+    ///
+    /// Read-modify-write, RLDREF_INT and RESP_FREQ are kept.
+    pub fn enable_offset_calibration(
+        &mut self,
+        enable: bool,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.modify_resp2(|resp2| resp2.offset_calibration_enable = enable, delay)
+    }
+
+    /// Channel offset calibration
+    ///
+    /// Sends `OFFSETCAL` and waits [`timing::offset_calibration_time_us`] at
+    /// `data_rate_sps`. Conversions must be stopped: fails with
+    /// [`Ads129xError::NotSupported`] while a [`mode::Dynamic`] driver tracks
+    /// the device in continuous mode, a `START` pin or command issued in
+    /// command mode isn't tracked. `CALIB_ON` must be set in RESP2, see
+    /// [`enable_offset_calibration`](Self::enable_offset_calibration).
+    pub fn calibrate_offset(
+        &mut self,
+        data_rate_sps: u32,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.require_command_mode("offset calibration in continuous mode")?;
+        if data_rate_sps == 0 {
+            return Err(Ads129xError::InvalidArgument("zero data rate"));
+        }
+        self.spi.write(
+            &[command::Command::OFFSETCAL as u8],
+            spi::DelayRef(&mut delay),
+        )?;
+        delay.delay_us(timing::offset_calibration_time_us(data_rate_sps));
+        Ok(())
+    }
+
+    /// [`calibrate_offset`](Self::calibrate_offset) at the configured data
+    /// rate
+    ///
+    /// Takes the rate from the last written `CONFIG1`, reads it when there is
+    /// none. `CALIB_ON` must be set, see
+    /// [`enable_offset_calibration`](Self::enable_offset_calibration).
+    pub fn run_offset_calibration(&mut self, mut delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        self.require_command_mode("offset calibration in continuous mode")?;
+        let data_rate_sps = self.data_rate_sps(spi::DelayRef(&mut delay))?;
+        self.calibrate_offset(data_rate_sps, delay)
+    }
+
+    /// Data rate of the last written `CONFIG1`, read from the device when
+    /// there is none
+    fn data_rate_sps(&mut self, delay: impl DelayUs<u32>) -> Ads129xResult<u32, E> {
+        let config = match self.shadow.get(ads1292::Register::CONFIG1 as u8) {
+            Some(val) => ads1292::conf::Config::try_from(ads1292::conf::Config1Reg(val))
+                .map_err(Ads129xError::ReadInterpret)?,
+            None => self.config(delay)?,
+        };
+        Ok(config.sample_rate.as_sps())
+    }
+
+    /// Write register as raw byte
+    ///
+    /// The value is replayed by `power_up_and_restore` like typed writes.
+    /// Read-only registers are rejected with
+    /// [`Ads129xError::InvalidArgument`].
+    pub fn write_register(
+        &mut self,
+        reg: ads1292::Register,
+        value: u8,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.write_register_raw(reg as u8, reg.info(), value, delay)
+    }
+
+    /// Select the modulator clock divider
+    ///
+    /// Writes `LOFF_STAT` CLK_DIV, the only writable bit of the register.
+    ///
+    /// The resulting `fMOD` is checked against [`ads1292::loff::FMOD_RANGE_HZ`] for the clock
+    /// source declared with `set_clock_source`, the register is left untouched on
+    /// [`Ads129xError::ClockOutOfRange`].
+    pub fn set_clk_div(
+        &mut self,
+        div: ads1292::loff::ClkDiv,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        let fclk_hz = match self.clock {
+            common::ClockSource::InternalOscillator => ads1292::INTERNAL_OSC_HZ,
+            common::ClockSource::ExternalClock { hz } => hz,
+        };
+        if div.modulator_clock(fclk_hz).is_none() {
+            return Err(Ads129xError::ClockOutOfRange {
+                fclk_hz,
+                fmod_hz: fclk_hz / div.ratio(),
+            });
+        }
+
+        // Lead-off status bits are read-only, only CLK_DIV is written
+        let status = ads1292::loff::LeadOffStatus {
+            clk_div: div,
+            ..Default::default()
+        };
+        let reg = ads1292::Register::LOFF_STAT;
+        let value = ads1292::loff::LeadOffStatusReg::from(status).0;
+        self.write_register_raw(reg as u8, reg.info(), value, delay)
+    }
+
+    /// Alias of [`set_clk_div`](Self::set_clk_div)
+    pub fn set_clock_divider(
+        &mut self,
+        div: ads1292::loff::ClkDiv,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.set_clk_div(div, delay)
+    }
+
+    /// Read register LOFF_STAT
+    #[deprecated(note = "use `leadoff_status`")]
+    pub fn loff_status(
+        &mut self,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<ads1292::loff::LeadOffStatus, E> {
+        self.leadoff_status(delay)
+    }
+
+    /// Write register LOFF_STAT
+    ///
+    /// Only CLK_DIV is writable, the lead-off status bits are ignored by the
+    /// device.
+    #[deprecated(note = "use `set_clock_divider`")]
+    pub fn set_loff_status(
+        &mut self,
+        status: ads1292::loff::LeadOffStatus,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        let reg = ads1292::Register::LOFF_STAT;
+        let value = ads1292::loff::LeadOffStatusReg::from(status).0;
+        self.write_register_raw(reg as u8, reg.info(), value, delay)
+    }
+
+    ads1292_registers!();
+}
+
+impl<SPI, NCS, E, PINS, MODE, const CH: usize>
+    Ads129x<SPI, NCS, Ads1292Family<variant::R>, CH, PINS, MODE>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+{
+    /// Set up the ADS1292R respiration circuitry
+    ///
+    /// Fails with [`Ads129xError::InvalidArgument`] before writing anything
+    /// when the phase is from the table of the other clock frequency. Powers
+    /// the reference buffer in `CONFIG2` when modulating, then writes `CH1SET`,
+    /// `RESP2` RESP_FREQ, keeping its other bits, and `RESP1` last so that
+    /// the circuitry starts with the clock and channel already set.
+    pub fn configure_respiration(
+        &mut self,
+        setup: ads1292::resp::RespirationSetup,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        use ads1292::resp;
+
+        setup.check().map_err(Ads129xError::InvalidArgument)?;
+
+        if setup.modulation_enable {
+            self.modify_misc_config(
+                |misc| misc.ref_buffer_enable = true,
+                spi::DelayRef(&mut delay),
+            )?;
+        }
+        self.set_chan_1(setup.channel, spi::DelayRef(&mut delay))?;
+        self.modify_resp2(
+            |resp2| resp2.resp_freq_64khz = setup.frequency == resp::RespFrequency::KHz64,
+            spi::DelayRef(&mut delay),
+        )?;
+        let resp1 = resp::Resp1 {
+            clock:               setup.clock,
+            phase:               setup.phase,
+            modulation_enable:   setup.modulation_enable,
+            demodulation_enable: setup.demodulation_enable,
+        };
+        self.set_resp(resp1, delay)
+    }
+
+    /// Read back the respiration setup from `RESP1`, `RESP2` and `CH1SET`
+    pub fn respiration_config(
+        &mut self,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<ads1292::resp::RespirationSetup, E> {
+        use ads1292::resp;
+
+        let mut regs = [0x00; 2];
+        self.read_registers(
+            ads1292::Register::RESP1,
+            &mut regs,
+            spi::DelayRef(&mut delay),
+        )?;
+        let channel = self.chan_1(delay)?;
+        resp::RespirationSetup::from_regs(
+            resp::RespControl1Reg(regs[0]),
+            resp::RespControl2Reg(regs[1]),
+            channel,
+        )
+        .map_err(Ads129xError::ReadInterpret)
+    }
+
+    ads1292r_registers!();
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, const CH: usize>
+    Ads129x<SPI, NCS, Ads1298Family<V>, CH, PINS, MODE>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::Mode,
+{
+    /// Leave deep power-down and restore the configuration
+    ///
+    /// Raises PWDN, waits `tPOR`, resets the device, sends `SDATAC` and replays
+    /// every register written through the driver. Each register is read
+    /// back, a mismatch is reported as [`Ads129xError::WriteVerifyFailed`].
+    /// The device is left in command mode, or back in `RDATAC` in
+    /// [`mode::Continuous`].
+    pub fn power_up_and_restore(&mut self, delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        self.power_up_and_replay(&ads1298::REGISTERS, delay)
+    }
+
+    /// PGA gain of every channel as last written to or read from `CHnSET`
+    ///
+    /// Channels not accessed since construction or the last reset report the
+    /// reset value ×6, as do raw writes of a reserved gain.
+    pub fn channel_gains(&self) -> [ads1298::chan::ChannelGain; CH] {
+        core::array::from_fn(|ch| {
+            self.observed
+                .get(ads1298::Register::CH1SET as u8 + ch as u8)
+                .and_then(|reg| {
+                    ads1298::chan::ChannelGain::try_from(ads1298::chan::ChanSetReg(reg).gain()).ok()
+                })
+                .unwrap_or(ads1298::chan::ChannelGain::X6)
+        })
+    }
+
+    /// Convert samples to µV with the [`channel_gains`](Self::channel_gains)
+    pub fn frame_to_microvolts(&self, frame: &data::DataFrame<CH>, vref_uv: u32) -> [i32; CH] {
+        let mut uv = [0; CH];
+        frame.to_microvolts(&self.channel_gains(), vref_uv, &mut uv);
+        uv
+    }
+
+    /// Reset through the RESET pin, e.g. to recover a glitched device
+    ///
+    /// Pulses RESET low for [`timing::RESET_PULSE_US`], waits the 18 `tCLK`
+    /// recovery and sends `SDATAC`. With `restore` every register written
+    /// through the driver is replayed and verified like in
+    /// `power_up_and_restore`, otherwise the registers are left at their
+    /// defaults. The device is left in command mode, or back in `RDATAC` in
+    /// [`mode::Continuous`]. Fails with [`Ads129xError::NotSupported`] when
+    /// RESET isn't connected.
+    pub fn hard_reset(&mut self, restore: bool, delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        let registers = if restore {
+            Some(&ads1298::REGISTERS[..])
+        } else {
+            None
+        };
+        self.hard_reset_and_replay(registers, delay)
+    }
+
+    /// Bring the device up after the supplies were applied
+    ///
+    /// Waits for the power-on reset, resets the device, sends `SDATAC`,
+    /// optionally powers up the internal reference buffer (`CONFIG3`, other
+    /// bits at their defaults) and waits for it to settle. Returns the model
+    /// read from `ID`.
+    pub fn power_up_sequence(
+        &mut self,
+        options: common::PowerUpOptions,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<common::id::DevModel, E> {
+        delay.delay_us(options.power_on_wait_us);
+        self.pulse_reset(spi::DelayRef(&mut delay))?;
+        self.command(command::Command::SDATAC, spi::DelayRef(&mut delay))?;
+
+        if options.internal_reference {
+            let config = ads1298::conf::RldConfig {
+                ref_buffer_enable: true,
+                ..Default::default()
+            };
+            self.write_register_raw(
+                ads1298::Register::CONFIG3 as u8,
+                ads1298::Register::CONFIG3.info(),
+                ads1298::conf::Config3Reg::from(config).0,
+                spi::DelayRef(&mut delay),
+            )?;
+            delay.delay_us(options.reference_wait_us);
+        }
+
+        let model = self.identify(spi::DelayRef(&mut delay))?;
+        self.resume_mode(false, delay)?;
+        Ok(model)
+    }
+
+    /// Clock out a frame, optionally preceded by `opcode`
+    fn read_frame(
+        &mut self,
+        data_frame: &mut data::DataFrame<CH>,
+        opcode: Option<command::Command>,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.read_frame_with(data_frame, opcode, || (), delay)
+    }
+
+    /// `read_frame`, calling `selected` right after nCS is asserted
+    fn read_frame_with(
+        &mut self,
+        data_frame: &mut data::DataFrame<CH>,
+        opcode: Option<command::Command>,
+        selected: impl FnOnce(),
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        // Read status_word/data
+        self.spi
+            .stats
+            .transaction(opcode.is_some() as usize + 3 + 3 * CH);
+        let mut buf = [0x00u8; spi::FRAME_BUF_LEN];
+        let rx = self.spi.transaction(delay, |spi| {
+            selected();
+            spi::transfer_frame::<_, _, CH>(spi, opcode.map(|op| op as u8), &mut buf)
+        })?;
+
+        // Length always matches CH, the frame is filled even on a sync error
+        let _ = data::parse_frame_with(rx, data_frame, self.format);
+
+        // Validate status word
+        self.check_sync(data_frame.status_word)
+    }
+
+    /// Read a streamed frame once `DRDY` is asserted and hand it to
+    /// `on_frame`
+    ///
+    /// Shared by the blocking and async streams, which only differ in how
+    /// they wait for `DRDY`.
+    fn stream_frame(
+        &mut self,
+        data_frame: &mut data::DataFrame<CH>,
+        on_frame: &mut impl FnMut(&data::DataFrame<CH>) -> core::ops::ControlFlow<()>,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<core::ops::ControlFlow<()>, E> {
+        self.read_frame(data_frame, None, delay)?;
+        Ok(on_frame(data_frame))
+    }
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, const CH: usize>
+    Ads129x<SPI, NCS, Ads1298Family<V>, CH, PINS, MODE>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::DataAccess,
+{
+    // Read data samples from ADC
+    // Data samples are sign extended unless set_sample_format says otherwise
+    pub fn read_data(
+        &mut self,
+        data_frame: &mut data::DataFrame<CH>,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.read_frame(data_frame, None, delay)
+    }
+
+    /// Read frame like `read_data`, stamped by the timestamp source
+    ///
+    /// The source set with `set_timestamp_source` is sampled once nCS is
+    /// asserted, before the frame is clocked out. Fails with
+    /// [`Ads129xError::NotSupported`] when no source is set.
+    pub fn read_data_timestamped(
+        &mut self,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<data::TimestampedFrame<CH>, E> {
+        let source = self.timestamp.ok_or(Ads129xError::NotSupported {
+            model: None,
+            what:  "timestamped read without timestamp source",
+        })?;
+
+        let mut timestamp = 0;
+        let mut frame = data::DataFrame::new();
+        self.read_frame_with(&mut frame, None, || timestamp = source(), delay)?;
+        Ok(data::TimestampedFrame { timestamp, frame })
+    }
+
+    /// Wait for `DRDY`, then read the frame like `read_data`
+    ///
+    /// Busy-waits, see [`pins::wait_drdy`].
+    pub fn read_data_when_ready<P: InputPin>(
+        &mut self,
+        drdy: &P,
+        data_frame: &mut data::DataFrame<CH>,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        pins::wait_drdy(drdy).map_err(|_| Ads129xError::DrdyPin)?;
+        self.read_frame(data_frame, None, delay)
+    }
+
+    /// Wait up to `timeout_us` for `DRDY`, then read the frame like `read_data`
+    ///
+    /// Fails with [`Ads129xError::Timeout`] when `DRDY` stays high, see
+    /// [`pins::wait_drdy_timeout`].
+    pub fn read_data_timeout<P: InputPin>(
+        &mut self,
+        drdy: &P,
+        timeout_us: u32,
+        data_frame: &mut data::DataFrame<CH>,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        if !pins::wait_drdy_timeout(drdy, timeout_us, &mut delay)
+            .map_err(|_| Ads129xError::DrdyPin)?
+        {
+            return Err(Ads129xError::Timeout);
+        }
+        self.read_frame(data_frame, None, delay)
+    }
+
+    /// Clock out a raw frame into the front of `buf`
+    ///
+    /// Asserts nCS, shifts `raw_frame_len` bytes and releases nCS. Nothing is
+    /// validated, parse the bytes with `data::parse_frame`.
+    pub fn read_raw_frame(
+        &mut self,
+        buf: &mut [u8],
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.transfer_raw(buf, data::DataFrame::<CH>::raw_frame_len(), delay)
+    }
+
+    /// Read consecutive frames in a single transaction
+    ///
+    /// For continuous mode. nCS stays asserted across all frames, so they are
+    /// whatever DOUT shifts out after the first one, e.g. the following
+    /// devices of a daisy chain. The device has no FIFO: a frame is only
+    /// valid until the next `DRDY` and a missed `DRDY` loses it, later
+    /// conversions can't be collected in one burst. Stops at the first status
+    /// word sync mismatch; returns the number of valid frames at the front of
+    /// `frames`.
+    /// With [`Lenient`](data::FrameValidation::Lenient) validation all frames
+    /// are read and counted, check each `sync_ok()`.
+    pub fn read_frames(
+        &mut self,
+        frames: &mut [data::DataFrame<CH>],
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<usize, E> {
+        let strict = self.validation == data::FrameValidation::Strict;
+        let format = self.format;
+        let (read, valid) = self.spi.transaction(delay, |spi| {
+            for (idx, frame) in frames.iter_mut().enumerate() {
+                spi::clock_frame(spi, None, format, &mut frame.status_word, &mut frame.data)?;
+                if strict && !frame.sync_ok() {
+                    return Ok((idx + 1, idx));
+                }
+            }
+            Ok((frames.len(), frames.len()))
+        })?;
+
+        self.spi
+            .stats
+            .transaction(read * data::DataFrame::<CH>::RAW_LEN);
+        for frame in &frames[..read] {
+            self.spi.stats.frame(frame.status_word);
+        }
+        Ok(valid)
+    }
+
+    /// Read one frame from each of `N` daisy-chained devices
+    ///
+    /// Clocks out `N * (3 + 3 * CH)` bytes in a single nCS assertion,
+    /// `frames[0]` gets the device whose DOUT drives the MCU, `frames[1]` the
+    /// one feeding its DAISY_IN, and so on. All devices must be in daisy-chain
+    /// mode; when the last written `CONFIG1` selects multiple readback the
+    /// read fails with [`Ads129xError::NotSupported`]. Every sync nibble is
+    /// checked, the first mismatch is reported once all frames are stored.
+    pub fn read_daisy_frames<const N: usize>(
+        &mut self,
+        frames: &mut [data::DataFrame<CH>; N],
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        if let Some(val) = self.shadow.get(ads1298::Register::CONFIG1 as u8) {
+            if ads1298::conf::Config1Reg(val).daisy_disable() {
+                return Err(Ads129xError::NotSupported {
+                    model: None,
+                    what:  "daisy-chain read in multiple readback mode",
+                });
+            }
+        }
+
+        self.spi
+            .stats
+            .transaction(N * data::DataFrame::<CH>::RAW_LEN);
+        let format = self.format;
+        self.spi.transaction(delay, |spi| {
+            for frame in frames.iter_mut() {
+                spi::clock_frame(spi, None, format, &mut frame.status_word, &mut frame.data)?;
+            }
+            Ok(())
+        })?;
+
+        // Validate every device's status word
+        let mut result = Ok(());
+        for frame in frames.iter() {
+            result = result.and(self.check_sync(frame.status_word));
+        }
+        result
+    }
+
+    /// Read data samples without materializing a frame
+    ///
+    /// `status` is invoked once with the status word, then `sample` with the
+    /// channel index and the sample, in channel order. Both run while nCS is
+    /// asserted, keep them short.
+    ///
+    /// Validation matches `read_data`: on a strict status word sync mismatch
+    /// the frame is still clocked out, but `sample` isn't invoked.
+    pub fn read_data_with(
+        &mut self,
+        delay: impl DelayUs<u32>,
+        mut sample: impl FnMut(usize, i32),
+        status: impl FnOnce(data::DataStatusWord),
+    ) -> Ads129xResult<(), E> {
+        self.spi.stats.transaction(3 + 3 * CH);
+        let lenient = self.validation == data::FrameValidation::Lenient;
+        let format = self.format;
+        let raw = self.spi.transaction(delay, |spi| {
+            let mut buf = [0x00u8; spi::FRAME_BUF_LEN];
+            let rx = spi::transfer_frame::<_, _, CH>(spi, None, &mut buf)?;
+            let word = data::DataStatusWord(u32::from_be_bytes([0x00, rx[0], rx[1], rx[2]]));
+            let sync = word.sync();
+            status(word);
+
+            if sync == 0b1100 || lenient {
+                for (idx, chunk) in rx[3..].chunks_exact(3).enumerate() {
+                    sample(idx, format.from_sign_extended(data::i24_from_be(chunk)));
+                }
+            }
+            Ok([rx[0], rx[1], rx[2]])
+        })?;
+
+        // Validate status word
+        self.check_sync(raw)
+    }
+
+    /// Read data samples straight into `samples`
+    ///
+    /// Returns the status word. Sync is checked like `read_data`, on a strict
+    /// mismatch `samples` is left untouched.
+    pub fn read_data_into(
+        &mut self,
+        samples: &mut [i32; CH],
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<data::DataStatusWord, E> {
+        let mut word = data::DataStatusWord(0);
+        self.read_data_with(delay, |idx, sample| samples[idx] = sample, |w| word = w)?;
+        Ok(word)
+    }
+
+    /// Read frame once `DRDY` is asserted
+    ///
+    /// Returns `WouldBlock` right away while `DRDY` is high, otherwise reads
+    /// the frame like `read_data` in a single transaction, a failed pin read
+    /// is [`Ads129xError::DrdyPin`]. Needs no delay provider: nCS is toggled
+    /// without the setup/hold waits, which the device's nanosecond
+    /// `tCSSC`/`tSCCS` requirements don't need anyway.
+    pub fn poll_frame<P>(
+        &mut self,
+        drdy: &mut P,
+        data_frame: &mut data::DataFrame<CH>,
+    ) -> nb::Result<(), Ads129xError<E>>
+    where
+        P: InputPin,
+    {
+        match drdy.is_high() {
+            Ok(true) => Err(nb::Error::WouldBlock),
+            Ok(false) => Ok(self.read_frame(data_frame, None, spi::NoDelay)?),
+            Err(_) => Err(nb::Error::Other(Ads129xError::DrdyPin)),
+        }
+    }
+
+    /// Wait for `DRDY` falling edge, then read the frame
+    ///
+    /// The frame itself is clocked out with the blocking SPI like
+    /// `poll_frame`, only the wait yields to the executor; the
+    /// [`asynch`] driver awaits the transfer too. A failed wait is
+    /// [`Ads129xError::DrdyPin`].
+    #[cfg(feature = "async")]
+    pub async fn read_data_on_drdy<P>(
+        &mut self,
+        drdy: &mut P,
+        data_frame: &mut data::DataFrame<CH>,
+    ) -> Ads129xResult<(), E>
+    where
+        P: embedded_hal_async::digital::Wait,
+    {
+        drdy.wait_for_falling_edge()
+            .await
+            .map_err(|_| Ads129xError::DrdyPin)?;
+        self.read_frame(data_frame, None, spi::NoDelay)
+    }
+
+    /// Stream frames on `DRDY` into `on_frame`
+    ///
+    /// Stops after `frames` frames, or earlier when `on_frame` returns
+    /// `ControlFlow::Break`. Returns the number of frames read; a failed read
+    /// ends the stream with its error, a failed wait with
+    /// [`Ads129xError::DrdyPin`]. The async counterpart of the loop
+    /// [`stream_frames`](Self::stream_frames) runs.
+    #[cfg(feature = "async")]
+    pub async fn stream_on_drdy<P>(
+        &mut self,
+        drdy: &mut P,
+        frames: usize,
+        mut on_frame: impl FnMut(&data::DataFrame<CH>) -> core::ops::ControlFlow<()>,
+    ) -> Ads129xResult<usize, E>
+    where
+        P: embedded_hal_async::digital::Wait,
+    {
+        let mut data_frame = data::DataFrame::new();
+        for count in 1..=frames {
+            drdy.wait_for_falling_edge()
+                .await
+                .map_err(|_| Ads129xError::DrdyPin)?;
+            if self
+                .stream_frame(&mut data_frame, &mut on_frame, spi::NoDelay)?
+                .is_break()
+            {
+                return Ok(count);
+            }
+        }
+        Ok(frames)
+    }
+}
+
+impl<SPI, NCS, E, PINS, MODE, V, const CH: usize>
+    Ads129x<SPI, NCS, Ads1298Family<V>, CH, PINS, MODE>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+{
+    /// Read single frame with the `RDATA` command
+    ///
+    /// For command mode, after `SDATAC`, once DRDY went low.
+    pub fn read_data_by_command(
+        &mut self,
+        data_frame: &mut data::DataFrame<CH>,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.read_frame(data_frame, Some(command::Command::RDATA), delay)
+    }
+
+    /// Stream frames on `DRDY` into `on_frame` in continuous mode
+    ///
+    /// Sends `RDATAC`, starts conversions and reads a frame each time `DRDY`
+    /// goes low until `on_frame` returns `ControlFlow::Break`. Conversions
+    /// are then stopped and `SDATAC` sent, also when a read failed and ended
+    /// the stream with its error. Returns the number of frames read.
+    ///
+    /// Fails with [`Ads129xError::Timeout`] when `DRDY` stays high for
+    /// [`timing::settling_time_us`] at the data rate of the last written
+    /// `CONFIG1`, read from the device when there is none.
+    pub fn stream_frames<P: InputPin>(
+        &mut self,
+        drdy: &P,
+        mut delay: impl DelayUs<u32>,
+        on_frame: impl FnMut(&data::DataFrame<CH>) -> core::ops::ControlFlow<()>,
+    ) -> Ads129xResult<usize, E> {
+        let timeout_us = timing::settling_time_us(self.data_rate_sps(spi::DelayRef(&mut delay))?);
+        self.command(command::Command::RDATAC, spi::DelayRef(&mut delay))?;
+        let streamed =
+            self.stream_until_break(drdy, timeout_us, spi::DelayRef(&mut delay), on_frame);
+        let stopped = self.stop_conv(spi::DelayRef(&mut delay));
+        let resumed = self.command(command::Command::SDATAC, delay);
+        let count = streamed?;
+        stopped?;
+        resumed?;
+        Ok(count)
+    }
+
+    fn stream_until_break<P: InputPin>(
+        &mut self,
+        drdy: &P,
+        timeout_us: u32,
+        mut delay: impl DelayUs<u32>,
+        mut on_frame: impl FnMut(&data::DataFrame<CH>) -> core::ops::ControlFlow<()>,
+    ) -> Ads129xResult<usize, E> {
+        self.start_conv(spi::DelayRef(&mut delay))?;
+        let mut data_frame = data::DataFrame::new();
+        let mut count = 0;
+        loop {
+            if !pins::wait_drdy_timeout(drdy, timeout_us, &mut delay)
+                .map_err(|_| Ads129xError::DrdyPin)?
+            {
+                return Err(Ads129xError::Timeout);
+            }
+            count += 1;
+            if self
+                .stream_frame(&mut data_frame, &mut on_frame, spi::DelayRef(&mut delay))?
+                .is_break()
+            {
+                return Ok(count);
+            }
+        }
+    }
+
+    /// Trigger single conversion by pulsing the START pin and read it with `RDATA`
+    ///
+    /// Deterministic alternative to the `START` opcode for single-shot mode: the device must
+    /// be in command mode with `MiscConfig::single_shot_mode` set. START is held high for
+    /// [`timing::START_PULSE_US`], then the driver waits [`timing::settling_time_us`] for the
+    /// configured data rate, taken from the last written `CONFIG1` or read from the device.
+    ///
+    /// Fails with [`Ads129xError::NotSupported`] when START isn't connected.
+    pub fn trigger_single_shot_pin(
+        &mut self,
+        data_frame: &mut data::DataFrame<CH>,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.require_pin(pins::ControlPin::Start, "single-shot trigger without START pin")?;
+
+        let data_rate_sps = self.data_rate_sps(spi::DelayRef(&mut delay))?;
+
+        self.trigger_conversion(spi::DelayRef(&mut delay))?;
+        delay.delay_us(timing::settling_time_us(data_rate_sps));
+
+        self.read_frame(data_frame, Some(command::Command::RDATA), delay)
+    }
+
+    /// Single conversion, read with `RDATA`
+    ///
+    /// Sets `MiscConfig::single_shot_mode`, a read-modify-write of `CONFIG4`
+    /// skipped when the last written value has it already. Starts the
+    /// conversion with a START pulse when the pin is connected, the `START`
+    /// opcode otherwise, and waits [`timing::settling_time_us`] for the data
+    /// rate of the last written `CONFIG1`, read from the device when there
+    /// is none. For command mode.
+    pub fn convert_once(
+        &mut self,
+        data_frame: &mut data::DataFrame<CH>,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.modify_misc_config(
+            |misc| misc.single_shot_mode = true,
+            spi::DelayRef(&mut delay),
+        )?;
+        let data_rate_sps = self.data_rate_sps(spi::DelayRef(&mut delay))?;
+
+        self.trigger_conversion(spi::DelayRef(&mut delay))?;
+        delay.delay_us(timing::settling_time_us(data_rate_sps));
+
+        self.read_frame(data_frame, Some(command::Command::RDATA), delay)
+    }
+
+    /// [`convert_once`](Self::convert_once), waiting for DRDY low instead of
+    /// the conversion time
+    ///
+    /// Fails with [`Ads129xError::Timeout`] when `DRDY` stays high for
+    /// [`timing::settling_time_us`], see [`pins::wait_drdy_timeout`].
+    pub fn convert_once_when_ready<P: InputPin>(
+        &mut self,
+        drdy: &P,
+        data_frame: &mut data::DataFrame<CH>,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.modify_misc_config(
+            |misc| misc.single_shot_mode = true,
+            spi::DelayRef(&mut delay),
+        )?;
+        let data_rate_sps = self.data_rate_sps(spi::DelayRef(&mut delay))?;
+
+        self.trigger_conversion(spi::DelayRef(&mut delay))?;
+        if !pins::wait_drdy_timeout(drdy, timing::settling_time_us(data_rate_sps), &mut delay)
+            .map_err(|_| Ads129xError::DrdyPin)?
+        {
+            return Err(Ads129xError::Timeout);
+        }
+
+        self.read_frame(data_frame, Some(command::Command::RDATA), delay)
+    }
+
+    /// Data rate of the last written `CONFIG1`, read from the device when
+    /// there is none
+    fn data_rate_sps(&mut self, delay: impl DelayUs<u32>) -> Ads129xResult<u32, E> {
+        let config = match self.shadow.get(ads1298::Register::CONFIG1 as u8) {
+            Some(val) => ads1298::conf::Config::try_from(ads1298::conf::Config1Reg(val))
+                .map_err(Ads129xError::ReadInterpret)?,
+            None => self.config(delay)?,
+        };
+        Ok(config.mode.as_sps())
+    }
+
+    /// START pulse when the pin is connected, `START` opcode otherwise
+    fn trigger_conversion(&mut self, mut delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        if self.pins.is_connected(pins::ControlPin::Start) {
+            self.set_pin(pins::ControlPin::Start, true)?;
+            delay.delay_us(timing::START_PULSE_US);
+            self.set_pin(pins::ControlPin::Start, false)?;
+        } else {
+            self.spi.write(&[command::Command::START as u8], delay)?;
+        }
+        Ok(())
+    }
+
+    /// Read register as raw byte
+    ///
+    /// Escape hatch for settings the typed API doesn't cover.
+    pub fn read_register(
+        &mut self,
+        reg: ads1298::Register,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<u8, E> {
+        self.read_register_raw(reg as u8, delay)
+    }
+
+    /// Read consecutive registers starting at `start` in one transaction
+    ///
+    /// Fails with [`Ads129xError::InvalidArgument`] when `buf` is empty or
+    /// runs past the last register.
+    pub fn read_registers(
+        &mut self,
+        start: ads1298::Register,
+        buf: &mut [u8],
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.read_registers_raw(start as u8, ads1298::REGISTERS.len(), buf, delay)
+    }
+
+    /// Read the whole register file, in register order
+    ///
+    /// Sends `SDATAC` first so the dump works in continuous mode too, the
+    /// device is left in command mode.
+    pub fn dump_registers(&mut self, mut delay: impl DelayUs<u32>) -> Ads129xResult<[u8; 26], E> {
+        self.command(command::Command::SDATAC, spi::DelayRef(&mut delay))?;
+        let mut regs = [0x00; 26];
+        self.read_registers(ads1298::Register::ID, &mut regs, delay)?;
+        Ok(regs)
+    }
+
+    /// Write consecutive registers starting at `start` in one transaction
+    ///
+    /// Fails with [`Ads129xError::InvalidArgument`] when `values` is empty,
+    /// runs past the last register or covers a read-only register.
+    pub fn write_registers(
+        &mut self,
+        start: ads1298::Register,
+        values: &[u8],
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.write_registers_raw(start as u8, &ads1298::REGISTERS, values, delay)
+    }
+
+    /// Read every channel setting with a single burst
+    pub fn channels(
+        &mut self,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<[ads1298::chan::Chan; CH], E> {
+        let mut regs = [0x00; CH];
+        self.read_registers(ads1298::Register::CH1SET, &mut regs, delay)?;
+        self.observe(ads1298::Register::CH1SET as u8, &regs);
+
+        let mut chans = [ads1298::chan::Chan::default(); CH];
+        for (chan, reg) in chans.iter_mut().zip(regs) {
+            *chan = ads1298::chan::Chan::try_from(ads1298::chan::ChanSetReg(reg))
+                .map_err(Ads129xError::ReadInterpret)?;
+        }
+        Ok(chans)
+    }
+
+    /// Write the same setting to every channel with a single burst `WREG`
+    pub fn set_all_channels(
+        &mut self,
+        chan: ads1298::chan::Chan,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        let regs = [ads1298::chan::ChanSetReg::from(chan).0; CH];
+        self.write_registers(ads1298::Register::CH1SET, &regs, delay)
+    }
+
+    /// Write register as raw byte
+    ///
+    /// The value is replayed by `power_up_and_restore` like typed writes.
+    /// Read-only registers are rejected with
+    /// [`Ads129xError::InvalidArgument`].
+    pub fn write_register(
+        &mut self,
+        reg: ads1298::Register,
+        value: u8,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.write_register_raw(reg as u8, reg.info(), value, delay)
+    }
+
+    /// Enable the oscillator clock output on the CLK pin
+    ///
+    /// Read-modify-write of `CONFIG1` CLK_EN, then waits [`timing::CLK_OUTPUT_SETTLE_US`] so
+    /// that a device clocked from this one runs before its registers are touched. Only the
+    /// clock source of a multi-device design may enable the output, see
+    /// [`conf::single_clock_source`](ads1298::conf::single_clock_source).
+    pub fn enable_clock_output(&mut self, mut delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        let mut config = self.config(spi::DelayRef(&mut delay))?;
+        config.osc_clock_output = true;
+        self.set_config(config, spi::DelayRef(&mut delay))?;
+        delay.delay_us(timing::CLK_OUTPUT_SETTLE_US);
+        Ok(())
+    }
+
+    /// Disable the oscillator clock output
+    ///
+    /// Read-modify-write of `CONFIG1` CLK_EN.
+    pub fn disable_clock_output(&mut self, mut delay: impl DelayUs<u32>) -> Ads129xResult<(), E> {
+        let mut config = self.config(spi::DelayRef(&mut delay))?;
+        config.osc_clock_output = false;
+        self.set_config(config, delay)
+    }
+
+    /// Select multiple readback mode, read-modify-write of `CONFIG1` DAISY_EN
+    ///
+    /// In multiple readback mode the devices share DOUT, which a device only
+    /// drives while its nCS is low. nCS of every device sharing the line
+    /// must therefore be asserted exclusively: a single nCS low at a time,
+    /// held for the whole frame and released before the next device is
+    /// selected. Two asserted devices drive DOUT against each other.
+    pub fn configure_multiple_readback(
+        &mut self,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.modify_config(|config| config.daisy_chain = false, delay)
+    }
+
+    /// Readout topology currently selected on the device
+    pub fn readback_mode(
+        &mut self,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<ads1298::conf::ReadbackMode, E> {
+        Ok(self.config(delay)?.readback_mode())
+    }
+
+    /// Select multiple readback mode on every device sharing DOUT
+    ///
+    /// Devices are configured first, then each one is read back: a device left
+    /// in daisy-chain mode fails with [`Ads129xError::WriteVerifyFailed`]
+    /// on `CONFIG1`.
+    pub fn configure_multiple_readback_all(
+        devices: &mut [Self],
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        for device in devices.iter_mut() {
+            device.configure_multiple_readback(spi::DelayRef(&mut delay))?;
+        }
+
+        for device in devices.iter_mut() {
+            device.check_readback_mode(
+                ads1298::conf::ReadbackMode::MultipleReadback,
+                spi::DelayRef(&mut delay),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Select daisy-chain mode, read-modify-write of `CONFIG1`
+    ///
+    /// Sets DAISY_EN to daisy chain and CLK_EN according to `role`. The
+    /// clock master waits [`timing::CLK_OUTPUT_SETTLE_US`] like
+    /// [`enable_clock_output`](Self::enable_clock_output). Fails with
+    /// [`Ads129xError::NotSupported`] for a clock master declared with an
+    /// external clock or a downstream device declared with the internal
+    /// oscillator, see [`set_clock_source`](Self::set_clock_source). Frames
+    /// of the chain are read with
+    /// [`read_daisy_frames`](Self::read_daisy_frames).
+    pub fn configure_daisy_chain(
+        &mut self,
+        role: ads1298::conf::DaisyRole,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        use ads1298::conf::DaisyRole;
+
+        let master = role == DaisyRole::ClockMaster;
+        let internal = self.clock == common::ClockSource::InternalOscillator;
+        if master && !internal {
+            return Err(Ads129xError::NotSupported {
+                model: None,
+                what:  "clock output with an external clock",
+            });
+        }
+        if !master && internal {
+            return Err(Ads129xError::NotSupported {
+                model: None,
+                what:  "downstream device without external clock",
+            });
+        }
+        self.modify_config(
+            |config| {
+                config.daisy_chain = true;
+                config.osc_clock_output = master;
+            },
+            spi::DelayRef(&mut delay),
+        )?;
+        if master {
+            delay.delay_us(timing::CLK_OUTPUT_SETTLE_US);
+        }
+        Ok(())
+    }
+
+    /// Select daisy-chain mode on every device of the chain
+    ///
+    /// `devices[0]` becomes the
+    /// [`ClockMaster`](ads1298::conf::DaisyRole::ClockMaster), the others
+    /// [`Downstream`](ads1298::conf::DaisyRole::Downstream), they must be
+    /// declared with an external clock. Devices are configured first, then
+    /// each one is read back: a device left in multiple readback mode fails
+    /// with [`Ads129xError::WriteVerifyFailed`] on `CONFIG1`.
+    pub fn configure_daisy_chain_all(
+        devices: &mut [Self],
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        use ads1298::conf::DaisyRole;
+
+        for (i, device) in devices.iter_mut().enumerate() {
+            let role = match i {
+                0 => DaisyRole::ClockMaster,
+                _ => DaisyRole::Downstream,
+            };
+            device.configure_daisy_chain(role, spi::DelayRef(&mut delay))?;
+        }
+
+        for device in devices.iter_mut() {
+            device.check_readback_mode(
+                ads1298::conf::ReadbackMode::DaisyChain,
+                spi::DelayRef(&mut delay),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Read `CONFIG1` back, DAISY_EN must select `mode`
+    ///
+    /// A mismatch counts as a failed write verification of DAISY_EN.
+    fn check_readback_mode(
+        &mut self,
+        mode: ads1298::conf::ReadbackMode,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        let reg = ads1298::Register::CONFIG1 as u8;
+        let read = self.read_register_raw(reg, delay)?;
+        let mut wrote = ads1298::conf::Config1Reg(read);
+        wrote.set_daisy_disable(mode == ads1298::conf::ReadbackMode::MultipleReadback);
+        if wrote.0 != read {
+            self.spi.stats.write_verify_failure();
+            return Err(Ads129xError::WriteVerifyFailed {
+                register: reg,
+                wrote: wrote.0,
+                read,
+            });
+        }
+        Ok(())
+    }
+
+    /// Write the whole configuration
+    ///
+    /// `CONFIG1` through the last channel's `CHnSET` and both `LOFF_SENS`
+    /// registers are written with a burst each, `GPIO` and `CONFIG4`
+    /// separately.
+    pub fn apply_config(
+        &mut self,
+        config: &ads1298::DeviceConfig<CH>,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        use ads1298::{chan, conf, loff};
+
+        let mut regs = [0x00; 4 + 8];
+        regs[0] = conf::Config1Reg::from(config.config).0;
+        regs[1] = conf::Config2Reg::from(config.test_signal).0;
+        regs[2] = conf::Config3Reg::from(config.rld).0;
+        regs[3] = loff::LeadOffControlReg::from(config.leadoff_control).0;
+        for (reg, chan) in regs[4..].iter_mut().zip(config.channels.iter()) {
+            *reg = chan::ChanSetReg::from(*chan).0;
+        }
+        self.write_registers(
+            ads1298::Register::CONFIG1,
+            &regs[..4 + CH],
+            spi::DelayRef(&mut delay),
+        )?;
+
+        let sense = [
+            loff::LeadOffSenseReg::from(config.leadoff_sense_positive).0,
+            loff::LeadOffSenseReg::from(config.leadoff_sense_negative).0,
+        ];
+        self.write_registers(
+            ads1298::Register::LOFF_SENSP,
+            &sense,
+            spi::DelayRef(&mut delay),
+        )?;
+
+        self.set_gpio(config.gpio, spi::DelayRef(&mut delay))?;
+        self.set_misc_config(config.misc, delay)
+    }
+
+    /// Read the whole configuration back, see
+    /// [`apply_config`](Self::apply_config)
+    pub fn read_config(
+        &mut self,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<ads1298::DeviceConfig<CH>, E> {
+        use ads1298::{chan, conf, loff};
+
+        let mut regs = [0x00; 4 + 8];
+        self.read_registers(
+            ads1298::Register::CONFIG1,
+            &mut regs[..4 + CH],
+            spi::DelayRef(&mut delay),
+        )?;
+        let mut sense = [0x00; 2];
+        self.read_registers(
+            ads1298::Register::LOFF_SENSP,
+            &mut sense,
+            spi::DelayRef(&mut delay),
+        )?;
+
+        let mut config = ads1298::DeviceConfig::<CH> {
+            config: conf::Config::try_from(conf::Config1Reg(regs[0]))
+                .map_err(Ads129xError::ReadInterpret)?,
+            test_signal: conf::TestSignalConfig::try_from(conf::Config2Reg(regs[1]))
+                .map_err(Ads129xError::ReadInterpret)?,
+            rld: conf::RldConfig::try_from(conf::Config3Reg(regs[2]))
+                .map_err(Ads129xError::ReadInterpret)?,
+            leadoff_control: loff::LeadOffControl::try_from(loff::LeadOffControlReg(regs[3]))
+                .map_err(Ads129xError::ReadInterpret)?,
+            leadoff_sense_positive: loff::LeadOffSense::try_from(loff::LeadOffSenseReg(sense[0]))
+                .map_err(Ads129xError::ReadInterpret)?,
+            leadoff_sense_negative: loff::LeadOffSense::try_from(loff::LeadOffSenseReg(sense[1]))
+                .map_err(Ads129xError::ReadInterpret)?,
+            gpio: self.gpio(spi::DelayRef(&mut delay))?,
+            misc: self.misc_config(delay)?,
+            ..Default::default()
+        };
+        for (chan, reg) in config.channels.iter_mut().zip(&regs[4..]) {
+            *chan = chan::Chan::try_from(chan::ChanSetReg(*reg))
+                .map_err(Ads129xError::ReadInterpret)?;
+        }
+        Ok(config)
+    }
+
+    /// Write settings of contiguous channels with a single burst `WREG`
+    ///
+    /// `start` is zero based: `chans[0]` goes to channel `start + 1`.
+    pub fn write_chans_range(
+        &mut self,
+        start: usize,
+        chans: &[ads1298::chan::Chan],
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        if chans.is_empty() || start + chans.len() > CH {
+            return Err(Ads129xError::InvalidArgument("channel range out of bounds"));
+        }
+
+        let mut regs = [0x00u8; 8];
+        for (reg, chan) in regs.iter_mut().zip(chans) {
+            *reg = ads1298::chan::ChanSetReg::from(*chan).0;
+        }
+        self.write_registers_raw(
+            ads1298::Register::CH1SET as u8 + start as u8,
+            &ads1298::REGISTERS,
+            &regs[..chans.len()],
+            delay,
+        )
+    }
+
+    /// Read settings of contiguous channels with a single burst `RREG`
+    ///
+    /// `start` is zero based: `chans[0]` is filled from channel `start + 1`.
+    pub fn read_chans_range(
+        &mut self,
+        start: usize,
+        chans: &mut [ads1298::chan::Chan],
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        if chans.is_empty() || start + chans.len() > CH {
+            return Err(Ads129xError::InvalidArgument("channel range out of bounds"));
+        }
+
+        let first = ads1298::Register::CH1SET as u8 + start as u8;
+        let mut regs = [0x00u8; 8];
+        let regs = &mut regs[..chans.len()];
+        self.read_registers_raw(first, ads1298::REGISTERS.len(), regs, delay)?;
+        self.observe(first, regs);
+
+        for (chan, word) in chans.iter_mut().zip(regs.iter()) {
+            *chan = ads1298::chan::Chan::try_from(ads1298::chan::ChanSetReg(*word))
+                .map_err(Ads129xError::ReadInterpret)?;
+        }
+        Ok(())
+    }
+
+    /// Measure offset and noise of every powered up channel with shorted
+    /// inputs
+    ///
+    /// Routes those channels to `ChannelInput::Shorted` keeping their gain,
+    /// starts conversions and reads `frames` frames with `RDATA`, each once
+    /// `drdy` is asserted. Fails with [`Ads129xError::Timeout`] when `DRDY`
+    /// stays high for [`timing::settling_time_us`]. Conversions are stopped
+    /// and the channel settings restored afterwards, also on failure. Expects
+    /// command mode without single-shot, µV values use the `VREF` selected in
+    /// CONFIG3.
+    pub fn measure_noise<P: InputPin>(
+        &mut self,
+        drdy: &P,
+        frames: usize,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<data::NoiseReport<CH>, E> {
+        use ads1298::chan::{Chan, ChannelInput};
+
+        if frames == 0 {
+            return Err(Ads129xError::InvalidArgument("zero frames"));
+        }
+        let saved = self.channels(spi::DelayRef(&mut delay))?;
+        let data_rate_sps = self.config(spi::DelayRef(&mut delay))?.mode.as_sps();
+        let vref_uv = if self
+            .test_rld_config(spi::DelayRef(&mut delay))?
+            .vref_4V_enable
+        {
+            4_000_000
+        } else {
+            2_400_000
+        };
+
+        let mut shorted = saved;
+        let mut gains = [None; CH];
+        for (chan, shorted_gain) in shorted.iter_mut().zip(gains.iter_mut()) {
+            if let Chan::PowerUp { gain, .. } = *chan {
+                *chan = Chan::PowerUp {
+                    input: ChannelInput::Shorted,
+                    gain,
+                };
+                *shorted_gain = Some(gain);
+            }
+        }
+        self.write_chans_range(0, &shorted, spi::DelayRef(&mut delay))?;
+
+        let mut stats = data::ChannelStats::new();
+        let timeout_us = timing::settling_time_us(data_rate_sps);
+        let res = self.accumulate_frames(
+            drdy,
+            &mut stats,
+            frames,
+            timeout_us,
+            spi::DelayRef(&mut delay),
+        );
+        let restored = self.write_chans_range(0, &saved, delay);
+        res?;
+        restored?;
+        Ok(data::NoiseReport::from_stats(&stats, vref_uv, &gains))
+    }
+
+    /// Start conversions and accumulate `frames` frames read with `RDATA`
+    ///
+    /// Waits up to `timeout_us` for `drdy` before each read.
+    fn accumulate_frames<P: InputPin>(
+        &mut self,
+        drdy: &P,
+        stats: &mut data::ChannelStats<CH>,
+        frames: usize,
+        timeout_us: u32,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.start_conv(spi::DelayRef(&mut delay))?;
+
+        let mut data_frame = data::DataFrame::new();
+        let mut res = Ok(());
+        for _ in 0..frames {
+            res = match pins::wait_drdy_timeout(drdy, timeout_us, &mut delay) {
+                Ok(true) => self.read_data_by_command(&mut data_frame, spi::DelayRef(&mut delay)),
+                Ok(false) => Err(Ads129xError::Timeout),
+                Err(_) => Err(Ads129xError::DrdyPin),
+            };
+            if res.is_err() {
+                break;
+            }
+            stats.update(&data_frame);
+        }
+
+        let stopped = self.stop_conv(delay);
+        res?;
+        stopped
+    }
+
+    /// Configure lead-off detection
+    ///
+    /// Writes `LOFF`, `LOFF_SENSP` through `LOFF_FLIP` with a single burst,
+    /// then read-modify-writes the comparator power in `CONFIG4` and the RLD
+    /// sensing in `CONFIG3`. Fails with [`Ads129xError::InvalidArgument`]
+    /// before writing anything when an electrode is sensed with the
+    /// comparators powered down or without AC/DC detection, or on a channel
+    /// the device lacks.
+    pub fn enable_leadoff(
+        &mut self,
+        setup: ads1298::loff::LeadOffSetup,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        use ads1298::loff;
+
+        setup.check(CH).map_err(Ads129xError::InvalidArgument)?;
+        self.set_leadoff_control(setup.control, spi::DelayRef(&mut delay))?;
+        self.write_registers(
+            ads1298::Register::LOFF_SENSP,
+            &[
+                loff::LeadOffSenseReg::from(setup.sense_positive).0,
+                loff::LeadOffSenseReg::from(setup.sense_negative).0,
+                loff::LeadOffFlipReg::from(setup.flip).0,
+            ],
+            spi::DelayRef(&mut delay),
+        )?;
+        self.modify_misc_config(
+            |misc| misc.leadoff_comparator_enable = setup.comparators_enable,
+            spi::DelayRef(&mut delay),
+        )?;
+        self.modify_rld_config(|rld| rld.leadoff_sense_enable = setup.rld_sense, delay)
+    }
+
+    /// Electrode connection from `LOFF_STATP/N` and `CONFIG3` RLD_STAT
+    ///
+    /// Reads `LOFF_SENSP` through `LOFF_STATN` with a single burst, electrodes
+    /// without sensing are `Unmonitored`. Frames carry the same flags but
+    /// RLD, see `LeadOffState::from_status`.
+    pub fn leadoff_state(
+        &mut self,
+        mut delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<ads1298::loff::LeadOffState, E> {
+        let mut regs = [0x00; 5];
+        self.read_registers(
+            ads1298::Register::LOFF_SENSP,
+            &mut regs,
+            spi::DelayRef(&mut delay),
+        )?;
+        let rld = self.test_rld_config(delay)?;
+
+        let bits = |reg: u8| core::array::from_fn(|ch| reg & 1 << ch != 0);
+        Ok(ads1298::loff::LeadOffState::from_regs(
+            regs[0],
+            regs[1],
+            bits(regs[3]),
+            bits(regs[4]),
+            (rld.leadoff_sense_enable, rld.leadoff_status),
+        ))
+    }
+
+    /// Configure the Wilson central terminal with a single burst `WREG`
+    ///
+    /// Writes WCT1 and WCT2 together. Fails with
+    /// [`Ads129xError::InvalidArgument`] when an input feeds two powered
+    /// amplifiers.
+    pub fn apply_wct_config(
+        &mut self,
+        config: ads1298::wct::WctConfig,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        config.validate().map_err(Ads129xError::InvalidArgument)?;
+
+        let (wct1, wct2) = config.to_regs();
+        let regs = [
+            ads1298::wct::Wct1Reg::from(wct1).0,
+            ads1298::wct::Wct2Reg::from(wct2).0,
+        ];
+        self.write_registers(ads1298::Register::WCT1, &regs, delay)
+    }
+
+    /// Read the Wilson central terminal setup with a single burst `RREG`
+    pub fn read_wct_config(
+        &mut self,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<ads1298::wct::WctConfig, E> {
+        let mut regs = [0x00; 2];
+        self.read_registers(ads1298::Register::WCT1, &mut regs, delay)?;
+
+        let wct1 = ads1298::wct::Wct1::try_from(ads1298::wct::Wct1Reg(regs[0]))
+            .map_err(Ads129xError::ReadInterpret)?;
+        let wct2 = ads1298::wct::Wct2::try_from(ads1298::wct::Wct2Reg(regs[1]))
+            .map_err(Ads129xError::ReadInterpret)?;
+        Ok(ads1298::wct::WctConfig::from_regs(wct1, wct2))
+    }
+
+    ads1298_registers!();
+}
+
+// Channels past the fourth only exist on the ADS1296 and ADS1298
+impl<SPI, NCS, E, PINS, MODE, V> Ads129x<SPI, NCS, Ads1298Family<V>, 6, PINS, MODE>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+{
+    ads1298_chan_5_6_registers!();
+}
+
+impl<SPI, NCS, E, PINS, MODE, V> Ads129x<SPI, NCS, Ads1298Family<V>, 8, PINS, MODE>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+{
+    ads1298_chan_5_6_registers!();
+    ads1298_chan_7_8_registers!();
+}
+
+impl<SPI, NCS, E, PINS, MODE, const CH: usize>
+    Ads129x<SPI, NCS, Ads1298Family<variant::R>, CH, PINS, MODE>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+    MODE: mode::RegisterAccess,
+{
+    ads1298r_registers!();
+}
+
+/// Human readable message, SPI errors are rendered without their details
+impl<E> core::fmt::Display for Ads129xError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::IdRegRead(e) => write!(f, "identification register read failed: {}", e),
+            Self::ReadInterpret(val) => write!(f, "invalid register value {:#04x}", val),
+            Self::StatusWordMissmatch { raw } => write!(
+                f,
+                "status word sync mismatch (got {:#X}, expected 0xC)",
+                raw[0] >> 4
+            ),
+            Self::InvalidArgument(what) => write!(f, "invalid argument: {}", what),
+            Self::NotSupported {
+                model: Some(model),
+                what,
+            } => write!(f, "not supported on {:?}: {}", model, what),
+            Self::NotSupported { model: None, what } => write!(f, "not supported: {}", what),
+            Self::Pin(pin) => write!(f, "{:?} pin failed", pin),
+            Self::NcsPin { high: true } => write!(f, "nCS pin failed to go high"),
+            Self::NcsPin { high: false } => write!(f, "nCS pin failed to go low"),
+            Self::DrdyPin => write!(f, "DRDY pin read failed"),
+            Self::Timeout => write!(f, "timed out"),
+            Self::WriteVerifyFailed {
+                register,
+                wrote,
+                read,
+            } => write!(
+                f,
+                "register {:#04x} verify failed: wrote {:#04x}, read {:#04x}",
+                register, wrote, read
+            ),
+            Self::ClockOutOfRange { fclk_hz, fmod_hz } => write!(
+                f,
+                "modulator clock {} Hz out of range at fCLK {} Hz",
+                fmod_hz, fclk_hz
+            ),
+            Self::DeviceMismatch {
+                expected_channels,
+                found,
+            } => write!(
+                f,
+                "found {:?}, expected a {} channel device",
+                found, expected_channels
+            ),
+            Self::VariantMismatch { found } => {
+                write!(f, "found {:?}, expected an R variant", found)
+            }
+            Self::BusBusy => write!(f, "bus held by the other half of a split driver"),
+            Self::Spi(_) => write!(f, "SPI error"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for Ads129xError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::IdRegRead(e) => Some(e),
+            Self::Spi(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+// Hand written so that `E: Debug` is enough
+#[cfg(feature = "defmt")]
+impl<E: core::fmt::Debug> defmt::Format for Ads129xError<E> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Self::IdRegRead(e) => defmt::write!(f, "IdRegRead({})", e),
+            Self::ReadInterpret(val) => defmt::write!(f, "ReadInterpret({=u8:#x})", val),
+            Self::StatusWordMissmatch { raw } => {
+                defmt::write!(f, "StatusWordMissmatch {{ raw: {=[u8]:#x} }}", raw[..])
+            }
+            Self::InvalidArgument(what) => defmt::write!(f, "InvalidArgument({=str})", what),
+            Self::NotSupported { model, what } => {
+                defmt::write!(f, "NotSupported {{ model: {}, what: {=str} }}", model, what)
+            }
+            Self::Pin(pin) => defmt::write!(f, "Pin({})", pin),
+            Self::NcsPin { high } => defmt::write!(f, "NcsPin {{ high: {} }}", high),
+            Self::DrdyPin => defmt::write!(f, "DrdyPin"),
+            Self::Timeout => defmt::write!(f, "Timeout"),
+            Self::WriteVerifyFailed {
+                register,
+                wrote,
+                read,
+            } => defmt::write!(
+                f,
+                "WriteVerifyFailed {{ register: {=u8:#x}, wrote: {=u8:#x}, read: {=u8:#x} }}",
+                register,
+                wrote,
+                read
+            ),
+            Self::ClockOutOfRange { fclk_hz, fmod_hz } => defmt::write!(
+                f,
+                "ClockOutOfRange {{ fclk_hz: {=u32}, fmod_hz: {=u32} }}",
+                fclk_hz,
+                fmod_hz
+            ),
+            Self::DeviceMismatch {
+                expected_channels,
+                found,
+            } => defmt::write!(
+                f,
+                "DeviceMismatch {{ expected_channels: {=usize}, found: {} }}",
+                expected_channels,
+                found
+            ),
+            Self::VariantMismatch { found } => {
+                defmt::write!(f, "VariantMismatch {{ found: {} }}", found)
+            }
+            Self::BusBusy => defmt::write!(f, "BusBusy"),
+            Self::Spi(e) => defmt::write!(f, "Spi({})", defmt::Debug2Format(e)),
+        }
+    }
 }
 
 impl<E> From<E> for Ads129xError<E> {
@@ -0,0 +1,113 @@
+//! Device operating mode typestates
+//!
+//! The device ignores `RREG`/`WREG` in continuous conversion mode (`RDATAC`),
+//! which it enters on power-up and after every reset. The driver tracks the
+//! mode it put the device in: register access only exists in [`Command`]
+//! mode, continuous frame reads only in [`Continuous`] mode.
+//!
+//! ```ignore
+//! let ads = Ads129x::new_ads1298(spi, ncs); // Continuous
+//! let mut ads = ads.set_command_mode(&mut delay)?;
+//! ads.apply_config(&config, &mut delay)?;
+//! let mut ads = ads.set_continuous_mode(&mut delay)?;
+//! ads.read_data(&mut frame, &mut delay)?;
+//! ```
+//!
+//! [`Dynamic`], see `into_dynamic`, offers every method and follows the mode
+//! at run time instead: register access in continuous mode is preceded by
+//! `SDATAC` as the [`AccessPolicy`] says.
+
+/// Command mode, after `SDATAC`
+#[derive(Debug)]
+pub struct Command;
+
+/// Continuous conversion mode, after `RDATAC`
+#[derive(Debug)]
+pub struct Continuous;
+
+/// Mode followed at run time
+#[derive(Debug)]
+pub struct Dynamic;
+
+/// Register access while [`Dynamic`] believes the device is in continuous mode
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AccessPolicy {
+    /// Send `SDATAC` first, staying in command mode
+    #[default]
+    Stop,
+    /// Send `SDATAC` first and `RDATAC` once the access is done
+    StopAndResume,
+    /// Send nothing, the mode is managed by the caller
+    Manual,
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Command {}
+    impl Sealed for super::Continuous {}
+    impl Sealed for super::Dynamic {}
+}
+
+/// Driver mode typestate
+pub trait Mode: sealed::Sealed {
+    /// Tracked mode, `Some(true)` for continuous, `None` when untracked
+    #[doc(hidden)]
+    const CONTINUOUS: Option<bool>;
+}
+
+/// Mode with register access
+pub trait RegisterAccess: Mode {}
+
+/// Mode with continuous frame reads
+pub trait DataAccess: Mode {}
+
+impl Mode for Command {
+    const CONTINUOUS: Option<bool> = Some(false);
+}
+
+impl Mode for Continuous {
+    const CONTINUOUS: Option<bool> = Some(true);
+}
+
+impl Mode for Dynamic {
+    const CONTINUOUS: Option<bool> = None;
+}
+
+/// Whether register access in `MODE` has to send `SDATAC` first
+pub(crate) fn stops_for_access<MODE: Mode>(continuous: bool, policy: AccessPolicy) -> bool {
+    MODE::CONTINUOUS.is_none() && continuous && policy != AccessPolicy::Manual
+}
+
+/// Whether `RDATAC` follows an access that sent `SDATAC`
+pub(crate) fn resumes_after_access(stopped: bool, policy: AccessPolicy) -> bool {
+    stopped && policy == AccessPolicy::StopAndResume
+}
+
+/// Opcode putting the device back into the mode `MODE` tracks, `continuous`
+/// is its current one
+pub(crate) fn resume_opcode<MODE: Mode>(continuous: bool) -> Option<crate::command::Command> {
+    match MODE::CONTINUOUS {
+        Some(true) if !continuous => Some(crate::command::Command::RDATAC),
+        Some(false) if continuous => Some(crate::command::Command::SDATAC),
+        _ => None,
+    }
+}
+
+/// Device mode after `opcode`, `None` when it doesn't change it
+pub(crate) fn followed(opcode: u8) -> Option<bool> {
+    if opcode == crate::command::Command::RDATAC as u8 {
+        Some(true)
+    } else if opcode == crate::command::Command::SDATAC as u8 {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+impl RegisterAccess for Command {}
+impl RegisterAccess for Dynamic {}
+
+impl DataAccess for Continuous {}
+impl DataAccess for Dynamic {}
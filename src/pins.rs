@@ -0,0 +1,184 @@
+//! Optional hardware control pins
+//!
+//! Designs may wire `START`, `RESET` and `PWDN` to MCU GPIOs instead of tying them off. Each
+//! pin is optional: when absent the driver falls back to the corresponding SPI opcode where one
+//! exists.
+
+use ehal::blocking::delay::DelayUs;
+use ehal::digital::v2::{InputPin, OutputPin};
+use embedded_hal as ehal;
+
+/// Placeholder for an unconnected control pin
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoPin;
+
+impl OutputPin for NoPin {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Control pin selector
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ControlPin {
+    /// `START`, active high
+    Start,
+    /// `RESET`, active low
+    Reset,
+    /// `PWDN`, active low
+    Pwdn,
+}
+
+/// Control pin failed to change its state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PinError;
+
+impl core::fmt::Display for PinError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "pin failed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PinError {}
+
+/// Set of control pins the driver may drive
+pub trait ControlPinSet {
+    /// Pin is connected
+    fn is_connected(&self, pin: ControlPin) -> bool;
+
+    /// Drive pin level, no-op for an unconnected pin
+    fn set(&mut self, pin: ControlPin, high: bool) -> Result<(), PinError>;
+}
+
+/// `START`/`RESET`/`PWDN` pin bundle
+///
+/// ```ignore
+/// let pins = ControlPins::new().with_start(start).with_pwdn(pwdn);
+/// let ads = Ads129x::new_ads1298(spi, ncs).with_control_pins(pins);
+/// ```
+#[derive(Debug)]
+pub struct ControlPins<STARTP = NoPin, RESETP = NoPin, PWDNP = NoPin> {
+    start: Option<STARTP>,
+    reset: Option<RESETP>,
+    pwdn:  Option<PWDNP>,
+}
+
+impl ControlPins {
+    /// Create bundle without any connected pin
+    pub fn new() -> Self {
+        ControlPins {
+            start: None,
+            reset: None,
+            pwdn:  None,
+        }
+    }
+}
+
+impl Default for ControlPins {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<STARTP, RESETP, PWDNP> ControlPins<STARTP, RESETP, PWDNP> {
+    /// Connect `START` pin
+    pub fn with_start<P: OutputPin>(self, pin: P) -> ControlPins<P, RESETP, PWDNP> {
+        ControlPins {
+            start: Some(pin),
+            reset: self.reset,
+            pwdn:  self.pwdn,
+        }
+    }
+
+    /// Connect `RESET` pin
+    pub fn with_reset<P: OutputPin>(self, pin: P) -> ControlPins<STARTP, P, PWDNP> {
+        ControlPins {
+            start: self.start,
+            reset: Some(pin),
+            pwdn:  self.pwdn,
+        }
+    }
+
+    /// Connect `PWDN` pin
+    pub fn with_pwdn<P: OutputPin>(self, pin: P) -> ControlPins<STARTP, RESETP, P> {
+        ControlPins {
+            start: self.start,
+            reset: self.reset,
+            pwdn:  Some(pin),
+        }
+    }
+
+    /// Release the pins
+    pub fn release(self) -> (Option<STARTP>, Option<RESETP>, Option<PWDNP>) {
+        (self.start, self.reset, self.pwdn)
+    }
+}
+
+impl<STARTP, RESETP, PWDNP> ControlPinSet for ControlPins<STARTP, RESETP, PWDNP>
+where
+    STARTP: OutputPin,
+    RESETP: OutputPin,
+    PWDNP: OutputPin,
+{
+    fn is_connected(&self, pin: ControlPin) -> bool {
+        match pin {
+            ControlPin::Start => self.start.is_some(),
+            ControlPin::Reset => self.reset.is_some(),
+            ControlPin::Pwdn => self.pwdn.is_some(),
+        }
+    }
+
+    fn set(&mut self, pin: ControlPin, high: bool) -> Result<(), PinError> {
+        match pin {
+            ControlPin::Start => drive(&mut self.start, high),
+            ControlPin::Reset => drive(&mut self.reset, high),
+            ControlPin::Pwdn => drive(&mut self.pwdn, high),
+        }
+    }
+}
+
+fn drive<P: OutputPin>(pin: &mut Option<P>, high: bool) -> Result<(), PinError> {
+    match pin {
+        Some(pin) if high => pin.set_high().map_err(|_| PinError),
+        Some(pin) => pin.set_low().map_err(|_| PinError),
+        None => Ok(()),
+    }
+}
+
+/// Spin until the active-low `DRDY` is asserted
+///
+/// Returns right away when `DRDY` is already low.
+pub fn wait_drdy<P: InputPin>(drdy: &P) -> Result<(), PinError> {
+    while drdy.is_high().map_err(|_| PinError)? {}
+    Ok(())
+}
+
+/// Wait up to `timeout_us` for the active-low `DRDY`
+///
+/// Polls every [`timing::DRDY_POLL_US`](crate::timing::DRDY_POLL_US), returns
+/// whether `DRDY` got asserted.
+pub fn wait_drdy_timeout<P: InputPin>(
+    drdy: &P,
+    timeout_us: u32,
+    delay: &mut impl DelayUs<u32>,
+) -> Result<bool, PinError> {
+    let mut waited = 0;
+    loop {
+        if drdy.is_low().map_err(|_| PinError)? {
+            return Ok(true);
+        }
+        if waited >= timeout_us {
+            return Ok(false);
+        }
+        delay.delay_us(crate::timing::DRDY_POLL_US);
+        waited += crate::timing::DRDY_POLL_US;
+    }
+}
@@ -0,0 +1,56 @@
+//! Serde for const generic arrays
+//!
+//! Serde only implements arrays up to 32 elements of a fixed length, so
+//! `[T; CH]` fields go through `#[serde(with = "crate::serde_array")]`. The
+//! array is encoded as a tuple, like serde's own fixed size arrays.
+
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+pub(crate) fn serialize<S, T, const N: usize>(
+    array: &[T; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut tuple = serializer.serialize_tuple(N)?;
+    for item in array {
+        tuple.serialize_element(item)?;
+    }
+    tuple.end()
+}
+
+pub(crate) fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Copy + Default,
+{
+    struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+    impl<'de, T, const N: usize> Visitor<'de> for ArrayVisitor<T, N>
+    where
+        T: Deserialize<'de> + Copy + Default,
+    {
+        type Value = [T; N];
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "an array of length {}", N)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut array = [T::default(); N];
+            for (idx, item) in array.iter_mut().enumerate() {
+                *item = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(idx, &self))?;
+            }
+            Ok(array)
+        }
+    }
+
+    deserializer.deserialize_tuple(N, ArrayVisitor(PhantomData))
+}
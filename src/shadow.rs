@@ -0,0 +1,46 @@
+//! Register shadow
+//!
+//! Remembers the last value written to each register so that the configuration
+//! can be replayed after the device lost it, e.g. in power-down, and the
+//! `modify_*` read-modify-write helpers can skip the read. The driver keeps a
+//! second table of the values written or read, behind the channel gains.
+
+/// Register file size of the largest device (ADS1298, `ID`..=`WCT2`)
+pub(crate) const REG_COUNT: usize = 0x1A;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Shadow {
+    regs:  [u8; REG_COUNT],
+    valid: u32,
+}
+
+impl Shadow {
+    pub(crate) const fn new() -> Self {
+        Shadow {
+            regs:  [0x00; REG_COUNT],
+            valid: 0,
+        }
+    }
+
+    /// Record written value
+    pub(crate) fn store(&mut self, reg: u8, val: u8) {
+        if let Some(slot) = self.regs.get_mut(reg as usize) {
+            *slot = val;
+            self.valid |= 1 << reg;
+        }
+    }
+
+    /// Forget every value, the device is back to its reset values
+    pub(crate) fn clear(&mut self) {
+        self.valid = 0;
+    }
+
+    /// Last written value
+    pub(crate) fn get(&self, reg: u8) -> Option<u8> {
+        if (reg as usize) < REG_COUNT && self.valid & (1 << reg) != 0 {
+            Some(self.regs[reg as usize])
+        } else {
+            None
+        }
+    }
+}
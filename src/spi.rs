@@ -1,27 +1,153 @@
+//! SPI transport
+//!
+//! Every access is a single transaction: nCS is asserted, the bytes are
+//! clocked and nCS is released, the driver holds nothing across transactions.
+//! DOUT is high impedance while nCS is high, so other devices may use the
+//! bus in between as long as their chip selects are released whenever the
+//! driver is called. Restoring SPI mode 1 (CPOL = 0, CPHA = 1) and the SCLK
+//! rate after another device changed them is up to the caller.
+//!
+//! Any type implementing the bus traits works, e.g. a bus manager proxy.
+//! [`BorrowedBus`] lends a `&mut SPI` to a driver, [`SharedBus`] lets
+//! several drivers use one bus from the same execution context:
+//!
+//! ```ignore
+//! let bus = RefCell::new(spi);
+//! let mut ecg = Ads129x::new_ads1298(SharedBus::new(&bus), ncs_ecg);
+//! let mut resp = Ads129x::new_ads1292(SharedBus::new(&bus), ncs_resp);
+//! ```
+
+use core::cell::RefCell;
+
 use ehal::blocking::delay::DelayUs;
 use ehal::blocking::spi::{Transfer, Write};
 use ehal::digital::v2::OutputPin;
 use ehal::spi::FullDuplex;
 use embedded_hal as ehal;
 
+use crate::data;
+use crate::stats::DriverStats;
+use crate::Ads129xError;
+
+/// nCS setup/hold waits around every transaction
+///
+/// The defaults are conservative for any SCLK the device accepts. The
+/// datasheet minima are in the nanosecond range (`tCSSC`, `tSCCS`, `tCSH`),
+/// all zeros suits most MCUs and is needed for the higher data rates.
+/// With the `critical-section` feature the setup/hold waits are part of the
+/// critical section, see [`SpiDevice::transaction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CsTiming {
+    /// nCS low to the first SCLK edge
+    pub pre_us:     u32,
+    /// Last SCLK edge to nCS high
+    pub post_us:    u32,
+    /// nCS high before the next transaction
+    pub release_us: u32,
+}
+
+impl CsTiming {
+    /// No waits, the bus and pin drivers' own latency only
+    pub const ZERO: CsTiming = CsTiming {
+        pre_us:     0,
+        post_us:    0,
+        release_us: 0,
+    };
+}
+
+impl Default for CsTiming {
+    fn default() -> Self {
+        CsTiming {
+            pre_us:     40,
+            post_us:    40,
+            release_us: 20,
+        }
+    }
+}
+
 /// A SPI device also triggering the nCS-pin when suited.
 pub struct SpiDevice<SPI, NCS> {
     /// Underlying peripheral
-    pub spi: SPI,
+    pub spi:       SPI,
     /// nCS
-    pub ncs: NCS,
+    pub ncs:       NCS,
+    /// Bus counters
+    pub stats:     DriverStats,
+    /// nCS waits
+    pub cs_timing: CsTiming,
+    /// nCS driven high since construction
+    deselected:    bool,
 }
 
 impl<SPI, NCS, E> SpiDevice<SPI, NCS>
 where
-    SPI: Write<u8, Error = E> + Transfer<u8, Error = E> + FullDuplex<u8, Error = E>,
-    NCS: OutputPin<Error = core::convert::Infallible>,
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
 {
     /// Create a new SPI device
+    ///
+    /// Deselects the device. When nCS fails to go high the first transaction
+    /// retries before selecting the device, reporting a second failure as
+    /// [`Ads129xError::NcsPin`].
     pub fn new(spi: SPI, mut ncs: NCS) -> Self {
-        let _ = ncs.set_high();
+        let deselected = ncs.set_high().is_ok();
 
-        SpiDevice { spi, ncs }
+        SpiDevice {
+            spi,
+            ncs,
+            stats: DriverStats::default(),
+            cs_timing: CsTiming::default(),
+            deselected,
+        }
+    }
+
+    /// Run `f` as a single transaction framed by nCS
+    ///
+    /// With the `critical-section` feature enabled the whole transaction, from
+    /// nCS low to nCS high, runs inside a critical section so that an
+    /// interrupt can never interleave its own bus access with an ongoing one.
+    /// The nCS setup/hold waits run inside it too, `delay` must not rely on
+    /// interrupts then, e.g. a busy-wait delay. The worst-case length is:
+    ///
+    /// `t_cs = pre_us + post_us + 8 * bytes / f_SCLK`
+    ///
+    /// where `bytes` is the transaction size, e.g. `3 + 3 * CH` for a data
+    /// frame read: 27 bytes of an ADS1298 frame at 4 MHz SCLK take 54 µs on
+    /// top of the 80 µs default waits, [`CsTiming::ZERO`] drops the latter.
+    /// The release wait stays outside. Zero waits aren't passed to `delay`.
+    ///
+    /// `f` doesn't run when nCS fails to go low, a failure to release nCS is
+    /// reported unless `f` failed first, both as [`Ads129xError::NcsPin`].
+    #[inline]
+    pub fn transaction<R>(
+        &mut self,
+        mut delay: impl DelayUs<u32>,
+        f: impl FnOnce(&mut SPI) -> Result<R, E>,
+    ) -> Result<R, Ads129xError<E>> {
+        if !self.deselected {
+            self.ncs
+                .set_high()
+                .map_err(|_| Ads129xError::NcsPin { high: true })?;
+            self.deselected = true;
+        }
+
+        let cs = self.cs_timing;
+        let (ncs, spi) = (&mut self.ncs, &mut self.spi);
+        let res = with_bus_lock(|| {
+            ncs.set_low()
+                .map_err(|_| Ads129xError::NcsPin { high: false })?;
+            wait(&mut delay, cs.pre_us);
+            let res = f(spi);
+            wait(&mut delay, cs.post_us);
+            let released = ncs.set_high();
+            let res = res?;
+            released.map_err(|_| Ads129xError::NcsPin { high: true })?;
+            Ok(res)
+        });
+        wait(&mut delay, cs.release_us);
+        // Drop out of function with SPIError only after setting NCS.
+        res
     }
 
     /// Transfer the buffer to the device, the passed buffer will contain the
@@ -30,44 +156,204 @@ where
     pub fn transfer<'buf>(
         &mut self,
         buffer: &'buf mut [u8],
-        mut delay: impl DelayUs<u32>,
-    ) -> Result<&'buf [u8], E> {
-        let _ = self.ncs.set_low();
-        delay.delay_us(40);
-
-        let res = self.spi.transfer(buffer);
-
-        delay.delay_us(40);
-        let _ = self.ncs.set_high();
-        delay.delay_us(20);
-        // Drop out of function with SPIError only after setting NCS.
-        Ok(res?)
+        delay: impl DelayUs<u32>,
+    ) -> Result<&'buf [u8], Ads129xError<E>> {
+        self.stats.transaction(buffer.len());
+        self.transaction(delay, move |spi| spi.transfer(buffer))
     }
 
     /// Write a number of bytes to the device.
     #[inline]
-    pub fn write(&mut self, buffer: &[u8], mut delay: impl DelayUs<u32>) -> Result<(), E> {
-        let _ = self.ncs.set_low();
-        delay.delay_us(40);
-
-        let res = self.spi.write(buffer);
-
-        delay.delay_us(40);
-        let _ = self.ncs.set_high();
-        delay.delay_us(20);
+    pub fn write(
+        &mut self,
+        buffer: &[u8],
+        delay: impl DelayUs<u32>,
+    ) -> Result<(), Ads129xError<E>> {
+        self.stats.transaction(buffer.len());
+        self.transaction(delay, |spi| spi.write(buffer))
+    }
 
-        res?; // Drop out of function with SPIError only after setting NCS.
-        Ok(())
+    /// Write `header`, then read `buffer.len()` bytes in the same transaction
+    ///
+    /// Zeros are clocked out while reading.
+    #[inline]
+    pub fn write_read(
+        &mut self,
+        header: &[u8],
+        buffer: &mut [u8],
+        delay: impl DelayUs<u32>,
+    ) -> Result<(), Ads129xError<E>> {
+        self.stats.transaction(header.len() + buffer.len());
+        buffer.fill(0x00);
+        self.transaction(delay, |spi| {
+            spi.write(header)?;
+            spi.transfer(buffer)?;
+            Ok(())
+        })
     }
 
-    /// Read single byte
+    /// Write `header`, then `buffer` in the same transaction
     #[inline]
-    pub fn recv_byte(&mut self) -> Result<u8, E> {
-        nb::block!(self.spi.send(0x00))?;
-        Ok(nb::block!(self.spi.read())?)
+    pub fn write_burst(
+        &mut self,
+        header: &[u8],
+        buffer: &[u8],
+        delay: impl DelayUs<u32>,
+    ) -> Result<(), Ads129xError<E>> {
+        self.stats.transaction(header.len() + buffer.len());
+        self.transaction(delay, |spi| {
+            spi.write(header)?;
+            spi.write(buffer)
+        })
     }
 
     pub fn destroy(self) -> (SPI, NCS) {
         (self.spi, self.ncs)
     }
 }
+
+#[inline]
+fn wait(delay: &mut impl DelayUs<u32>, us: u32) {
+    if us > 0 {
+        delay.delay_us(us);
+    }
+}
+
+#[cfg(feature = "critical-section")]
+#[inline]
+fn with_bus_lock<R>(f: impl FnOnce() -> R) -> R {
+    critical_section::with(|_| f())
+}
+
+#[cfg(not(feature = "critical-section"))]
+#[inline]
+fn with_bus_lock<R>(f: impl FnOnce() -> R) -> R {
+    f()
+}
+
+/// Longest frame transfer: opcode, status word and `MAX_CHANNELS` channels
+pub(crate) const FRAME_BUF_LEN: usize =
+    1 + data::STATUS_LEN + data::SAMPLE_LEN * data::MAX_CHANNELS;
+
+/// Clock out a frame of `CH` samples in a single transfer
+///
+/// `opcode` is shifted out first when given, its response byte is dropped.
+/// Returns the status word followed by the raw i24 big endian samples.
+/// `CH > MAX_CHANNELS` fails to compile.
+pub(crate) fn transfer_frame<'b, SPI, E, const CH: usize>(
+    spi: &mut SPI,
+    opcode: Option<u8>,
+    buf: &'b mut [u8; FRAME_BUF_LEN],
+) -> Result<&'b [u8], E>
+where
+    SPI: Transfer<u8, Error = E>,
+{
+    let () = data::DataFrame::<CH>::CHANNELS_FIT;
+
+    let skip = opcode.is_some() as usize;
+    let buf = &mut buf[..skip + data::STATUS_LEN + data::SAMPLE_LEN * CH];
+    buf.fill(0x00);
+    if let Some(opcode) = opcode {
+        buf[0] = opcode;
+    }
+    Ok(&spi.transfer(buf)?[skip..])
+}
+
+/// Clock out the status word and `CH` i24 samples in `format`
+pub(crate) fn clock_frame<SPI, E, const CH: usize>(
+    spi: &mut SPI,
+    opcode: Option<u8>,
+    format: data::SampleFormat,
+    status_word: &mut [u8; 3],
+    data: &mut [i32; CH],
+) -> Result<(), E>
+where
+    SPI: Transfer<u8, Error = E>,
+{
+    let mut buf = [0x00u8; FRAME_BUF_LEN];
+    let rx = transfer_frame::<_, _, CH>(spi, opcode, &mut buf)?;
+    status_word.copy_from_slice(&rx[..data::STATUS_LEN]);
+    for (chunk, sample) in rx[data::STATUS_LEN..]
+        .chunks_exact(data::SAMPLE_LEN)
+        .zip(data)
+    {
+        *sample = format.from_sign_extended(data::i24_from_be(chunk));
+    }
+    Ok(())
+}
+
+/// `&mut SPI` lent to a driver, `destroy` hands the borrow back
+pub struct BorrowedBus<'a, SPI>(pub &'a mut SPI);
+
+/// Bus shared between drivers through a `RefCell`
+///
+/// Borrows the bus for each bus call. `RefCell` isn't `Sync`, keep every
+/// user in one execution context.
+pub struct SharedBus<'a, SPI>(&'a RefCell<SPI>);
+
+impl<'a, SPI> SharedBus<'a, SPI> {
+    pub fn new(bus: &'a RefCell<SPI>) -> Self {
+        SharedBus(bus)
+    }
+}
+
+impl<SPI> Clone for SharedBus<'_, SPI> {
+    fn clone(&self) -> Self {
+        SharedBus(self.0)
+    }
+}
+
+macro_rules! forward_bus {
+    ($bus:ident, $this:ident => $spi:expr) => {
+        impl<SPI: Write<u8>> Write<u8> for $bus<'_, SPI> {
+            type Error = SPI::Error;
+
+            fn write(&mut $this, words: &[u8]) -> Result<(), Self::Error> {
+                $spi.write(words)
+            }
+        }
+
+        impl<SPI: Transfer<u8>> Transfer<u8> for $bus<'_, SPI> {
+            type Error = SPI::Error;
+
+            fn transfer<'w>(&mut $this, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+                $spi.transfer(words)
+            }
+        }
+
+        impl<SPI: FullDuplex<u8>> FullDuplex<u8> for $bus<'_, SPI> {
+            type Error = SPI::Error;
+
+            fn read(&mut $this) -> nb::Result<u8, Self::Error> {
+                $spi.read()
+            }
+
+            fn send(&mut $this, word: u8) -> nb::Result<(), Self::Error> {
+                $spi.send(word)
+            }
+        }
+    };
+}
+
+forward_bus!(BorrowedBus, self => self.0);
+forward_bus!(SharedBus, self => self.0.borrow_mut());
+
+/// Borrowed delay, lets a method reuse its delay across several transactions
+///
+/// E.g. `ads.set_config(config, DelayRef(&mut delay))`.
+pub struct DelayRef<'a, D>(pub &'a mut D);
+
+impl<D: DelayUs<u32>> DelayUs<u32> for DelayRef<'_, D> {
+    #[inline]
+    fn delay_us(&mut self, us: u32) {
+        self.0.delay_us(us)
+    }
+}
+
+/// Delay doing nothing, for transactions without timing requirements
+pub(crate) struct NoDelay;
+
+impl DelayUs<u32> for NoDelay {
+    #[inline]
+    fn delay_us(&mut self, _us: u32) {}
+}
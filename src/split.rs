@@ -0,0 +1,353 @@
+//! Driver halves for interrupt driven acquisition
+//!
+//! `split` hands the frame reads to a [`DataReader`], e.g. a resource of the
+//! DRDY interrupt, and the register access to a [`ConfigPort`] kept by the
+//! configuring task. The reader keeps the frame settings, the port the
+//! control pins, register shadow and clock. Bus and nCS are parked in a
+//! [`SharedDevice`] in between, each half takes them for its own calls only.
+//!
+//! [`ConfigPort::access`] keeps the bus for the whole access: it stops
+//! continuous mode with `SDATAC` and resumes it with `RDATAC` once done.
+//! Reads meanwhile fail with [`Ads129xError::BusBusy`], the frames are lost.
+//! With the `critical-section` feature the device is `Sync` and the halves
+//! can live in different execution contexts, otherwise keep both in one.
+//!
+//! ```ignore
+//! static DEVICE: SharedDevice<Spi, Ncs> = SharedDevice::new();
+//! let (mut reader, mut port) = ads.split(&DEVICE).ok().unwrap();
+//! // DRDY interrupt
+//! reader.read_data(&mut frame, DelayRef(&mut delay))?;
+//! // idle task
+//! let loff = port.access(delay, |ads, delay| {
+//!     ads.leadoff_control(DelayRef(delay))
+//! })?;
+//! let ads = port.join(reader).ok().unwrap();
+//! ```
+
+use core::cell::Cell;
+
+use ehal::blocking::delay::DelayUs;
+use ehal::blocking::spi::{Transfer, Write};
+use ehal::digital::v2::{InputPin, OutputPin};
+use embedded_hal as ehal;
+
+use crate::stats::DriverStats;
+use crate::{command, common, data, mode, pins, shadow, spi};
+use crate::{Ads1292Family, Ads1298Family, Ads129x, Ads129xError, Ads129xResult};
+
+#[cfg(feature = "critical-section")]
+type Slot<T> = critical_section::Mutex<Cell<Option<T>>>;
+
+#[cfg(not(feature = "critical-section"))]
+type Slot<T> = Cell<Option<T>>;
+
+/// Bus and nCS of a split driver
+///
+/// Empty until `split`, and while a half uses the bus.
+pub struct SharedDevice<SPI, NCS> {
+    slot: Slot<spi::SpiDevice<SPI, NCS>>,
+}
+
+impl<SPI, NCS> SharedDevice<SPI, NCS> {
+    #[cfg(feature = "critical-section")]
+    pub const fn new() -> Self {
+        SharedDevice {
+            slot: critical_section::Mutex::new(Cell::new(None)),
+        }
+    }
+
+    #[cfg(not(feature = "critical-section"))]
+    pub const fn new() -> Self {
+        SharedDevice {
+            slot: Cell::new(None),
+        }
+    }
+
+    #[cfg(feature = "critical-section")]
+    fn replace(
+        &self,
+        device: Option<spi::SpiDevice<SPI, NCS>>,
+    ) -> Option<spi::SpiDevice<SPI, NCS>> {
+        critical_section::with(|cs| self.slot.borrow(cs).replace(device))
+    }
+
+    #[cfg(not(feature = "critical-section"))]
+    fn replace(
+        &self,
+        device: Option<spi::SpiDevice<SPI, NCS>>,
+    ) -> Option<spi::SpiDevice<SPI, NCS>> {
+        self.slot.replace(device)
+    }
+
+    fn take<E>(&self) -> Ads129xResult<spi::SpiDevice<SPI, NCS>, E> {
+        self.replace(None).ok_or(Ads129xError::BusBusy)
+    }
+
+    fn put(&self, device: spi::SpiDevice<SPI, NCS>) {
+        self.replace(Some(device));
+    }
+}
+
+impl<SPI, NCS> Default for SharedDevice<SPI, NCS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Frame reading half
+pub struct DataReader<'a, SPI, NCS, DEV, const CH: usize> {
+    device:     &'a SharedDevice<SPI, NCS>,
+    validation: data::FrameValidation,
+    format:     data::SampleFormat,
+    timestamp:  Option<fn() -> u64>,
+    _d:         core::marker::PhantomData<DEV>,
+}
+
+/// Register access half
+pub struct ConfigPort<'a, SPI, NCS, DEV, const CH: usize, PINS> {
+    device:     &'a SharedDevice<SPI, NCS>,
+    /// Out while an access runs
+    pins:       Option<PINS>,
+    shadow:     shadow::Shadow,
+    observed:   shadow::Shadow,
+    clock:      common::ClockSource,
+    verify:     bool,
+    continuous: bool,
+    /// Policy before `split`, restored by `join`
+    access:     mode::AccessPolicy,
+    _d:         core::marker::PhantomData<DEV>,
+}
+
+impl<SPI, NCS, DEV, E, PINS, const CH: usize> Ads129x<SPI, NCS, DEV, CH, PINS, mode::Continuous>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+{
+    /// Split into a frame reader and a register access port sharing
+    /// `device`, see [`split`](crate::split)
+    ///
+    /// Hands the driver back when `device` already holds the bus of another
+    /// split driver.
+    #[allow(clippy::type_complexity)]
+    pub fn split(
+        self,
+        device: &SharedDevice<SPI, NCS>,
+    ) -> Result<
+        (
+            DataReader<'_, SPI, NCS, DEV, CH>,
+            ConfigPort<'_, SPI, NCS, DEV, CH, PINS>,
+        ),
+        Self,
+    > {
+        if let Some(other) = device.replace(None) {
+            device.put(other);
+            return Err(self);
+        }
+        device.put(self.spi);
+        let reader = DataReader {
+            device,
+            validation: self.validation,
+            format: self.format,
+            timestamp: self.timestamp,
+            _d: core::marker::PhantomData,
+        };
+        let port = ConfigPort {
+            device,
+            pins: Some(self.pins),
+            shadow: self.shadow,
+            observed: self.observed,
+            clock: self.clock,
+            verify: self.verify,
+            continuous: self.continuous,
+            access: self.access,
+            _d: core::marker::PhantomData,
+        };
+        Ok((reader, port))
+    }
+}
+
+impl<'a, SPI, NCS, DEV, const CH: usize> DataReader<'a, SPI, NCS, DEV, CH> {
+    /// Bus and acquisition counters, `None` while the port holds the bus
+    pub fn stats(&self) -> Option<DriverStats> {
+        let device = self.device.replace(None)?;
+        let stats = device.stats.clone();
+        self.device.put(device);
+        Some(stats)
+    }
+
+    /// Run `f` on a driver around the parked bus, reading frames only
+    fn with_driver<R, E>(
+        &mut self,
+        f: impl FnOnce(&mut Ads129x<SPI, NCS, DEV, CH>) -> Ads129xResult<R, E>,
+    ) -> Ads129xResult<R, E> {
+        let mut driver = Ads129x {
+            spi:        self.device.take()?,
+            pins:       pins::ControlPins::default(),
+            shadow:     shadow::Shadow::new(),
+            observed:   shadow::Shadow::new(),
+            clock:      common::ClockSource::default(),
+            verify:     false,
+            validation: self.validation,
+            format:     self.format,
+            timestamp:  self.timestamp,
+            continuous: true,
+            access:     mode::AccessPolicy::Manual,
+            _d:         core::marker::PhantomData,
+        };
+        let res = f(&mut driver);
+        self.device.put(driver.spi);
+        res
+    }
+}
+
+impl<SPI, NCS, E, V, const CH: usize> DataReader<'_, SPI, NCS, Ads1292Family<V>, CH>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+{
+    /// See `Ads129x::read_data`
+    pub fn read_data(
+        &mut self,
+        data_frame: &mut data::DataFrame92,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.with_driver(|ads| ads.read_data(data_frame, delay))
+    }
+
+    /// See `Ads129x::read_data_when_ready`
+    pub fn read_data_when_ready<P: InputPin>(
+        &mut self,
+        drdy: &P,
+        data_frame: &mut data::DataFrame92,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        pins::wait_drdy(drdy).map_err(|_| Ads129xError::DrdyPin)?;
+        self.read_data(data_frame, delay)
+    }
+}
+
+impl<SPI, NCS, E, V, const CH: usize> DataReader<'_, SPI, NCS, Ads1298Family<V>, CH>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+{
+    /// See `Ads129x::read_data`
+    pub fn read_data(
+        &mut self,
+        data_frame: &mut data::DataFrame<CH>,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        self.with_driver(|ads| ads.read_data(data_frame, delay))
+    }
+
+    /// See `Ads129x::read_data_when_ready`
+    pub fn read_data_when_ready<P: InputPin>(
+        &mut self,
+        drdy: &P,
+        data_frame: &mut data::DataFrame<CH>,
+        delay: impl DelayUs<u32>,
+    ) -> Ads129xResult<(), E> {
+        pins::wait_drdy(drdy).map_err(|_| Ads129xError::DrdyPin)?;
+        self.read_data(data_frame, delay)
+    }
+}
+
+impl<'a, SPI, NCS, DEV, E, PINS, const CH: usize> ConfigPort<'a, SPI, NCS, DEV, CH, PINS>
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    PINS: pins::ControlPinSet,
+{
+    /// Run register accesses `f` with continuous mode stopped
+    ///
+    /// Takes the bus, sends `SDATAC`, runs `f` and sends `RDATAC`, also when
+    /// `f` failed. `f` gets the driver in [`mode::Dynamic`] without automatic
+    /// stops and `delay` to pass on, wrapped in [`spi::DelayRef`].
+    pub fn access<D, R>(
+        &mut self,
+        mut delay: D,
+        f: impl FnOnce(
+            &mut Ads129x<SPI, NCS, DEV, CH, PINS, mode::Dynamic>,
+            &mut D,
+        ) -> Ads129xResult<R, E>,
+    ) -> Ads129xResult<R, E>
+    where
+        D: DelayUs<u32>,
+    {
+        let spi = self.device.take()?;
+        let mut driver = Ads129x {
+            spi,
+            pins: self
+                .pins
+                .take()
+                .expect("control pins are back after every access"),
+            shadow: self.shadow.clone(),
+            observed: self.observed.clone(),
+            clock: self.clock,
+            verify: self.verify,
+            validation: data::FrameValidation::default(),
+            format: data::SampleFormat::default(),
+            timestamp: None,
+            continuous: self.continuous,
+            access: mode::AccessPolicy::Manual,
+            _d: core::marker::PhantomData,
+        };
+
+        let res = driver
+            .command(command::Command::SDATAC, spi::DelayRef(&mut delay))
+            .and_then(|()| f(&mut driver, &mut delay));
+        let resumed = driver.command(command::Command::RDATAC, delay);
+
+        self.pins = Some(driver.pins);
+        self.shadow = driver.shadow;
+        self.observed = driver.observed;
+        self.clock = driver.clock;
+        self.verify = driver.verify;
+        self.continuous = driver.continuous;
+        self.device.put(driver.spi);
+
+        let res = res?;
+        resumed?;
+        Ok(res)
+    }
+
+    /// Recombine the halves of one `split`, with the access policy from
+    /// before the split
+    ///
+    /// Hands both back when `reader` came from another split driver.
+    #[allow(clippy::type_complexity)]
+    pub fn join(
+        mut self,
+        reader: DataReader<'a, SPI, NCS, DEV, CH>,
+    ) -> Result<
+        Ads129x<SPI, NCS, DEV, CH, PINS, mode::Continuous>,
+        (Self, DataReader<'a, SPI, NCS, DEV, CH>),
+    > {
+        if !core::ptr::eq(self.device, reader.device) {
+            return Err((self, reader));
+        }
+        let (spi, pins) = match (self.device.replace(None), self.pins.take()) {
+            (Some(spi), Some(pins)) => (spi, pins),
+            (spi, pins) => {
+                self.device.replace(spi);
+                self.pins = pins;
+                return Err((self, reader));
+            }
+        };
+        Ok(Ads129x {
+            spi,
+            pins,
+            shadow: self.shadow,
+            observed: self.observed,
+            clock: self.clock,
+            verify: self.verify,
+            validation: reader.validation,
+            format: reader.format,
+            timestamp: reader.timestamp,
+            continuous: self.continuous,
+            access: self.access,
+            _d: core::marker::PhantomData,
+        })
+    }
+}
@@ -0,0 +1,67 @@
+//! Driver statistics
+//!
+//! Cheap counters of bus and acquisition events since boot or the last
+//! `reset_stats`. All counters wrap on overflow. Without the `stats` feature
+//! the struct is empty and every update compiles to nothing.
+//!
+//! With [`Lenient`](crate::data::FrameValidation::Lenient) validation a sync
+//! mismatch isn't an error, `sync_failures` and `last_error_status` are then
+//! the only sign of reads slipping against `DRDY`.
+
+/// Bus and acquisition counters
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DriverStats {
+    /// SPI transactions, one per nCS assertion
+    #[cfg(feature = "stats")]
+    pub transactions:          u32,
+    /// Bytes shifted over SPI
+    #[cfg(feature = "stats")]
+    pub bytes:                 u32,
+    /// Data frames read, including rejected ones
+    #[cfg(feature = "stats")]
+    pub frames:                u32,
+    /// Frames rejected for a status word sync mismatch
+    #[cfg(feature = "stats")]
+    pub sync_failures:         u32,
+    /// Status word of the last frame with a sync mismatch
+    #[cfg(feature = "stats")]
+    pub last_error_status:     Option<[u8; 3]>,
+    /// Register read backs differing from the written value
+    #[cfg(feature = "stats")]
+    pub write_verify_failures: u32,
+}
+
+#[cfg(feature = "stats")]
+impl DriverStats {
+    #[inline]
+    pub(crate) fn transaction(&mut self, bytes: usize) {
+        self.transactions = self.transactions.wrapping_add(1);
+        self.bytes = self.bytes.wrapping_add(bytes as u32);
+    }
+
+    #[inline]
+    pub(crate) fn frame(&mut self, status: [u8; 3]) {
+        self.frames = self.frames.wrapping_add(1);
+        if status[0] >> 4 != 0b1100 {
+            self.sync_failures = self.sync_failures.wrapping_add(1);
+            self.last_error_status = Some(status);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn write_verify_failure(&mut self) {
+        self.write_verify_failures = self.write_verify_failures.wrapping_add(1);
+    }
+}
+
+#[cfg(not(feature = "stats"))]
+impl DriverStats {
+    #[inline]
+    pub(crate) fn transaction(&mut self, _bytes: usize) {}
+
+    #[inline]
+    pub(crate) fn frame(&mut self, _status: [u8; 3]) {}
+
+    #[inline]
+    pub(crate) fn write_verify_failure(&mut self) {}
+}
@@ -0,0 +1,178 @@
+//! Serial interface timing constraints
+
+/// Nominal master clock frequency `fCLK`, nominal data rates refer to it
+pub const FCLK_NOMINAL_HZ: u32 = 2_048_000;
+
+/// Maximum SCLK frequency (`tSCLK` >= 50 ns)
+pub const SCLK_MAX_HZ: u32 = 20_000_000;
+
+/// Parameters bounding the SCLK frequency
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SclkParams {
+    /// Master clock frequency `fCLK`
+    pub fclk_hz:       u32,
+    /// Output data rate at nominal `fCLK`, scales with the actual `fCLK`
+    pub data_rate_sps: u32,
+    /// Channels per device
+    pub channels:      usize,
+    /// Devices in the daisy chain, 1 for a single device
+    pub daisy_depth:   usize,
+}
+
+/// Violated SCLK bound
+///
+/// Both variants carry the allowed range, `min_hz > max_hz` means no SCLK satisfies the
+/// configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SclkError {
+    /// Full frame(s) can't be shifted out between two DRDY pulses
+    TooSlow { sclk_hz: u32, min_hz: u32, max_hz: u32 },
+    /// Serial interface or command decoder can't keep up
+    TooFast { sclk_hz: u32, min_hz: u32, max_hz: u32 },
+}
+
+impl core::fmt::Display for SclkError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (what, sclk_hz, min_hz, max_hz) = match *self {
+            Self::TooSlow {
+                sclk_hz,
+                min_hz,
+                max_hz,
+            } => ("slow", sclk_hz, min_hz, max_hz),
+            Self::TooFast {
+                sclk_hz,
+                min_hz,
+                max_hz,
+            } => ("fast", sclk_hz, min_hz, max_hz),
+        };
+        write!(
+            f,
+            "SCLK {} Hz too {}, allowed {}..={} Hz",
+            sclk_hz, what, min_hz, max_hz
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SclkError {}
+
+impl SclkParams {
+    /// Actual output data rate
+    pub fn data_rate_hz(&self) -> u32 {
+        (self.data_rate_sps as u64 * self.fclk_hz as u64 / FCLK_NOMINAL_HZ as u64) as u32
+    }
+
+    /// Bits clocked out per DRDY period: status word and 24 bits per channel for each device
+    pub fn frame_bits(&self) -> u32 {
+        ((24 + 24 * self.channels) * self.daisy_depth) as u32
+    }
+
+    /// Allowed SCLK range `(min_hz, max_hz)` of the data readback
+    ///
+    /// Lower bound: data must be retrieved within `tDR - 4 tCLK` after DRDY.
+    ///
+    /// Upper bound: `tSCLK` >= 50 ns. Multi-byte commands are bound tighter,
+    /// see [`command_sclk_max_hz`](Self::command_sclk_max_hz).
+    pub fn sclk_range(&self) -> (u32, u32) {
+        let fclk = self.fclk_hz as u64;
+        let data_rate = self.data_rate_hz() as u64;
+
+        // bits / (1 / fDR - 4 / fCLK)
+        let window = fclk.saturating_sub(4 * data_rate);
+        let min_hz = match window {
+            0 => u32::MAX as u64,
+            _ => (self.frame_bits() as u64 * data_rate * fclk).div_ceil(window),
+        };
+
+        (min_hz.min(u32::MAX as u64) as u32, SCLK_MAX_HZ)
+    }
+
+    /// Highest SCLK for multi-byte commands such as `RREG`/`WREG`
+    ///
+    /// The driver sends their bytes back to back, so each byte must span the
+    /// 4 `tCLK` command decode time, i.e. `fSCLK` <= 2 `fCLK`.
+    pub fn command_sclk_max_hz(&self) -> u32 {
+        core::cmp::min(SCLK_MAX_HZ as u64, 2 * self.fclk_hz as u64) as u32
+    }
+
+    /// Check SCLK frequency against [`sclk_range`](Self::sclk_range)
+    pub fn validate(&self, sclk_hz: u32) -> Result<(), SclkError> {
+        let (min_hz, max_hz) = self.sclk_range();
+        if sclk_hz < min_hz {
+            return Err(SclkError::TooSlow {
+                sclk_hz,
+                min_hz,
+                max_hz,
+            });
+        }
+        if sclk_hz > max_hz {
+            return Err(SclkError::TooFast {
+                sclk_hz,
+                min_hz,
+                max_hz,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Command decode time before the next command, >= 4 `tCLK` at nominal `fCLK`
+pub const DECODE_US: u32 = 2;
+
+/// RESET pin low pulse width, >= 2 `tCLK` at nominal `fCLK`
+pub const RESET_PULSE_US: u32 = 2;
+
+/// Wait after reset before accessing the device, >= 18 `tCLK` at nominal `fCLK`
+pub const RESET_RECOVERY_US: u32 = 10;
+
+/// Wait after reset, 18 `tCLK` at `fclk_hz`
+///
+/// [`RESET_RECOVERY_US`] covers the nominal `fCLK` only.
+pub const fn reset_recovery_us(fclk_hz: u32) -> u32 {
+    (18 * 1_000_000u32).div_ceil(fclk_hz)
+}
+
+/// `DRDY` polling period of the timed waits
+pub const DRDY_POLL_US: u32 = 10;
+
+/// Wait between `ID` read attempts of `verify_device`
+pub const ID_RETRY_US: u32 = 100;
+
+/// START pin setup time before the next SPI access, >= 2 `tCLK` at nominal `fCLK`
+pub const START_SETUP_US: u32 = 2;
+
+/// Power-on reset time `tPOR` = 2^18 `tCLK` at nominal `fCLK`
+pub const POWER_UP_US: u32 = 128_000;
+
+/// Internal reference settling after its buffer is powered up, conservative
+pub const REFERENCE_SETTLE_US: u32 = 150_000;
+
+/// START pin high pulse width in single-shot mode, >= 2 `tCLK` at nominal `fCLK`
+pub const START_PULSE_US: u32 = 2;
+
+/// Conversion time after a START edge in single-shot mode
+///
+/// Conservative bound on the datasheet settling time `tSETTLE` at nominal `fCLK`: 4 data
+/// periods plus 18 `tCLK` start-up latency.
+pub const fn settling_time_us(data_rate_sps: u32) -> u32 {
+    4 * 1_000_000u32.div_ceil(data_rate_sps) + 9
+}
+
+/// One data period at nominal `fCLK`, rounded up
+pub const fn data_period_us(data_rate_sps: u32) -> u32 {
+    1_000_000u32.div_ceil(data_rate_sps)
+}
+
+/// Wait after the `OFFSETCAL` command
+///
+/// Conservative bound at nominal `fCLK`: 16 data periods plus 18 `tCLK`
+/// start-up latency.
+pub const fn offset_calibration_time_us(data_rate_sps: u32) -> u32 {
+    16 * 1_000_000u32.div_ceil(data_rate_sps) + 9
+}
+
+/// Wait after enabling the CLK output before the device clocked from it is accessed
+///
+/// Covers the internal oscillator start-up when it was idle and lets the downstream device's
+/// digital core run a few hundred clock cycles.
+pub const CLK_OUTPUT_SETTLE_US: u32 = 100;
@@ -15,21 +15,62 @@ macro_rules! impl_cmd {
     };
 }
 
+/// Define `WithDelay` methods calling the driver's namesake with the stored
+/// delay last
+macro_rules! forward {
+    ($($fn_name:ident($($arg:ident: $ty:ty),*) -> $ret:ty;)*) => {
+        $(
+            #[doc = concat!("[`Ads129x::", stringify!($fn_name), "`] with the stored delay")]
+            pub fn $fn_name(&mut self, $($arg: $ty),*) -> Ads129xResult<$ret, E> {
+                self.driver.$fn_name($($arg,)* spi::DelayRef(&mut self.delay))
+            }
+        )*
+    };
+}
+
 macro_rules! write_reg {
+    (DELAYED: FAM: $family_path:ident, FN: $fn_name:ident, REG: $reg_name:ident ($param_path:ident::$param_ty:ident => $reg_path:ident::$reg_ty:ident)) => {
+        forward! {
+            $fn_name(param: $family_path::$param_path::$param_ty) -> ();
+        }
+    };
+    (ASYNC: FAM: $family_path:ident, FN: $fn_name:ident, REG: $reg_name:ident ($param_path:ident::$param_ty:ident => $reg_path:ident::$reg_ty:ident)) => {
+        #[doc = concat!("Write register ", stringify!($reg_name))]
+        pub async fn $fn_name(
+            &mut self,
+            param: $family_path::$param_path::$param_ty,
+            delay: &mut impl DelayNs,
+        ) -> Ads129xResult<(), SPI::Error> {
+            self.write_register_raw(
+                $family_path::Register::$reg_name as u8,
+                $family_path::Register::$reg_name.info(),
+                $family_path::$reg_path::$reg_ty::from(param).0,
+                delay,
+            )
+            .await
+        }
+    };
     (_INNER: $doc:expr, FAM: $family_path:ident, FN: $fn_name:ident, REG: $reg_name:ident ($param_path:ident::$param_ty:ident => $reg_path:ident::$reg_ty:ident)) => {
         #[doc = $doc]
         pub fn $fn_name(
             &mut self,
             param: $family_path::$param_path::$param_ty,
-            delay: impl DelayUs<u32>,
+            mut delay: impl DelayUs<u32>,
         ) -> Ads129xResult<(), E> {
             let mut words = [
                 command::Command::WREG as u8 | $family_path::Register::$reg_name as u8,
                 0x00,
                 $family_path::$reg_path::$reg_ty::from(param).0,
             ];
-            let _ = self.spi.write(&mut words, delay)?;
-            Ok(())
+            let stopped = self.stop_for_access(spi::DelayRef(&mut delay))?;
+            let _ = self.spi.write(&mut words, spi::DelayRef(&mut delay))?;
+            self.written(
+                $family_path::Register::$reg_name as u8,
+                $family_path::Register::$reg_name.info(),
+                words[2],
+                spi::DelayRef(&mut delay),
+            )?;
+            self.resume_after_access(stopped, delay)
         }
     };
     (FAM: $family_path:ident, FN: $fn_name:ident, REG: $reg_name:ident ($param_path:ident::$param_ty:ident => $reg_path:ident::$reg_ty:ident)) => {
@@ -43,18 +84,41 @@ macro_rules! write_reg {
 }
 
 macro_rules! read_reg {
-    (_INNER: $doc:expr, FAM: $family_path:ident, FN: $fn_name:ident, REG: $reg_name:ident ($param_path:ident::$param_ty:ident <= $reg_path:ident::$reg_ty:ident)) => {
+    (DELAYED: FAM: $family_path:ident, FN: $fn_name:ident, REG: $reg_name:ident ($param_path:ident::$param_ty:ident <= $reg_path:ident::$reg_ty:ident) $(, SHADOW)?) => {
+        forward! {
+            $fn_name() -> $family_path::$param_path::$param_ty;
+        }
+    };
+    (ASYNC: FAM: $family_path:ident, FN: $fn_name:ident, REG: $reg_name:ident ($param_path:ident::$param_ty:ident <= $reg_path:ident::$reg_ty:ident) $(, SHADOW)?) => {
+        #[doc = concat!("Read register ", stringify!($reg_name))]
+        pub async fn $fn_name(
+            &mut self,
+            delay: &mut impl DelayNs,
+        ) -> Ads129xResult<$family_path::$param_path::$param_ty, SPI::Error> {
+            let raw = self
+                .read_register_raw($family_path::Register::$reg_name as u8, delay)
+                .await?;
+            $family_path::$param_path::$param_ty::try_from($family_path::$reg_path::$reg_ty(raw))
+                .map_err(|e| Ads129xError::ReadInterpret(e))
+        }
+    };
+    (_INNER: $doc:expr, FAM: $family_path:ident, FN: $fn_name:ident, REG: $reg_name:ident ($param_path:ident::$param_ty:ident <= $reg_path:ident::$reg_ty:ident), SHADOW: $shadow:literal) => {
         #[doc = $doc]
         pub fn $fn_name(
             &mut self,
-            delay: impl DelayUs<u32>,
+            mut delay: impl DelayUs<u32>,
         ) -> Ads129xResult<$family_path::$param_path::$param_ty, E> {
             let mut words = [
                 command::Command::RREG as u8 | $family_path::Register::$reg_name as u8,
                 0x00,
                 0xA5,
             ];
-            let res = self.spi.transfer(&mut words, delay)?;
+            let stopped = self.stop_for_access(spi::DelayRef(&mut delay))?;
+            let res = self.spi.transfer(&mut words, spi::DelayRef(&mut delay))?;
+            self.resume_after_access(stopped, delay)?;
+            if $shadow {
+                self.observe($family_path::Register::$reg_name as u8, &[res[2]]);
+            }
 
             let param = $family_path::$param_path::$param_ty::try_from(
                 $family_path::$reg_path::$reg_ty(res[2]),
@@ -69,7 +133,252 @@ macro_rules! read_reg {
             _INNER: concat!("Read register ", stringify!($reg_name)),
             FAM: $family_path,
             FN: $fn_name,
-            REG: $reg_name ($param_path::$param_ty <= $reg_path::$reg_ty)
+            REG: $reg_name ($param_path::$param_ty <= $reg_path::$reg_ty),
+            SHADOW: false
+        );
+    };
+    // Read value is kept for the channel gains, apart from the written shadow
+    (FAM: $family_path:ident, FN: $fn_name:ident, REG: $reg_name:ident ($param_path:ident::$param_ty:ident <= $reg_path:ident::$reg_ty:ident), SHADOW) => {
+        read_reg!(
+            _INNER: concat!("Read register ", stringify!($reg_name)),
+            FAM: $family_path,
+            FN: $fn_name,
+            REG: $reg_name ($param_path::$param_ty <= $reg_path::$reg_ty),
+            SHADOW: true
         );
     };
 }
+
+macro_rules! modify_reg {
+    (DELAYED: FAM: $family_path:ident, FN: $fn_name:ident, REG: $reg_name:ident ($param_path:ident::$param_ty:ident <=> $reg_path:ident::$reg_ty:ident), SET: $set_fn:ident) => {
+        forward! {
+            $fn_name(f: impl FnOnce(&mut $family_path::$param_path::$param_ty)) -> ();
+        }
+    };
+    (ASYNC: FAM: $family_path:ident, FN: $fn_name:ident, REG: $reg_name:ident ($param_path:ident::$param_ty:ident <=> $reg_path:ident::$reg_ty:ident), SET: $set_fn:ident) => {
+        #[doc = concat!("Read-modify-write register ", stringify!($reg_name))]
+        ///
+        /// Starts from the last written value, reads the register when there is
+        /// none. Writes only when `f` changed the value.
+        pub async fn $fn_name(
+            &mut self,
+            f: impl FnOnce(&mut $family_path::$param_path::$param_ty),
+            delay: &mut impl DelayNs,
+        ) -> Ads129xResult<(), SPI::Error> {
+            let reg = $family_path::Register::$reg_name as u8;
+            let raw = match self.shadow.get(reg) {
+                Some(val) => val,
+                None => self.read_register_raw(reg, delay).await?,
+            };
+            let old = $family_path::$param_path::$param_ty::try_from(
+                $family_path::$reg_path::$reg_ty(raw),
+            )
+            .map_err(|e| Ads129xError::ReadInterpret(e))?;
+
+            let mut new = old;
+            f(&mut new);
+            if new != old {
+                self.$set_fn(new, delay).await?;
+            }
+            Ok(())
+        }
+    };
+    (_INNER: $doc:expr, FAM: $family_path:ident, FN: $fn_name:ident, REG: $reg_name:ident ($param_path:ident::$param_ty:ident <=> $reg_path:ident::$reg_ty:ident), SET: $set_fn:ident) => {
+        #[doc = $doc]
+        ///
+        /// Starts from the last written value, reads the register when there is
+        /// none. Writes only when `f` changed the value.
+        pub fn $fn_name(
+            &mut self,
+            f: impl FnOnce(&mut $family_path::$param_path::$param_ty),
+            mut delay: impl DelayUs<u32>,
+        ) -> Ads129xResult<(), E> {
+            let reg = $family_path::Register::$reg_name as u8;
+            let raw = match self.shadow.get(reg) {
+                Some(val) => val,
+                None => self.read_register_raw(reg, spi::DelayRef(&mut delay))?,
+            };
+            let old = $family_path::$param_path::$param_ty::try_from(
+                $family_path::$reg_path::$reg_ty(raw),
+            )
+            .map_err(|e| Ads129xError::ReadInterpret(e))?;
+
+            let mut new = old;
+            f(&mut new);
+            if new != old {
+                self.$set_fn(new, delay)?;
+            }
+            Ok(())
+        }
+    };
+    (FAM: $family_path:ident, FN: $fn_name:ident, REG: $reg_name:ident ($param_path:ident::$param_ty:ident <=> $reg_path:ident::$reg_ty:ident), SET: $set_fn:ident) => {
+        modify_reg!(
+            _INNER: concat!("Read-modify-write register ", stringify!($reg_name)),
+            FAM: $family_path,
+            FN: $fn_name,
+            REG: $reg_name ($param_path::$param_ty <=> $reg_path::$reg_ty),
+            SET: $set_fn
+        );
+    };
+}
+
+#[cfg(feature = "async")]
+macro_rules! impl_cmd_async {
+    ($fn_name:ident, $command:ident) => {
+        #[doc = concat!("Spi command ", stringify!($command))]
+        pub async fn $fn_name(
+            &mut self,
+            delay: &mut impl DelayNs,
+        ) -> Ads129xResult<(), SPI::Error> {
+            self.command(command::Command::$command, delay).await
+        }
+    };
+}
+
+// Register accessor tables, expanded into the driver, with `DELAYED:` into
+// the `WithDelay` forwards and with `ASYNC:` into the async driver, so all
+// offer the same accessors
+
+// ADS1291/2 registers
+macro_rules! ads1292_registers {
+    ($($target:tt)*) => {
+        read_reg!($($target)* FAM: ads1292, FN: config, REG: CONFIG1 (conf::Config <= conf::Config1Reg));
+        write_reg!($($target)* FAM: ads1292, FN: set_config, REG: CONFIG1 (conf::Config => conf::Config1Reg));
+        modify_reg!($($target)* FAM: ads1292, FN: modify_config, REG: CONFIG1 (conf::Config <=> conf::Config1Reg), SET: set_config);
+
+        read_reg!($($target)* FAM: ads1292, FN: misc_config, REG: CONFIG2 (conf::MiscConfig <= conf::Config2Reg));
+        write_reg!($($target)* FAM: ads1292, FN: set_misc_config, REG: CONFIG2 (conf::MiscConfig => conf::Config2Reg));
+        modify_reg!($($target)* FAM: ads1292, FN: modify_misc_config, REG: CONFIG2 (conf::MiscConfig <=> conf::Config2Reg), SET: set_misc_config);
+
+        read_reg!($($target)* FAM: ads1292, FN: chan_1, REG: CH1SET (chan::Chan <= chan::ChanSetReg), SHADOW);
+        read_reg!($($target)* FAM: ads1292, FN: chan_2, REG: CH2SET (chan::Chan <= chan::ChanSetReg), SHADOW);
+        write_reg!($($target)* FAM: ads1292, FN: set_chan_1, REG: CH1SET (chan::Chan => chan::ChanSetReg));
+        write_reg!($($target)* FAM: ads1292, FN: set_chan_2, REG: CH2SET (chan::Chan => chan::ChanSetReg));
+
+        read_reg!($($target)* FAM: ads1292, FN: rld_sense, REG: RLD_SENS (rld::RldSense <= rld::RldSenseReg));
+        write_reg!($($target)* FAM: ads1292, FN: set_rld_sense, REG: RLD_SENS (rld::RldSense => rld::RldSenseReg));
+        modify_reg!($($target)* FAM: ads1292, FN: modify_rld_sense, REG: RLD_SENS (rld::RldSense <=> rld::RldSenseReg), SET: set_rld_sense);
+
+        read_reg!($($target)* FAM: ads1292, FN: leadoff_sense, REG: LOFF_SENS (loff::LeadOffSense <= loff::LoffSense));
+        write_reg!($($target)* FAM: ads1292, FN: set_leadoff_sense, REG: LOFF_SENS (loff::LeadOffSense => loff::LoffSense));
+        modify_reg!($($target)* FAM: ads1292, FN: modify_leadoff_sense, REG: LOFF_SENS (loff::LeadOffSense <=> loff::LoffSense), SET: set_leadoff_sense);
+
+        read_reg!($($target)* FAM: ads1292, FN: leadoff_status, REG: LOFF_STAT (loff::LeadOffStatus <= loff::LeadOffStatusReg));
+
+        read_reg!($($target)* FAM: ads1292, FN: leadoff_control, REG: LOFF (loff::LeadOffControl <= loff::LeadOffControlReg));
+        write_reg!($($target)* FAM: ads1292, FN: set_leadoff_control, REG: LOFF (loff::LeadOffControl => loff::LeadOffControlReg));
+        modify_reg!($($target)* FAM: ads1292, FN: modify_leadoff_control, REG: LOFF (loff::LeadOffControl <=> loff::LeadOffControlReg), SET: set_leadoff_control);
+
+        read_reg!($($target)* FAM: ads1292, FN: resp2, REG: RESP2 (resp::Resp2 <= resp::RespControl2Reg));
+        write_reg!($($target)* FAM: ads1292, FN: set_resp2, REG: RESP2 (resp::Resp2 => resp::RespControl2Reg));
+        modify_reg!($($target)* FAM: ads1292, FN: modify_resp2, REG: RESP2 (resp::Resp2 <=> resp::RespControl2Reg), SET: set_resp2);
+
+        read_reg!($($target)* FAM: ads1292, FN: gpio, REG: GPIO (gpio::Gpio <= gpio::GpioReg));
+        write_reg!($($target)* FAM: ads1292, FN: set_gpio, REG: GPIO (gpio::Gpio => gpio::GpioReg));
+        modify_reg!($($target)* FAM: ads1292, FN: modify_gpio, REG: GPIO (gpio::Gpio <=> gpio::GpioReg), SET: set_gpio);
+    };
+}
+
+// ADS1292R respiration register
+macro_rules! ads1292r_registers {
+    ($($target:tt)*) => {
+        read_reg!($($target)* FAM: ads1292, FN: resp, REG: RESP1 (resp::Resp1 <= resp::RespControl1Reg));
+        write_reg!($($target)* FAM: ads1292, FN: set_resp, REG: RESP1 (resp::Resp1 => resp::RespControl1Reg));
+        modify_reg!($($target)* FAM: ads1292, FN: modify_resp, REG: RESP1 (resp::Resp1 <=> resp::RespControl1Reg), SET: set_resp);
+    };
+}
+
+// ADS1294/6/8 registers
+macro_rules! ads1298_registers {
+    ($($target:tt)*) => {
+        read_reg!($($target)* FAM: ads1298, FN: config, REG: CONFIG1 (conf::Config <= conf::Config1Reg));
+        write_reg!($($target)* FAM: ads1298, FN: set_config, REG: CONFIG1 (conf::Config => conf::Config1Reg));
+        modify_reg!($($target)* FAM: ads1298, FN: modify_config, REG: CONFIG1 (conf::Config <=> conf::Config1Reg), SET: set_config);
+        read_reg!($($target)* FAM: ads1298, FN: test_signal_config, REG: CONFIG2 (conf::TestSignalConfig <= conf::Config2Reg));
+        write_reg!($($target)* FAM: ads1298, FN: set_test_signal_config, REG: CONFIG2 (conf::TestSignalConfig => conf::Config2Reg));
+        modify_reg!($($target)* FAM: ads1298, FN: modify_test_signal_config, REG: CONFIG2 (conf::TestSignalConfig <=> conf::Config2Reg), SET: set_test_signal_config);
+        read_reg!($($target)* FAM: ads1298, FN: test_rld_config, REG: CONFIG3 (conf::RldConfig <= conf::Config3Reg));
+        write_reg!($($target)* FAM: ads1298, FN: set_rld_config, REG: CONFIG3 (conf::RldConfig => conf::Config3Reg));
+        modify_reg!($($target)* FAM: ads1298, FN: modify_rld_config, REG: CONFIG3 (conf::RldConfig <=> conf::Config3Reg), SET: set_rld_config);
+
+        read_reg!($($target)* FAM: ads1298, FN: leadoff_control, REG: LOFF (loff::LeadOffControl <= loff::LeadOffControlReg));
+        write_reg!($($target)* FAM: ads1298, FN: set_leadoff_control, REG: LOFF (loff::LeadOffControl => loff::LeadOffControlReg));
+        modify_reg!($($target)* FAM: ads1298, FN: modify_leadoff_control, REG: LOFF (loff::LeadOffControl <=> loff::LeadOffControlReg), SET: set_leadoff_control);
+
+        read_reg!($($target)* FAM: ads1298, FN: chan_1, REG: CH1SET (chan::Chan <= chan::ChanSetReg), SHADOW);
+        read_reg!($($target)* FAM: ads1298, FN: chan_2, REG: CH2SET (chan::Chan <= chan::ChanSetReg), SHADOW);
+        read_reg!($($target)* FAM: ads1298, FN: chan_3, REG: CH3SET (chan::Chan <= chan::ChanSetReg), SHADOW);
+        read_reg!($($target)* FAM: ads1298, FN: chan_4, REG: CH4SET (chan::Chan <= chan::ChanSetReg), SHADOW);
+
+        write_reg!($($target)* FAM: ads1298, FN: set_chan_1, REG: CH1SET (chan::Chan => chan::ChanSetReg));
+        write_reg!($($target)* FAM: ads1298, FN: set_chan_2, REG: CH2SET (chan::Chan => chan::ChanSetReg));
+        write_reg!($($target)* FAM: ads1298, FN: set_chan_3, REG: CH3SET (chan::Chan => chan::ChanSetReg));
+        write_reg!($($target)* FAM: ads1298, FN: set_chan_4, REG: CH4SET (chan::Chan => chan::ChanSetReg));
+
+        read_reg!($($target)* FAM: ads1298, FN: rld_sense_positive, REG: RLD_SENSP (rld::RldSense <= rld::RldSenseReg));
+        write_reg!($($target)* FAM: ads1298, FN: set_rld_sense_positive, REG: RLD_SENSP (rld::RldSense => rld::RldSenseReg));
+        modify_reg!($($target)* FAM: ads1298, FN: modify_rld_sense_positive, REG: RLD_SENSP (rld::RldSense <=> rld::RldSenseReg), SET: set_rld_sense_positive);
+        read_reg!($($target)* FAM: ads1298, FN: rld_sense_negative, REG: RLD_SENSN (rld::RldSense <= rld::RldSenseReg));
+        write_reg!($($target)* FAM: ads1298, FN: set_rld_sense_negative, REG: RLD_SENSN (rld::RldSense => rld::RldSenseReg));
+        modify_reg!($($target)* FAM: ads1298, FN: modify_rld_sense_negative, REG: RLD_SENSN (rld::RldSense <=> rld::RldSenseReg), SET: set_rld_sense_negative);
+
+        read_reg!($($target)* FAM: ads1298, FN: leadoff_sense_positive, REG: LOFF_SENSP (loff::LeadOffSense <= loff::LeadOffSenseReg));
+        write_reg!($($target)* FAM: ads1298, FN: set_leadoff_sense_positive, REG: LOFF_SENSP (loff::LeadOffSense => loff::LeadOffSenseReg));
+        modify_reg!($($target)* FAM: ads1298, FN: modify_leadoff_sense_positive, REG: LOFF_SENSP (loff::LeadOffSense <=> loff::LeadOffSenseReg), SET: set_leadoff_sense_positive);
+        read_reg!($($target)* FAM: ads1298, FN: leadoff_sense_negative, REG: LOFF_SENSN (loff::LeadOffSense <= loff::LeadOffSenseReg));
+        write_reg!($($target)* FAM: ads1298, FN: set_leadoff_sense_negative, REG: LOFF_SENSN (loff::LeadOffSense => loff::LeadOffSenseReg));
+        modify_reg!($($target)* FAM: ads1298, FN: modify_leadoff_sense_negative, REG: LOFF_SENSN (loff::LeadOffSense <=> loff::LeadOffSenseReg), SET: set_leadoff_sense_negative);
+        read_reg!($($target)* FAM: ads1298, FN: leadoff_flip, REG: LOFF_FLIP (loff::LeadOffFlip <= loff::LeadOffFlipReg));
+        write_reg!($($target)* FAM: ads1298, FN: set_leadoff_flip, REG: LOFF_FLIP (loff::LeadOffFlip => loff::LeadOffFlipReg));
+        modify_reg!($($target)* FAM: ads1298, FN: modify_leadoff_flip, REG: LOFF_FLIP (loff::LeadOffFlip <=> loff::LeadOffFlipReg), SET: set_leadoff_flip);
+
+        read_reg!($($target)* FAM: ads1298, FN: gpio, REG: GPIO (gpio::Gpio <= gpio::GpioReg));
+        write_reg!($($target)* FAM: ads1298, FN: set_gpio, REG: GPIO (gpio::Gpio => gpio::GpioReg));
+        modify_reg!($($target)* FAM: ads1298, FN: modify_gpio, REG: GPIO (gpio::Gpio <=> gpio::GpioReg), SET: set_gpio);
+
+        read_reg!($($target)* FAM: ads1298, FN: pace, REG: PACE (pace::Pace <= pace::PaceReg));
+        write_reg!($($target)* FAM: ads1298, FN: set_pace, REG: PACE (pace::Pace => pace::PaceReg));
+        modify_reg!($($target)* FAM: ads1298, FN: modify_pace, REG: PACE (pace::Pace <=> pace::PaceReg), SET: set_pace);
+
+        read_reg!($($target)* FAM: ads1298, FN: wct1, REG: WCT1 (wct::Wct1 <= wct::Wct1Reg));
+        write_reg!($($target)* FAM: ads1298, FN: set_wct1, REG: WCT1 (wct::Wct1 => wct::Wct1Reg));
+        modify_reg!($($target)* FAM: ads1298, FN: modify_wct1, REG: WCT1 (wct::Wct1 <=> wct::Wct1Reg), SET: set_wct1);
+        read_reg!($($target)* FAM: ads1298, FN: wct2, REG: WCT2 (wct::Wct2 <= wct::Wct2Reg));
+        write_reg!($($target)* FAM: ads1298, FN: set_wct2, REG: WCT2 (wct::Wct2 => wct::Wct2Reg));
+        modify_reg!($($target)* FAM: ads1298, FN: modify_wct2, REG: WCT2 (wct::Wct2 <=> wct::Wct2Reg), SET: set_wct2);
+
+        read_reg!($($target)* FAM: ads1298, FN: misc_config, REG: CONFIG4 (conf::MiscConfig <= conf::Config4Reg));
+        write_reg!($($target)* FAM: ads1298, FN: set_misc_config, REG: CONFIG4 (conf::MiscConfig => conf::Config4Reg));
+        modify_reg!($($target)* FAM: ads1298, FN: modify_misc_config, REG: CONFIG4 (conf::MiscConfig <=> conf::Config4Reg), SET: set_misc_config);
+    };
+}
+
+// Channels 5 and 6 of the ADS1296/8
+macro_rules! ads1298_chan_5_6_registers {
+    ($($target:tt)*) => {
+        read_reg!($($target)* FAM: ads1298, FN: chan_5, REG: CH5SET (chan::Chan <= chan::ChanSetReg), SHADOW);
+        read_reg!($($target)* FAM: ads1298, FN: chan_6, REG: CH6SET (chan::Chan <= chan::ChanSetReg), SHADOW);
+
+        write_reg!($($target)* FAM: ads1298, FN: set_chan_5, REG: CH5SET (chan::Chan => chan::ChanSetReg));
+        write_reg!($($target)* FAM: ads1298, FN: set_chan_6, REG: CH6SET (chan::Chan => chan::ChanSetReg));
+    };
+}
+
+// Channels 7 and 8 of the ADS1298
+macro_rules! ads1298_chan_7_8_registers {
+    ($($target:tt)*) => {
+        read_reg!($($target)* FAM: ads1298, FN: chan_7, REG: CH7SET (chan::Chan <= chan::ChanSetReg), SHADOW);
+        read_reg!($($target)* FAM: ads1298, FN: chan_8, REG: CH8SET (chan::Chan <= chan::ChanSetReg), SHADOW);
+        write_reg!($($target)* FAM: ads1298, FN: set_chan_7, REG: CH7SET (chan::Chan => chan::ChanSetReg));
+        write_reg!($($target)* FAM: ads1298, FN: set_chan_8, REG: CH8SET (chan::Chan => chan::ChanSetReg));
+    };
+}
+
+// ADS129xR respiration register
+macro_rules! ads1298r_registers {
+    ($($target:tt)*) => {
+        read_reg!($($target)* FAM: ads1298, FN: resp, REG: RESP (resp::Resp <= resp::RespReg));
+        write_reg!($($target)* FAM: ads1298, FN: set_resp, REG: RESP (resp::Resp => resp::RespReg));
+        modify_reg!($($target)* FAM: ads1298, FN: modify_resp, REG: RESP (resp::Resp <=> resp::RespReg), SET: set_resp);
+    };
+}
@@ -0,0 +1,62 @@
+//! Respiration variant markers
+//!
+//! The R variants (ADS1292R, ADS1294R, ADS1296R, ADS1298R) add the
+//! respiration circuitry. Drivers from the `new_ads129*r` constructors carry
+//! [`R`] in their family type and are the only ones offering the RESP
+//! register accessors and the respiration helpers. The plain constructors
+//! drive R variants too, without respiration.
+//!
+//! `read_id` and `verify_device` of an [`R`] driver fail with
+//! [`VariantMismatch`](crate::Ads129xError::VariantMismatch) on a device
+//! without the circuitry.
+//!
+//! ```ignore
+//! let mut ads = Ads129x::new_ads1298r(spi, ncs).set_command_mode(&mut delay)?;
+//! ads.verify_device(&mut delay, 3)?;
+//! ads.set_resp(resp, &mut delay)?;
+//! ```
+
+use crate::{Ads1292Family, Ads1298Family};
+
+/// Device driven without respiration
+#[derive(Debug)]
+pub struct Standard;
+
+/// R variant with the respiration circuitry
+#[derive(Debug)]
+pub struct R;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Standard {}
+    impl Sealed for super::R {}
+}
+
+/// Respiration variant typestate
+pub trait Variant: sealed::Sealed {
+    /// The device must have the respiration circuitry
+    const RESPIRATION: bool;
+}
+
+impl Variant for Standard {
+    const RESPIRATION: bool = false;
+}
+
+impl Variant for R {
+    const RESPIRATION: bool = true;
+}
+
+/// Variant of a device family marker
+#[doc(hidden)]
+pub trait FamilyVariant {
+    const RESPIRATION: bool;
+}
+
+impl<V: Variant> FamilyVariant for Ads1292Family<V> {
+    const RESPIRATION: bool = V::RESPIRATION;
+}
+
+impl<V: Variant> FamilyVariant for Ads1298Family<V> {
+    const RESPIRATION: bool = V::RESPIRATION;
+}
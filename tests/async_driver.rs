@@ -0,0 +1,197 @@
+#![cfg(feature = "async")]
+
+use core::convert::Infallible;
+use std::collections::VecDeque;
+
+use embedded_hal_1::spi::ErrorType;
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::digital::Wait;
+use embedded_hal_async::spi::{Operation, SpiDevice};
+
+use ads129x::ads1298::conf::*;
+use ads129x::asynch::Ads129xAsync;
+use ads129x::common::id::DevModel;
+use ads129x::data::DataFrame;
+use ads129x::{timing, Ads129xError};
+
+/// Expected transaction: bytes shifted out, bytes shifted in
+type Expectation = (Vec<u8>, Vec<u8>);
+
+/// SPI device replaying one expectation per transaction
+struct MockDevice(VecDeque<Expectation>);
+
+impl ErrorType for MockDevice {
+    type Error = Infallible;
+}
+
+impl SpiDevice for MockDevice {
+    async fn transaction(
+        &mut self,
+        operations: &mut [Operation<'_, u8>],
+    ) -> Result<(), Infallible> {
+        let (tx, rx) = self.0.pop_front().expect("unexpected transaction");
+        match operations {
+            [Operation::Write(words)] => assert_eq!(*words, &tx[..]),
+            [Operation::TransferInPlace(words)] => {
+                assert_eq!(*words, &tx[..]);
+                words.copy_from_slice(&rx);
+            }
+            _ => panic!("unexpected operations"),
+        }
+        Ok(())
+    }
+}
+
+/// Delay recording awaited waits
+#[derive(Default)]
+struct RecDelay(Vec<u32>);
+
+impl DelayNs for RecDelay {
+    async fn delay_ns(&mut self, ns: u32) {
+        self.0.push(ns / 1_000);
+    }
+}
+
+/// DRDY pin whose edges are already there
+struct ReadyDrdy;
+
+impl embedded_hal_1::digital::ErrorType for ReadyDrdy {
+    type Error = Infallible;
+}
+
+impl Wait for ReadyDrdy {
+    async fn wait_for_high(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    async fn wait_for_low(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    async fn wait_for_any_edge(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+}
+
+fn write(words: &[u8]) -> Expectation {
+    (words.to_vec(), Vec::new())
+}
+
+fn transfer(tx: &[u8], rx: &[u8]) -> Expectation {
+    (tx.to_vec(), rx.to_vec())
+}
+
+#[test]
+fn async_driver() {
+    let mut frame = [0x00; 15];
+    frame[0] = 0xC0;
+    frame[5] = 0x01;
+    let expectations = vec![
+        write(&[0x06]),
+        write(&[0x11]),
+        transfer(&[0x20, 0x00, 0xA5], &[0x00, 0x00, 0x90]),
+        write(&[0x41, 0x00, 0b0000_0110]),
+        transfer(&[0x21, 0x00, 0xA5], &[0x00, 0x00, 0b0000_0110]),
+        write(&[0x10]),
+        transfer(&[0x00; 15], &frame),
+        transfer(&[0x00; 15], &[0x00; 15]),
+    ];
+
+    let mut delay = RecDelay::default();
+    let mut ads1294 = Ads129xAsync::new_ads1294(MockDevice(expectations.into()));
+    let ads1294 = pollster::block_on(async {
+        ads1294.reset_device(&mut delay).await.unwrap();
+        let mut ads1294 = ads1294.set_command_mode(&mut delay).await.unwrap();
+        assert_eq!(
+            ads1294.read_id(&mut delay).await.unwrap(),
+            DevModel::Ads1294
+        );
+        ads1294
+            .set_config(Config::default(), &mut delay)
+            .await
+            .unwrap();
+        assert_eq!(ads1294.config(&mut delay).await.unwrap(), Config::default());
+        // Starts from the written value, unchanged so nothing goes out
+        ads1294
+            .modify_config(|config| config.osc_clock_output = false, &mut delay)
+            .await
+            .unwrap();
+        let mut ads1294 = ads1294.set_continuous_mode(&mut delay).await.unwrap();
+
+        let mut data_frame = DataFrame::<4>::new();
+        ads1294
+            .read_data_on_drdy(&mut ReadyDrdy, &mut data_frame)
+            .await
+            .unwrap();
+        assert_eq!(data_frame.data, [1, 0, 0, 0]);
+        assert!(matches!(
+            ads1294.read_data(&mut data_frame).await,
+            Err(Ads129xError::StatusWordMissmatch { .. })
+        ));
+        ads1294
+    });
+
+    assert_eq!(delay.0, [
+        timing::RESET_RECOVERY_US,
+        timing::DECODE_US,
+        timing::DECODE_US,
+        timing::DECODE_US,
+        timing::DECODE_US,
+        timing::DECODE_US,
+    ]);
+    assert!(ads1294.destroy().0.is_empty());
+}
+
+#[test]
+fn async_write_verification() {
+    let expectations = vec![
+        write(&[0x11]),
+        write(&[0x41, 0x00, 0b0000_0110]),
+        transfer(&[0x21, 0x00, 0xA5], &[0x00, 0x00, 0b0000_0101]),
+    ];
+
+    let mut delay = RecDelay::default();
+    let ads1294 = Ads129xAsync::new_ads1294(MockDevice(expectations.into()));
+    let ads1294 = pollster::block_on(async {
+        let mut ads1294 = ads1294.set_command_mode(&mut delay).await.unwrap();
+        ads1294.set_write_verification(true);
+        assert!(matches!(
+            ads1294.set_config(Config::default(), &mut delay).await,
+            Err(Ads129xError::WriteVerifyFailed {
+                register: 0x01,
+                wrote:    0b0000_0110,
+                read:     0b0000_0101,
+            })
+        ));
+        ads1294
+    });
+    assert!(ads1294.destroy().0.is_empty());
+}
+
+#[test]
+fn async_dynamic_access() {
+    let expectations = vec![
+        // Register access in continuous mode stops it first
+        write(&[0x11]),
+        transfer(&[0x20, 0x00, 0xA5], &[0x00, 0x00, 0x90]),
+        transfer(&[0x20, 0x00, 0xA5], &[0x00, 0x00, 0x90]),
+    ];
+
+    let mut delay = RecDelay::default();
+    let mut ads1294 = Ads129xAsync::new_ads1294(MockDevice(expectations.into())).into_dynamic();
+    pollster::block_on(async {
+        assert!(ads1294.is_continuous());
+        ads1294.read_id(&mut delay).await.unwrap();
+        assert!(!ads1294.is_continuous());
+        ads1294.read_id(&mut delay).await.unwrap();
+    });
+    assert!(ads1294.destroy().0.is_empty());
+}
@@ -1,12 +1,23 @@
+use std::cell::RefCell;
+use std::convert::TryFrom;
+use std::ops::ControlFlow;
+
 use embedded_hal::blocking::delay::DelayUs;
-use embedded_hal::digital::v2::OutputPin;
+use embedded_hal::digital::v2::{InputPin, OutputPin};
 use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
 
 use ads129x::ads1298::chan::*;
 use ads129x::ads1298::conf::*;
 use ads129x::ads1298::gpio::*;
 use ads129x::ads1298::loff::*;
-use ads129x::Ads129x;
+use ads129x::ads1298::pace::*;
+use ads129x::ads1298::resp::*;
+use ads129x::ads1298::rld::*;
+use ads129x::ads1298::wct::*;
+use ads129x::common::ClockSource;
+use ads129x::data::DataFrame;
+use ads129x::spi::CsTiming;
+use ads129x::{timing, Ads129x, Ads129xError};
 
 struct MockNcs;
 
@@ -22,12 +33,52 @@ impl OutputPin for MockNcs {
     }
 }
 
+/// Input pin replaying scripted levels, one per read
+struct ScriptPin(RefCell<std::vec::IntoIter<bool>>);
+
+impl InputPin for ScriptPin {
+    type Error = core::convert::Infallible;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.0.borrow_mut().next().unwrap())
+    }
+
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        self.is_high().map(|high| !high)
+    }
+}
+
+struct BrokenPin;
+
+impl InputPin for BrokenPin {
+    type Error = ();
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        Err(())
+    }
+
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        Err(())
+    }
+}
+
 struct MockDelay;
 
 impl DelayUs<u32> for MockDelay {
     fn delay_us(&mut self, _us: u32) {}
 }
 
+/// Delay recording waits other than the nCS framing ones
+struct RecDelay<'a>(&'a RefCell<Vec<u32>>);
+
+impl DelayUs<u32> for RecDelay<'_> {
+    fn delay_us(&mut self, us: u32) {
+        if ![20, 40].contains(&us) {
+            self.0.borrow_mut().push(us);
+        }
+    }
+}
+
 #[test]
 fn test() {
     // Configure expectations
@@ -65,8 +116,9 @@ fn test() {
 
     let spi = SpiMock::new(&expectations);
 
-    let mut ads1298 = Ads129x::new_ads1298(spi, ncs);
-    ads1298.set_command_mode(MockDelay).unwrap();
+    let mut ads1298 = Ads129x::new_ads1298(spi, ncs)
+        .set_command_mode(MockDelay)
+        .unwrap();
 
     // Basic setup
 
@@ -194,3 +246,1935 @@ fn test() {
     let (mut spi, _) = ads1298.destroy();
     spi.done();
 }
+
+#[test]
+fn raw_command() {
+    let expectations = [SpiTransaction::write(vec![0x1B])];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs);
+
+    ads1298.send_command_raw(0x1B, MockDelay).unwrap();
+
+    // RREG/WREG ranges never reach the bus
+    for opcode in [0x20, 0x3F, 0x40, 0x5F].iter() {
+        assert!(matches!(
+            ads1298.send_command_raw(*opcode, MockDelay),
+            Err(Ads129xError::InvalidArgument(_))
+        ));
+    }
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn chans_range() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // CH3SET..CH6SET burst write
+        SpiTransaction::write(vec![0x47, 0x03]),
+        SpiTransaction::write(vec![0x40, 0x40, 0x81, 0x05]),
+        // CH2SET..CH3SET burst read
+        SpiTransaction::write(vec![0x26, 0x01]),
+        SpiTransaction::transfer(vec![0x00, 0x00], vec![0x60, 0x81]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    let chan = Chan::PowerUp {
+        gain:  ChannelGain::X4,
+        input: ChannelInput::Normal,
+    };
+    let test = Chan::PowerUp {
+        gain:  ChannelGain::X6,
+        input: ChannelInput::TestSig,
+    };
+    ads1298
+        .write_chans_range(2, &[chan, chan, Chan::PowerDown, test], MockDelay)
+        .unwrap();
+
+    let mut chans = [Chan::default(); 2];
+    ads1298.read_chans_range(1, &mut chans, MockDelay).unwrap();
+    assert_eq!(
+        chans,
+        [
+            Chan::PowerUp {
+                gain:  ChannelGain::X12,
+                input: ChannelInput::Normal,
+            },
+            Chan::PowerDown
+        ]
+    );
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn chans_range_out_of_bounds() {
+    let spi = SpiMock::new(&[SpiTransaction::write(vec![0x11])]);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    assert!(matches!(
+        ads1294.write_chans_range(2, &[Chan::PowerDown; 3], MockDelay),
+        Err(Ads129xError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        ads1294.read_chans_range(0, &mut [], MockDelay),
+        Err(Ads129xError::InvalidArgument(_))
+    ));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+fn rdata(samples: [i32; 4]) -> SpiTransaction {
+    let mut tx = vec![0x00; 16];
+    tx[0] = 0x12;
+    let mut rx = vec![0x00, 0xC0, 0x00, 0x00];
+    for sample in samples {
+        rx.extend_from_slice(&sample.to_be_bytes()[1..]);
+    }
+    SpiTransaction::transfer(tx, rx)
+}
+
+#[test]
+fn set_all_channels() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // CH1SET..CH4SET only
+        SpiTransaction::write(vec![0x45, 0x03]),
+        SpiTransaction::write(vec![0x40, 0x40, 0x40, 0x40]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    let chan = Chan::PowerUp {
+        gain:  ChannelGain::X4,
+        input: ChannelInput::Normal,
+    };
+    ads1294.set_all_channels(chan, MockDelay).unwrap();
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn measure_noise() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // X6 normal, powered down, X12 normal, X6 test signal
+        SpiTransaction::write(vec![0x25, 0x03]),
+        SpiTransaction::transfer(vec![0x00; 4], vec![0x00, 0x81, 0x60, 0x05]),
+        // 250 SPS, 4 V reference
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0110]),
+        SpiTransaction::transfer(vec![0x23, 0x00, 0xA5], vec![0x00, 0x00, 0b1110_0000]),
+        SpiTransaction::write(vec![0x45, 0x03]),
+        SpiTransaction::write(vec![0x01, 0x81, 0x61, 0x01]),
+        SpiTransaction::write(vec![0x08]),
+        rdata([10, 0, -5, 0]),
+        rdata([12, 0, -5, 0]),
+        rdata([14, 0, -5, 0]),
+        SpiTransaction::write(vec![0x0A]),
+        SpiTransaction::write(vec![0x45, 0x03]),
+        SpiTransaction::write(vec![0x00, 0x81, 0x60, 0x05]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    // DRDY polled before each RDATA
+    let drdy = ScriptPin(RefCell::new(
+        vec![true, false, false, true, false].into_iter(),
+    ));
+    let delays = RefCell::new(Vec::new());
+    let report = ads1294.measure_noise(&drdy, 3, RecDelay(&delays)).unwrap();
+    assert_eq!(delays.into_inner(), [timing::DRDY_POLL_US; 2]);
+
+    assert_eq!(report.frames, 3);
+    assert_eq!(report.channels[1], None);
+    let ch1 = report.channels[0].unwrap();
+    assert_eq!((ch1.gain, ch1.offset, ch1.noise), (ChannelGain::X6, 12, 1));
+    // One LSB is 8 V / 6 / 2^24
+    assert!((ch1.offset_uv - 0.953_674).abs() < 1e-4);
+    assert!((ch1.noise_uv - 0.079_473).abs() < 1e-4);
+    let ch3 = report.channels[2].unwrap();
+    assert_eq!((ch3.gain, ch3.offset, ch3.noise), (ChannelGain::X12, -5, 0));
+
+    assert!(matches!(
+        ads1294.measure_noise(&drdy, 0, MockDelay),
+        Err(Ads129xError::InvalidArgument(_))
+    ));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn measure_noise_restores_channels() {
+    let bad_frame = vec![0x00; 16];
+    let mut tx = vec![0x00; 16];
+    tx[0] = 0x12;
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x25, 0x03]),
+        SpiTransaction::transfer(vec![0x00; 4], vec![0x00, 0x00, 0x00, 0x00]),
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0110]),
+        SpiTransaction::transfer(vec![0x23, 0x00, 0xA5], vec![0x00, 0x00, 0b0100_0000]),
+        SpiTransaction::write(vec![0x45, 0x03]),
+        SpiTransaction::write(vec![0x01, 0x01, 0x01, 0x01]),
+        SpiTransaction::write(vec![0x08]),
+        // Status word out of sync
+        SpiTransaction::transfer(tx, bad_frame),
+        SpiTransaction::write(vec![0x0A]),
+        SpiTransaction::write(vec![0x45, 0x03]),
+        SpiTransaction::write(vec![0x00, 0x00, 0x00, 0x00]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    let drdy = ScriptPin(RefCell::new(vec![false].into_iter()));
+    assert!(matches!(
+        ads1294.measure_noise(&drdy, 10, MockDelay),
+        Err(Ads129xError::StatusWordMissmatch { .. })
+    ));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn measure_noise_timeout() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x25, 0x03]),
+        SpiTransaction::transfer(vec![0x00; 4], vec![0x00, 0x00, 0x00, 0x00]),
+        // 250 SPS
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0110]),
+        SpiTransaction::transfer(vec![0x23, 0x00, 0xA5], vec![0x00, 0x00, 0b0100_0000]),
+        SpiTransaction::write(vec![0x45, 0x03]),
+        SpiTransaction::write(vec![0x01, 0x01, 0x01, 0x01]),
+        SpiTransaction::write(vec![0x08]),
+        SpiTransaction::write(vec![0x0A]),
+        SpiTransaction::write(vec![0x45, 0x03]),
+        SpiTransaction::write(vec![0x00, 0x00, 0x00, 0x00]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    // DRDY stays high past the settling time
+    let polls = (timing::settling_time_us(250) / timing::DRDY_POLL_US + 2) as usize;
+    let drdy = ScriptPin(RefCell::new(vec![true; polls].into_iter()));
+    assert!(matches!(
+        ads1294.measure_noise(&drdy, 3, MockDelay),
+        Err(Ads129xError::Timeout)
+    ));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn leadoff() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x44, 0x00, 0b0000_0111]),
+        SpiTransaction::write(vec![0x4F, 0x02]),
+        SpiTransaction::write(vec![0x03, 0x01, 0x00]),
+        SpiTransaction::transfer(vec![0x37, 0x00, 0xA5], vec![0x00, 0x00, 0x00]),
+        SpiTransaction::write(vec![0x57, 0x00, 0x02]),
+        SpiTransaction::transfer(vec![0x23, 0x00, 0xA5], vec![0x00, 0x00, 0x40]),
+        SpiTransaction::write(vec![0x43, 0x00, 0x42]),
+        // LOFF_SENSP..LOFF_STATN, IN2P off
+        SpiTransaction::write(vec![0x2F, 0x04]),
+        SpiTransaction::transfer(vec![0x00; 5], vec![0x03, 0x01, 0x00, 0x02, 0x00]),
+        // RLD off
+        SpiTransaction::transfer(vec![0x23, 0x00, 0xA5], vec![0x00, 0x00, 0x43]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    let setup = LeadOffSetup {
+        control: LeadOffControl {
+            frequency: LeadOffFreq::DC,
+            magnitude: LeadOffMagnitude::nA_12,
+            ..Default::default()
+        },
+        sense_positive: LeadOffSense {
+            ch1_enable: true,
+            ch2_enable: true,
+            ..Default::default()
+        },
+        sense_negative: LeadOffSense {
+            ch1_enable: true,
+            ..Default::default()
+        },
+        comparators_enable: true,
+        rld_sense: true,
+        ..Default::default()
+    };
+    ads1294.enable_leadoff(setup, MockDelay).unwrap();
+
+    let state = ads1294.leadoff_state(MockDelay).unwrap();
+    use ElectrodeState::*;
+    assert_eq!(state.positive[..3], [Connected, Disconnected, Unmonitored]);
+    assert_eq!(state.negative[..2], [Connected, Unmonitored]);
+    assert_eq!(state.rld, Disconnected);
+    assert!(!state.all_connected());
+
+    // Same flags from a frame, without RLD
+    let mut frame = DataFrame::<4>::new();
+    frame.status_word = [0xC0, 0x20, 0x00];
+    let state = LeadOffState::from_status(&frame.status(), &setup);
+    assert_eq!(state.positive[..2], [Connected, Disconnected]);
+    assert_eq!(state.rld, Unmonitored);
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn leadoff_misconfigured() {
+    let spi = SpiMock::new(&[SpiTransaction::write(vec![0x11])]);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    let sensed = LeadOffSetup {
+        control: LeadOffControl {
+            frequency: LeadOffFreq::AC,
+            ..Default::default()
+        },
+        sense_positive: LeadOffSense {
+            ch1_enable: true,
+            ..Default::default()
+        },
+        comparators_enable: true,
+        ..Default::default()
+    };
+    let no_comparators = LeadOffSetup {
+        comparators_enable: false,
+        ..sensed
+    };
+    let no_detection = LeadOffSetup {
+        control: LeadOffControl::default(),
+        ..sensed
+    };
+    let missing_channel = LeadOffSetup {
+        sense_negative: LeadOffSense {
+            ch5_enable: true,
+            ..Default::default()
+        },
+        ..sensed
+    };
+    let missing_flip = LeadOffSetup {
+        flip: LeadOffFlip {
+            ch8_flip: true,
+            ..Default::default()
+        },
+        ..sensed
+    };
+    for setup in [no_comparators, no_detection, missing_channel, missing_flip] {
+        assert!(matches!(
+            ads1294.enable_leadoff(setup, MockDelay),
+            Err(Ads129xError::InvalidArgument(_))
+        ));
+    }
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn clock_output() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // Enable: read-modify-write CONFIG1
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b1000_0110]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b1010_0110]),
+        // Disable
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b1010_0110]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b1000_0110]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    let waits = RefCell::new(Vec::new());
+    ads1298.enable_clock_output(RecDelay(&waits)).unwrap();
+    assert_eq!(*waits.borrow(), [timing::CLK_OUTPUT_SETTLE_US]);
+
+    waits.borrow_mut().clear();
+    ads1298.disable_clock_output(RecDelay(&waits)).unwrap();
+    assert!(waits.borrow().is_empty());
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+
+    let source = Config {
+        osc_clock_output: true,
+        ..Default::default()
+    };
+    assert!(single_clock_source(&[source, Config::default()]));
+    assert!(!single_clock_source(&[source, source]));
+    assert!(!single_clock_source(&[Config::default(); 2]));
+}
+
+#[test]
+fn multiple_readback() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b1000_0110]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b1100_0110]),
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b1100_0110]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1298.configure_multiple_readback(MockDelay).unwrap();
+    assert_eq!(
+        ads1298.readback_mode(MockDelay).unwrap(),
+        ReadbackMode::MultipleReadback
+    );
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn multiple_readback_all() {
+    let rmw = |read_back: u8| {
+        [
+            SpiTransaction::write(vec![0x11]),
+            SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b1000_0110]),
+            SpiTransaction::write(vec![0x41, 0x00, 0b1100_0110]),
+            SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, read_back]),
+        ]
+    };
+
+    let mut devices = [
+        Ads129x::new_ads1294(SpiMock::new(&rmw(0b1100_0110)), MockNcs)
+            .set_command_mode(MockDelay)
+            .unwrap(),
+        Ads129x::new_ads1294(SpiMock::new(&rmw(0b1100_0110)), MockNcs)
+            .set_command_mode(MockDelay)
+            .unwrap(),
+    ];
+    Ads129x::configure_multiple_readback_all(&mut devices, MockDelay).unwrap();
+    for device in devices {
+        device.destroy().0.done();
+    }
+
+    // Second device didn't take the write
+    let mut devices = [
+        Ads129x::new_ads1294(SpiMock::new(&rmw(0b1100_0110)), MockNcs)
+            .set_command_mode(MockDelay)
+            .unwrap(),
+        Ads129x::new_ads1294(SpiMock::new(&rmw(0b1000_0110)), MockNcs)
+            .set_command_mode(MockDelay)
+            .unwrap(),
+    ];
+    assert!(matches!(
+        Ads129x::configure_multiple_readback_all(&mut devices, MockDelay),
+        Err(Ads129xError::WriteVerifyFailed {
+            register: 0x01,
+            wrote:    0b1100_0110,
+            read:     0b1000_0110,
+        })
+    ));
+    for device in devices {
+        device.destroy().0.done();
+    }
+}
+
+#[test]
+fn daisy_chain() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // Clock master: DAISY_EN and CLK_EN set
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b1100_0110]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b1010_0110]),
+        // Downstream: CLK_EN cleared, starting from the written value
+        SpiTransaction::write(vec![0x41, 0x00, 0b1000_0110]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    // Downstream devices run from the master's clock
+    assert!(matches!(
+        ads1298.configure_daisy_chain(DaisyRole::Downstream, MockDelay),
+        Err(Ads129xError::NotSupported { .. })
+    ));
+    ads1298
+        .configure_daisy_chain(DaisyRole::ClockMaster, MockDelay)
+        .unwrap();
+    ads1298.set_clock_source(ClockSource::ExternalClock { hz: 2_048_000 });
+    ads1298
+        .configure_daisy_chain(DaisyRole::Downstream, MockDelay)
+        .unwrap();
+
+    // No clock to output from an external clock
+    assert!(matches!(
+        ads1298.configure_daisy_chain(DaisyRole::ClockMaster, MockDelay),
+        Err(Ads129xError::NotSupported { .. })
+    ));
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn daisy_chain_all() {
+    let rmw = |wrote: u8, read_back: u8| {
+        [
+            SpiTransaction::write(vec![0x11]),
+            SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b1100_0110]),
+            SpiTransaction::write(vec![0x41, 0x00, wrote]),
+            SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, read_back]),
+        ]
+    };
+
+    let mut devices = [
+        Ads129x::new_ads1294(SpiMock::new(&rmw(0b1010_0110, 0b1010_0110)), MockNcs)
+            .set_command_mode(MockDelay)
+            .unwrap(),
+        Ads129x::new_ads1294(SpiMock::new(&rmw(0b1000_0110, 0b1000_0110)), MockNcs)
+            .set_command_mode(MockDelay)
+            .unwrap(),
+    ];
+    devices[1].set_clock_source(ClockSource::ExternalClock { hz: 2_048_000 });
+    Ads129x::configure_daisy_chain_all(&mut devices, MockDelay).unwrap();
+    for device in devices {
+        device.destroy().0.done();
+    }
+
+    // Second device stayed in multiple readback
+    let mut devices = [
+        Ads129x::new_ads1294(SpiMock::new(&rmw(0b1010_0110, 0b1010_0110)), MockNcs)
+            .set_command_mode(MockDelay)
+            .unwrap(),
+        Ads129x::new_ads1294(SpiMock::new(&rmw(0b1000_0110, 0b1100_0110)), MockNcs)
+            .set_command_mode(MockDelay)
+            .unwrap(),
+    ];
+    devices[1].set_clock_source(ClockSource::ExternalClock { hz: 2_048_000 });
+    assert!(matches!(
+        Ads129x::configure_daisy_chain_all(&mut devices, MockDelay),
+        Err(Ads129xError::WriteVerifyFailed {
+            register: 0x01,
+            wrote:    0b1000_0110,
+            read:     0b1100_0110,
+        })
+    ));
+    for device in devices {
+        device.destroy().0.done();
+    }
+}
+
+#[test]
+fn read_data_with() {
+    let mut expectations = Vec::new();
+    let frames = [
+        [
+            0xC0, 0x12, 0x34, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFE, 0x7F, 0xFF, 0xFF, 0x80, 0x00,
+            0x00,
+        ],
+        // Sync mismatch
+        [
+            0x40, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00,
+            0x01,
+        ],
+    ];
+    for frame in frames.iter() {
+        expectations.push(SpiTransaction::transfer(
+            vec![0x00; frame.len()],
+            frame.to_vec(),
+        ));
+    }
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+
+    let events = RefCell::new(Vec::new());
+    ads1294
+        .read_data_with(
+            MockDelay,
+            |ch, sample| events.borrow_mut().push((Some(ch), sample)),
+            |status| events.borrow_mut().push((None, status.0 as i32)),
+        )
+        .unwrap();
+    assert_eq!(*events.borrow(), [
+        (None, 0xC0_1234),
+        (Some(0), 1),
+        (Some(1), -2),
+        (Some(2), 0x7F_FFFF),
+        (Some(3), -0x80_0000),
+    ]);
+
+    events.borrow_mut().clear();
+    assert!(matches!(
+        ads1294.read_data_with(
+            MockDelay,
+            |ch, sample| events.borrow_mut().push((Some(ch), sample)),
+            |status| events.borrow_mut().push((None, status.0 as i32)),
+        ),
+        Err(Ads129xError::StatusWordMissmatch {
+            raw: [0x40, 0x00, 0x00],
+        })
+    ));
+    assert_eq!(*events.borrow(), [(None, 0x40_0000)]);
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn read_data_into() {
+    let mut expectations = Vec::new();
+    let frames = [
+        [
+            0xC8, 0x01, 0x23, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFE, 0x7F, 0xFF, 0xFF, 0x80, 0x00,
+            0x00,
+        ],
+        // Sync mismatch
+        [
+            0x40, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x02, 0x00, 0x00, 0x02, 0x00, 0x00,
+            0x02,
+        ],
+    ];
+    for frame in frames.iter() {
+        expectations.push(SpiTransaction::transfer(
+            vec![0x00; frame.len()],
+            frame.to_vec(),
+        ));
+    }
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+
+    let mut samples = [0; 4];
+    let status = ads1294.read_data_into(&mut samples, MockDelay).unwrap();
+    assert_eq!(status.0, 0xC8_0123);
+    assert_eq!(status.loff_statp(), 0x80);
+    assert_eq!(status.gpio(), 0x3);
+    assert_eq!(samples, [1, -2, 0x7F_FFFF, -0x80_0000]);
+
+    assert!(matches!(
+        ads1294.read_data_into(&mut samples, MockDelay),
+        Err(Ads129xError::StatusWordMissmatch {
+            raw: [0x40, 0x00, 0x00],
+        })
+    ));
+    assert_eq!(samples, [1, -2, 0x7F_FFFF, -0x80_0000]);
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn read_data_timestamped() {
+    use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+    static NCS_LOW: AtomicBool = AtomicBool::new(false);
+    static CALLS: AtomicU64 = AtomicU64::new(0);
+
+    struct TrackedNcs;
+
+    impl OutputPin for TrackedNcs {
+        type Error = core::convert::Infallible;
+
+        fn set_low(&mut self) -> Result<(), Self::Error> {
+            NCS_LOW.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn set_high(&mut self) -> Result<(), Self::Error> {
+            NCS_LOW.store(false, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn now() -> u64 {
+        assert!(NCS_LOW.load(Ordering::SeqCst));
+        1000 + CALLS.fetch_add(1, Ordering::SeqCst)
+    }
+
+    let mut expectations = Vec::new();
+    let frames = [
+        [
+            0xC0, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x02, 0x00, 0x00, 0x03, 0x00, 0x00,
+            0x04,
+        ],
+        [
+            0xC0, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x06, 0x00, 0x00, 0x07, 0x00, 0x00,
+            0x08,
+        ],
+    ];
+    for frame in frames.iter() {
+        expectations.push(SpiTransaction::transfer(
+            vec![0x00; frame.len()],
+            frame.to_vec(),
+        ));
+    }
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, TrackedNcs);
+
+    assert!(matches!(
+        ads1294.read_data_timestamped(MockDelay),
+        Err(Ads129xError::NotSupported { .. })
+    ));
+    assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+    ads1294.set_timestamp_source(now);
+    let first = ads1294.read_data_timestamped(MockDelay).unwrap();
+    assert_eq!(first.timestamp, 1000);
+    assert_eq!(first.frame.data, [1, 2, 3, 4]);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+    let second = ads1294.read_data_timestamped(MockDelay).unwrap();
+    assert_eq!(second.timestamp, 1001);
+    assert_eq!(second.frame.data, [5, 6, 7, 8]);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+    assert!(!NCS_LOW.load(Ordering::SeqCst));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn read_frames() {
+    let frames = [
+        [
+            0xC0, 0x00, 0x00, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFE, 0x7F, 0xFF, 0xFF, 0x80, 0x00,
+            0x00,
+        ],
+        // Sync mismatch
+        [
+            0x40, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x02, 0x00, 0x00, 0x02, 0x00, 0x00,
+            0x02,
+        ],
+        [
+            0xC0, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x03, 0x00, 0x00, 0x03, 0x00, 0x00,
+            0x03,
+        ],
+    ];
+    // Third frame is never clocked out, the second one stops the burst
+    let mut expectations = Vec::new();
+    for frame in frames[..2].iter().chain(&frames[..2]) {
+        expectations.push(SpiTransaction::transfer(
+            vec![0x00; frame.len()],
+            frame.to_vec(),
+        ));
+    }
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+
+    let mut buf = [DataFrame::<4>::new(); 3];
+    assert_eq!(ads1294.read_frames(&mut buf[..1], MockDelay).unwrap(), 1);
+    assert_eq!(buf[0].data, [1, -2, 0x7F_FFFF, -0x80_0000]);
+    assert_eq!(ads1294.read_frames(&mut buf[..0], MockDelay).unwrap(), 0);
+    assert_eq!(ads1294.read_frames(&mut buf[1..], MockDelay).unwrap(), 0);
+    assert_eq!(buf[2].data, [0; 4]);
+
+    let mut buf = [DataFrame::<4>::new(); 3];
+    assert_eq!(ads1294.read_frames(&mut buf, MockDelay).unwrap(), 1);
+    assert_eq!(buf[0].data, [1, -2, 0x7F_FFFF, -0x80_0000]);
+    assert_eq!(buf[2].data, [0; 4]);
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn read_daisy_frames() {
+    let chain = [
+        0xC0, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x02, 0x00, 0x00, 0x03, 0x00, 0x00, 0x04,
+        0xC1, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0xFF, 0xFF, 0xFD, 0xFF, 0xFF, 0xFC,
+    ];
+    let mut expectations = Vec::new();
+    for _ in 0..2 {
+        for frame in chain.chunks(15) {
+            expectations.push(SpiTransaction::transfer(vec![0x00; 15], frame.to_vec()));
+        }
+    }
+    // Device 2 sync broken
+    expectations.push(SpiTransaction::transfer(
+        vec![0x00; 15],
+        chain[..15].to_vec(),
+    ));
+    expectations.push(SpiTransaction::transfer(vec![0x00; 15], vec![0x00; 15]));
+    // Multiple readback selected
+    expectations.push(SpiTransaction::write(vec![0x11]));
+    expectations.push(SpiTransaction::write(vec![0x41, 0x00, 0b0100_0110]));
+    expectations.push(SpiTransaction::write(vec![0x10]));
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+
+    let mut frames = [DataFrame::<4>::new(); 2];
+    ads1294.read_daisy_frames(&mut frames, MockDelay).unwrap();
+    assert_eq!(frames[0].data, [1, 2, 3, 4]);
+    assert_eq!(frames[1].status_word, [0xC1, 0x00, 0x00]);
+    assert_eq!(frames[1].data, [-1, -2, -3, -4]);
+
+    let mut frames = [DataFrame::<4>::new(); 2];
+    ads1294.read_daisy_frames(&mut frames, MockDelay).unwrap();
+    assert_eq!(frames[1].data, [-1, -2, -3, -4]);
+
+    assert!(matches!(
+        ads1294.read_daisy_frames(&mut frames, MockDelay),
+        Err(Ads129xError::StatusWordMissmatch { raw: [0, 0, 0] })
+    ));
+    assert_eq!(frames[0].data, [1, 2, 3, 4]);
+
+    let mut ads1294 = ads1294.set_command_mode(MockDelay).unwrap();
+    ads1294
+        .set_config(
+            Config {
+                daisy_chain: false,
+                ..Default::default()
+            },
+            MockDelay,
+        )
+        .unwrap();
+    let mut ads1294 = ads1294.set_continuous_mode(MockDelay).unwrap();
+    assert!(matches!(
+        ads1294.read_daisy_frames(&mut frames, MockDelay),
+        Err(Ads129xError::NotSupported { .. })
+    ));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn frame_validation() {
+    use ads129x::data::FrameValidation;
+
+    // Sync nibble 0b1000, e.g. a wrong SPI mode
+    let corrupted = [
+        0x8A, 0xBC, 0xDE, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFE, 0x00, 0x00, 0x03, 0xFF, 0xFF, 0xFC,
+    ];
+    let mut expectations = Vec::new();
+    for _ in 0..6 {
+        expectations.push(SpiTransaction::transfer(vec![0x00; 15], corrupted.to_vec()));
+    }
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+    let mut frame = DataFrame::new();
+
+    // Strict by default
+    assert!(matches!(
+        ads1294.read_data(&mut frame, MockDelay),
+        Err(Ads129xError::StatusWordMissmatch {
+            raw: [0x8A, 0xBC, 0xDE],
+        })
+    ));
+    let mut frames = [DataFrame::<4>::new(); 2];
+    assert_eq!(ads1294.read_frames(&mut frames, MockDelay).unwrap(), 0);
+
+    ads1294.set_frame_validation(FrameValidation::Lenient);
+    let mut frame = DataFrame::new();
+    ads1294.read_data(&mut frame, MockDelay).unwrap();
+    assert!(!frame.sync_ok());
+    assert_eq!(frame.data, [1, -2, 3, -4]);
+
+    let mut samples = [0; 4];
+    let status = ads1294.read_data_into(&mut samples, MockDelay).unwrap();
+    assert_eq!(status.sync(), 0b1000);
+    assert_eq!(samples, [1, -2, 3, -4]);
+
+    let mut frames = [DataFrame::<4>::new(); 2];
+    assert_eq!(ads1294.read_frames(&mut frames, MockDelay).unwrap(), 2);
+    assert!(frames.iter().all(|frame| !frame.sync_ok()));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn sample_format() {
+    use ads129x::data::SampleFormat;
+
+    let frame = [
+        0xC0, 0x00, 0x00, 0x80, 0x00, 0x00, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x01,
+    ];
+    let mut expectations = Vec::new();
+    for _ in 0..4 {
+        expectations.push(SpiTransaction::transfer(vec![0x00; 15], frame.to_vec()));
+    }
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+    let mut data_frame = DataFrame::new();
+
+    // Sign extended by default
+    ads1294.read_data(&mut data_frame, MockDelay).unwrap();
+    assert_eq!(data_frame.data, [-0x80_0000, 0x7F_FFFF, -1, 1]);
+    let mut samples = [0; 4];
+    ads1294.read_data_into(&mut samples, MockDelay).unwrap();
+    assert_eq!(samples, [-0x80_0000, 0x7F_FFFF, -1, 1]);
+
+    ads1294.set_sample_format(SampleFormat::LeftJustified);
+    ads1294.read_data(&mut data_frame, MockDelay).unwrap();
+    assert_eq!(data_frame.data, [i32::MIN, 0x7FFF_FF00, -0x100, 0x100]);
+    ads1294.read_data_into(&mut samples, MockDelay).unwrap();
+    assert_eq!(samples, [i32::MIN, 0x7FFF_FF00, -0x100, 0x100]);
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn poll_frame() {
+    let mut expectations = Vec::new();
+    let frame = [
+        0xC0, 0x00, 0x00, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFE, 0x7F, 0xFF, 0xFF, 0x80, 0x00, 0x00,
+    ];
+    for _ in 0..2 {
+        expectations.push(SpiTransaction::transfer(vec![0x00; 15], frame.to_vec()));
+    }
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+    let mut drdy = ScriptPin(RefCell::new(
+        vec![true, false, true, true, false].into_iter(),
+    ));
+
+    let mut data_frame = DataFrame::<4>::new();
+    let mut polls = Vec::new();
+    for _ in 0..5 {
+        polls.push(ads1294.poll_frame(&mut drdy, &mut data_frame));
+    }
+    assert!(matches!(polls.as_slice(), [
+        Err(nb::Error::WouldBlock),
+        Ok(()),
+        Err(nb::Error::WouldBlock),
+        Err(nb::Error::WouldBlock),
+        Ok(()),
+    ]));
+    assert_eq!(data_frame.data, [1, -2, 0x7F_FFFF, -0x80_0000]);
+
+    assert!(matches!(
+        ads1294.poll_frame(&mut BrokenPin, &mut data_frame),
+        Err(nb::Error::Other(Ads129xError::DrdyPin))
+    ));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn rld_sense() {
+    let sense = RldSense {
+        ch1_enable: true,
+        ch2_enable: true,
+        ch8_enable: true,
+        ..Default::default()
+    };
+    assert_eq!(RldSenseReg::from(sense).0, 0b1000_0011);
+    assert_eq!(RldSense::try_from(RldSenseReg(0b1000_0011)), Ok(sense));
+    for val in 0..=0xFF {
+        assert_eq!(
+            RldSenseReg::from(RldSense::try_from(RldSenseReg(val)).unwrap()).0,
+            val
+        );
+    }
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x4D, 0x00, 0b1000_0011]),
+        SpiTransaction::write(vec![0x4E, 0x00, 0b0000_0001]),
+        SpiTransaction::transfer(vec![0x2D, 0x00, 0xA5], vec![0x00, 0x00, 0b1000_0011]),
+        SpiTransaction::transfer(vec![0x2E, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0001]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    let negative = RldSense {
+        ch1_enable: true,
+        ..Default::default()
+    };
+    ads1298.set_rld_sense_positive(sense, MockDelay).unwrap();
+    ads1298.set_rld_sense_negative(negative, MockDelay).unwrap();
+    assert_eq!(ads1298.rld_sense_positive(MockDelay).unwrap(), sense);
+    assert_eq!(ads1298.rld_sense_negative(MockDelay).unwrap(), negative);
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn pace() {
+    let pace = Pace {
+        even_channel:  PaceEvenChannel::Ch6,
+        odd_channel:   PaceOddChannel::Ch3,
+        buffer_enable: true,
+    };
+    assert_eq!(PaceReg::from(pace).0, 0b0001_0011);
+    assert_eq!(Pace::try_from(PaceReg(0b0001_0011)), Ok(pace));
+    for val in 0..0b10_0000 {
+        assert_eq!(PaceReg::from(Pace::try_from(PaceReg(val)).unwrap()).0, val);
+    }
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x55, 0x00, 0b0001_0011]),
+        SpiTransaction::transfer(vec![0x35, 0x00, 0xA5], vec![0x00, 0x00, 0b0001_1110]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1298.set_pace(pace, MockDelay).unwrap();
+    assert_eq!(ads1298.pace(MockDelay).unwrap(), Pace {
+        even_channel:  PaceEvenChannel::Ch8,
+        odd_channel:   PaceOddChannel::Ch7,
+        buffer_enable: false,
+    });
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn resp() {
+    let resp = Resp {
+        demodulation_enable: true,
+        modulation_enable:   true,
+        phase:               RespPhase::Deg_135,
+        mode:                RespMode::Internal,
+    };
+    // Reserved bit 5 always written as 1
+    assert_eq!(RespReg::from(resp).0, 0b1111_0110);
+    assert_eq!(RespReg::from(Resp::default()).0, 0b0010_0000);
+    assert_eq!(Resp::try_from(RespReg(0b1111_0110)), Ok(resp));
+    // Phase 0b111 is not available
+    assert_eq!(Resp::try_from(RespReg(0b0011_1100)), Err(0b0011_1100));
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x56, 0x00, 0b1111_0110]),
+        SpiTransaction::transfer(vec![0x36, 0x00, 0xA5], vec![0x00, 0x00, 0b0010_0001]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298r(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1298.set_resp(resp, MockDelay).unwrap();
+    assert_eq!(ads1298.resp(MockDelay).unwrap(), Resp {
+        mode: RespMode::External,
+        ..Default::default()
+    });
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn wct1() {
+    let wct1 = Wct1 {
+        avf_ch6:     true,
+        avl_ch5:     false,
+        avr_ch7:     true,
+        avr_ch4:     false,
+        wcta_enable: true,
+        wcta:        WctInput::In2N,
+    };
+    assert_eq!(Wct1Reg::from(wct1).0, 0b1010_1011);
+    assert_eq!(Wct1::try_from(Wct1Reg(0b1010_1011)), Ok(wct1));
+    for val in 0..=0xFF {
+        assert_eq!(Wct1Reg::from(Wct1::try_from(Wct1Reg(val)).unwrap()).0, val);
+    }
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x58, 0x00, 0b1010_1011]),
+        SpiTransaction::transfer(vec![0x38, 0x00, 0xA5], vec![0x00, 0x00, 0b0001_0000]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1298.set_wct1(wct1, MockDelay).unwrap();
+    assert_eq!(ads1298.wct1(MockDelay).unwrap(), Wct1 {
+        avr_ch4: true,
+        ..Default::default()
+    });
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn wct2() {
+    let wct2 = Wct2 {
+        wctc_enable: true,
+        wctb_enable: false,
+        wctb:        WctInput::In3P,
+        wctc:        WctInput::In4N,
+    };
+    assert_eq!(Wct2Reg::from(wct2).0, 0b1010_0111);
+    assert_eq!(Wct2::try_from(Wct2Reg(0b1010_0111)), Ok(wct2));
+    for val in 0..=0xFF {
+        assert_eq!(Wct2Reg::from(Wct2::try_from(Wct2Reg(val)).unwrap()).0, val);
+    }
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x59, 0x00, 0b1010_0111]),
+        SpiTransaction::transfer(vec![0x39, 0x00, 0xA5], vec![0x00, 0x00, 0b0101_1001]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1298.set_wct2(wct2, MockDelay).unwrap();
+    assert_eq!(ads1298.wct2(MockDelay).unwrap(), Wct2 {
+        wctc_enable: false,
+        wctb_enable: true,
+        wctb:        WctInput::In2N,
+        wctc:        WctInput::In1N,
+    });
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn wct_config() {
+    let config = WctConfig {
+        wcta: Some(WctInput::In1N),
+        wctb: Some(WctInput::In1P),
+        wctc: Some(WctInput::In2P),
+        avl_ch5: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        WctConfig::from_regs(config.to_regs().0, config.to_regs().1),
+        config
+    );
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // WCT1..WCT2 burst write
+        SpiTransaction::write(vec![0x58, 0x01]),
+        SpiTransaction::write(vec![0b0100_1001, 0b1100_0010]),
+        // WCT1..WCT2 burst read, WCTC powered down
+        SpiTransaction::write(vec![0x38, 0x01]),
+        SpiTransaction::transfer(vec![0x00, 0x00], vec![0b0000_1001, 0b0100_0010]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1298.apply_wct_config(config, MockDelay).unwrap();
+    assert_eq!(ads1298.read_wct_config(MockDelay).unwrap(), WctConfig {
+        wcta: Some(WctInput::In1N),
+        wctb: Some(WctInput::In1P),
+        ..Default::default()
+    });
+
+    // Same input twice, rejected before touching the bus
+    let twice = WctConfig {
+        wctc: Some(WctInput::In1N),
+        ..config
+    };
+    assert!(matches!(
+        ads1298.apply_wct_config(twice, MockDelay),
+        Err(Ads129xError::InvalidArgument(_))
+    ));
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn raw_register() {
+    use ads129x::ads1298::Register;
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x92]),
+        SpiTransaction::transfer(vec![0x32, 0x00, 0xA5], vec![0x00, 0x00, 0x81]),
+        SpiTransaction::write(vec![0x55, 0x00, 0x1F]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    assert_eq!(
+        ads1298.read_register(Register::ID, MockDelay).unwrap(),
+        0x92
+    );
+    assert_eq!(
+        ads1298
+            .read_register(Register::LOFF_STATP, MockDelay)
+            .unwrap(),
+        0x81
+    );
+    ads1298
+        .write_register(Register::PACE, 0x1F, MockDelay)
+        .unwrap();
+    assert!(matches!(
+        ads1298.write_register(Register::LOFF_STATN, 0x00, MockDelay),
+        Err(Ads129xError::InvalidArgument(_))
+    ));
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn verify_device() {
+    use ads129x::common::id::DevModel;
+
+    let delays = RefCell::new(Vec::new());
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // Garbage twice, then the ID
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0xFF]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x00]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x92]),
+        // Out of retries
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0xFF]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0xFF]),
+        // ADS1294 behind an 8 channel driver
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x90]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    assert_eq!(
+        ads1298.verify_device(RecDelay(&delays), 3).unwrap(),
+        DevModel::Ads1298
+    );
+    assert_eq!(*delays.borrow(), [timing::ID_RETRY_US; 2]);
+    assert!(matches!(
+        ads1298.verify_device(MockDelay, 1),
+        Err(Ads129xError::IdRegRead(_))
+    ));
+    assert!(matches!(
+        ads1298.verify_device(MockDelay, 3),
+        Err(Ads129xError::DeviceMismatch {
+            expected_channels: 8,
+            found:             DevModel::Ads1294,
+        })
+    ));
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn verify_device_variant() {
+    use ads129x::common::id::DevModel;
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0xD2]),
+        // ADS1298 behind an ADS1298R driver
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x92]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x92]),
+        // Plain driver accepts the R variant
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0xD2]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298r = Ads129x::new_ads1298r(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    assert_eq!(
+        ads1298r.verify_device(MockDelay, 0).unwrap(),
+        DevModel::Ads1298R
+    );
+    assert!(matches!(
+        ads1298r.read_id(MockDelay),
+        Err(Ads129xError::VariantMismatch {
+            found: DevModel::Ads1298,
+        })
+    ));
+    let err = ads1298r.verify_device(MockDelay, 3).unwrap_err();
+    assert_eq!(err.to_string(), "found Ads1298, expected an R variant");
+
+    let (spi, ncs) = ads1298r.destroy();
+    let mut ads1298 = Ads129x::new_ads1298(spi, ncs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+    assert_eq!(ads1298.read_id(MockDelay).unwrap(), DevModel::Ads1298R);
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn modify_register() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x43, 0x00, 0b1100_0000]),
+        // From the cache, PD_REFBUF kept
+        SpiTransaction::write(vec![0x43, 0x00, 0b1100_0100]),
+        SpiTransaction::write(vec![0x06]),
+        SpiTransaction::write(vec![0x11]),
+        // Cache cleared by the reset
+        SpiTransaction::transfer(vec![0x23, 0x00, 0xA5], vec![0x00, 0x00, 0b0100_0000]),
+        SpiTransaction::write(vec![0x43, 0x00, 0b0100_0100]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    let rld = RldConfig {
+        ref_buffer_enable: true,
+        ..Default::default()
+    };
+    ads1298.set_rld_config(rld, MockDelay).unwrap();
+    ads1298
+        .modify_rld_config(|c| c.buffer_power_enable = true, MockDelay)
+        .unwrap();
+    // Unchanged, nothing written
+    ads1298
+        .modify_rld_config(|c| c.buffer_power_enable = true, MockDelay)
+        .unwrap();
+
+    ads1298.reset_device(MockDelay).unwrap();
+    ads1298
+        .modify_rld_config(|c| c.buffer_power_enable = true, MockDelay)
+        .unwrap();
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn fallible_ncs() {
+    /// nCS behind an I/O expander, failing on demand
+    struct ExpanderNcs {
+        fail_low: bool,
+        /// Successful `set_high` calls left before failing
+        highs_ok: usize,
+    }
+
+    impl OutputPin for ExpanderNcs {
+        type Error = ();
+
+        fn set_low(&mut self) -> Result<(), Self::Error> {
+            if self.fail_low {
+                return Err(());
+            }
+            Ok(())
+        }
+
+        fn set_high(&mut self) -> Result<(), Self::Error> {
+            if self.highs_ok == 0 {
+                return Err(());
+            }
+            self.highs_ok -= 1;
+            Ok(())
+        }
+    }
+
+    let expectations = [
+        SpiTransaction::write(vec![0x0A]),
+        // Released nCS failing after the transfer
+        SpiTransaction::write(vec![0x08]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let ncs = ExpanderNcs {
+        fail_low: true,
+        highs_ok: usize::MAX,
+    };
+    let mut ads1298 = Ads129x::new_ads1298(spi, ncs);
+    // Device never selected, nothing clocked out
+    assert!(matches!(
+        ads1298.stop_conv(MockDelay),
+        Err(Ads129xError::NcsPin { high: false })
+    ));
+    let (spi, mut ncs) = ads1298.destroy();
+
+    ncs.fail_low = false;
+    let mut ads1298 = Ads129x::new_ads1298(spi, ncs);
+    ads1298.stop_conv(MockDelay).unwrap();
+    let (spi, mut ncs) = ads1298.destroy();
+
+    // Deselected by the constructor only
+    ncs.highs_ok = 1;
+    let mut ads1298 = Ads129x::new_ads1298(spi, ncs);
+    assert!(matches!(
+        ads1298.start_conv(MockDelay),
+        Err(Ads129xError::NcsPin { high: true })
+    ));
+    let (spi, mut ncs) = ads1298.destroy();
+
+    // Failed deselect reported before selecting the device
+    ncs.highs_ok = 0;
+    let mut ads1298 = Ads129x::new_ads1298(spi, ncs);
+    assert!(matches!(
+        ads1298.start_conv(MockDelay),
+        Err(Ads129xError::NcsPin { high: true })
+    ));
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn read_data_by_command() {
+    // RDATA opcode first, in the same transfer
+    let mut tx = vec![0x00; 16];
+    tx[0] = 0x12;
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(tx, vec![
+            0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x02, 0x80,
+            0x00, 0x00,
+        ]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+    let mut data_frame = DataFrame::new();
+
+    ads1294
+        .read_data_by_command(&mut data_frame, MockDelay)
+        .unwrap();
+    assert_eq!(data_frame.data, [1, -1, 2, -0x80_0000]);
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn read_raw_frame() {
+    use ads129x::data::parse_frame;
+
+    let frame = vec![
+        0xC0, 0x00, 0x00, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x02, 0x80, 0x00, 0x00,
+    ];
+    let expectations = [SpiTransaction::transfer(vec![0x00; 15], frame)];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+
+    // Only the frame length is clocked, stale bytes are cleared first
+    let mut buf = [0xAA; 20];
+    ads1294.read_raw_frame(&mut buf, MockDelay).unwrap();
+    assert_eq!(buf[15..], [0xAA; 5]);
+
+    let mut data_frame = DataFrame::new();
+    parse_frame(&buf[..DataFrame::<4>::raw_frame_len()], &mut data_frame).unwrap();
+    assert_eq!(data_frame.data, [1, -1, 2, -0x80_0000]);
+
+    assert!(matches!(
+        ads1294.read_raw_frame(&mut buf[..14], MockDelay),
+        Err(Ads129xError::InvalidArgument(_))
+    ));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn read_registers() {
+    use ads129x::ads1298::Register;
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // RREG CH1SET, count - 1 = 3
+        SpiTransaction::write(vec![0x25, 0x03]),
+        SpiTransaction::transfer(vec![0x00; 4], vec![0x60, 0x81, 0x14, 0x00]),
+        SpiTransaction::write(vec![0x25, 0x03]),
+        SpiTransaction::transfer(vec![0x00; 4], vec![0x60, 0x81, 0x14, 0x00]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    let mut buf = [0xFF; 4];
+    ads1294
+        .read_registers(Register::CH1SET, &mut buf, MockDelay)
+        .unwrap();
+    assert_eq!(buf, [0x60, 0x81, 0x14, 0x00]);
+
+    assert_eq!(ads1294.channels(MockDelay).unwrap(), [
+        Chan::PowerUp {
+            gain:  ChannelGain::X12,
+            input: ChannelInput::Normal,
+        },
+        Chan::PowerDown,
+        Chan::PowerUp {
+            gain:  ChannelGain::X1,
+            input: ChannelInput::Temp,
+        },
+        Chan::PowerUp {
+            gain:  ChannelGain::X6,
+            input: ChannelInput::Normal,
+        },
+    ]);
+
+    // Past WCT2, empty buffer
+    assert!(matches!(
+        ads1294.read_registers(Register::WCT1, &mut buf, MockDelay),
+        Err(Ads129xError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        ads1294.read_registers(Register::ID, &mut [], MockDelay),
+        Err(Ads129xError::InvalidArgument(_))
+    ));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn write_registers() {
+    use ads129x::ads1298::Register;
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // WREG CH1SET, count - 1 = 2
+        SpiTransaction::write(vec![0x45, 0x02]),
+        SpiTransaction::write(vec![0x60, 0x81, 0x14]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1294
+        .write_registers(Register::CH1SET, &[0x60, 0x81, 0x14], MockDelay)
+        .unwrap();
+
+    assert!(matches!(
+        ads1294.write_registers(Register::CH1SET, &[], MockDelay),
+        Err(Ads129xError::InvalidArgument(_))
+    ));
+    // Past WCT2
+    assert!(matches!(
+        ads1294.write_registers(Register::WCT2, &[0x00, 0x00], MockDelay),
+        Err(Ads129xError::InvalidArgument(_))
+    ));
+    // LOFF_STATP is read-only
+    assert!(matches!(
+        ads1294.write_registers(Register::LOFF_FLIP, &[0x00, 0x00], MockDelay),
+        Err(Ads129xError::InvalidArgument("read-only register"))
+    ));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn dump_registers() {
+    let regs: Vec<u8> = (0..26).collect();
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // SDATAC, then RREG ID with count - 1 = 25
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x20, 0x19]),
+        SpiTransaction::transfer(vec![0x00; 26], regs.clone()),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    assert_eq!(ads1298.dump_registers(MockDelay).unwrap().to_vec(), regs);
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn apply_config() {
+    use ads129x::ads1298::DeviceConfig;
+
+    let chan = Chan::PowerUp {
+        gain:  ChannelGain::X4,
+        input: ChannelInput::Normal,
+    };
+    let config = DeviceConfig {
+        config:                 Config {
+            mode:             Mode::LowPower(SampleRateLP::KSps1),
+            osc_clock_output: true,
+            daisy_chain:      false,
+        },
+        test_signal:            TestSignalConfig {
+            frequency: TestSignalFreq::PulsedAtFclk_div_2_20,
+            amplitude: TestSignalAmp::Mode_x2,
+            source: TestSignalSource::Internal,
+            ..Default::default()
+        },
+        rld:                    RldConfig {
+            ref_buffer_enable: true,
+            ..Default::default()
+        },
+        leadoff_control:        LeadOffControl {
+            frequency: LeadOffFreq::DC,
+            magnitude: LeadOffMagnitude::nA_24,
+            ..Default::default()
+        },
+        channels:               [chan, chan, Chan::PowerDown, chan],
+        leadoff_sense_positive: LeadOffSense {
+            ch1_enable: true,
+            ch2_enable: true,
+            ..Default::default()
+        },
+        leadoff_sense_negative: LeadOffSense {
+            ch8_enable: true,
+            ..Default::default()
+        },
+        gpio:                   Gpio {
+            mode: [GpioMode::Output; 4],
+            data: [false; 4],
+        },
+        misc:                   MiscConfig {
+            leadoff_comparator_enable: true,
+            ..Default::default()
+        },
+    };
+    let regs = vec![
+        0b0110_0100,
+        0b0001_0101,
+        0b1100_0000,
+        0b0000_1111,
+        0b0100_0000,
+        0b0100_0000,
+        0b1000_0001,
+        0b0100_0000,
+    ];
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // CONFIG1..CH4SET
+        SpiTransaction::write(vec![0x41, 0x07]),
+        SpiTransaction::write(regs.clone()),
+        // LOFF_SENSP/N
+        SpiTransaction::write(vec![0x4F, 0x01]),
+        SpiTransaction::write(vec![0b0000_0011, 0b1000_0000]),
+        SpiTransaction::write(vec![0x54, 0x00, 0b0000_0000]),
+        SpiTransaction::write(vec![0x57, 0x00, 0b0000_0010]),
+        // Read back
+        SpiTransaction::write(vec![0x21, 0x07]),
+        SpiTransaction::transfer(vec![0x00; 8], regs),
+        SpiTransaction::write(vec![0x2F, 0x01]),
+        SpiTransaction::transfer(vec![0x00; 2], vec![0b0000_0011, 0b1000_0000]),
+        SpiTransaction::transfer(vec![0x34, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0000]),
+        SpiTransaction::transfer(vec![0x37, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0010]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1294.apply_config(&config, MockDelay).unwrap();
+    assert_eq!(ads1294.read_config(MockDelay).unwrap(), config);
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn write_verification() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // Verification off: no read back
+        SpiTransaction::write(vec![0x43, 0x00, 0b1100_0000]),
+        // RLD_STAT is masked out
+        SpiTransaction::write(vec![0x43, 0x00, 0b1100_0000]),
+        SpiTransaction::transfer(vec![0x23, 0x00, 0xA5], vec![0x00, 0x00, 0b1100_0001]),
+        // Lost write
+        SpiTransaction::write(vec![0x43, 0x00, 0b1100_0000]),
+        SpiTransaction::transfer(vec![0x23, 0x00, 0xA5], vec![0x00, 0x00, 0b0100_0000]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+    let rld_config = RldConfig {
+        ref_buffer_enable: true,
+        ..Default::default()
+    };
+
+    ads1294.set_rld_config(rld_config, MockDelay).unwrap();
+    ads1294.set_write_verification(true);
+    ads1294.set_rld_config(rld_config, MockDelay).unwrap();
+    assert!(matches!(
+        ads1294.set_rld_config(rld_config, MockDelay),
+        Err(Ads129xError::WriteVerifyFailed {
+            register: 0x03,
+            wrote:    0b1100_0000,
+            read:     0b0100_0000,
+        })
+    ));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn read_data_when_ready() {
+    let mut expectations = Vec::new();
+    let frames = [
+        [
+            0xC0, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x02, 0x00, 0x00, 0x03, 0x00, 0x00,
+            0x04,
+        ],
+        [
+            0xC0, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00,
+        ],
+    ];
+    for frame in frames.iter() {
+        expectations.push(SpiTransaction::transfer(
+            vec![0x00; frame.len()],
+            frame.to_vec(),
+        ));
+    }
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+    let mut data_frame = DataFrame::new();
+
+    // Spins until DRDY goes low
+    let drdy = ScriptPin(RefCell::new(vec![true, true, false].into_iter()));
+    ads1294
+        .read_data_when_ready(&drdy, &mut data_frame, MockDelay)
+        .unwrap();
+    assert_eq!(data_frame.data, [1, 2, 3, 4]);
+    assert!(drdy.0.borrow_mut().next().is_none());
+
+    // Already low
+    let drdy = ScriptPin(RefCell::new(vec![false].into_iter()));
+    ads1294
+        .read_data_when_ready(&drdy, &mut data_frame, MockDelay)
+        .unwrap();
+    assert_eq!(data_frame.data, [-1, 0, 0, 0]);
+
+    assert!(matches!(
+        ads1294.read_data_when_ready(&BrokenPin, &mut data_frame, MockDelay),
+        Err(Ads129xError::DrdyPin)
+    ));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn stream_frames() {
+    let frame = |sync: u8, sample: u8| {
+        let mut frame = vec![0x00; 15];
+        frame[0] = sync;
+        frame[5] = sample;
+        SpiTransaction::transfer(vec![0x00; 15], frame)
+    };
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // 250 SPS
+        SpiTransaction::write(vec![0x41, 0x00, 0x06]),
+        // RDATAC, START, three frames, STOP, SDATAC
+        SpiTransaction::write(vec![0x10]),
+        SpiTransaction::write(vec![0x08]),
+        frame(0xC0, 1),
+        frame(0xC0, 2),
+        frame(0xC0, 3),
+        SpiTransaction::write(vec![0x0A]),
+        SpiTransaction::write(vec![0x11]),
+        // Sync mismatch ends the stream, the device is still stopped
+        SpiTransaction::write(vec![0x10]),
+        SpiTransaction::write(vec![0x08]),
+        frame(0xC0, 1),
+        frame(0x40, 2),
+        SpiTransaction::write(vec![0x0A]),
+        SpiTransaction::write(vec![0x11]),
+        // DRDY timeout ends the stream too
+        SpiTransaction::write(vec![0x10]),
+        SpiTransaction::write(vec![0x08]),
+        SpiTransaction::write(vec![0x0A]),
+        SpiTransaction::write(vec![0x11]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+    ads1294.set_config(Config::default(), MockDelay).unwrap();
+
+    let drdy = ScriptPin(RefCell::new(
+        vec![true, false, false, true, false].into_iter(),
+    ));
+    let mut samples = Vec::new();
+    let count = ads1294
+        .stream_frames(&drdy, MockDelay, |frame| {
+            samples.push(frame.data[0]);
+            match samples.len() {
+                3 => ControlFlow::Break(()),
+                _ => ControlFlow::Continue(()),
+            }
+        })
+        .unwrap();
+    assert_eq!(count, 3);
+    assert_eq!(samples, [1, 2, 3]);
+    assert!(drdy.0.borrow_mut().next().is_none());
+
+    let drdy = ScriptPin(RefCell::new(vec![false; 2].into_iter()));
+    assert!(matches!(
+        ads1294.stream_frames(&drdy, MockDelay, |_| ControlFlow::Continue(())),
+        Err(Ads129xError::StatusWordMissmatch { .. })
+    ));
+
+    let polls = (timing::settling_time_us(250) / timing::DRDY_POLL_US + 2) as usize;
+    let drdy = ScriptPin(RefCell::new(vec![true; polls].into_iter()));
+    assert!(matches!(
+        ads1294.stream_frames(&drdy, MockDelay, |_| ControlFlow::Continue(())),
+        Err(Ads129xError::Timeout)
+    ));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn read_data_timeout() {
+    let delays = RefCell::new(Vec::new());
+    let expectations = [SpiTransaction::transfer(vec![0x00; 15], {
+        let mut frame = vec![0x00; 15];
+        frame[0] = 0xC0;
+        frame[5] = 0x01;
+        frame
+    })];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+    let mut data_frame = DataFrame::new();
+
+    // DRDY stays high past the timeout
+    let drdy = ScriptPin(RefCell::new(vec![true; 3].into_iter()));
+    assert!(matches!(
+        ads1294.read_data_timeout(&drdy, 20, &mut data_frame, RecDelay(&delays)),
+        Err(Ads129xError::Timeout)
+    ));
+    assert_eq!(*delays.borrow(), [timing::DRDY_POLL_US; 2]);
+
+    let drdy = ScriptPin(RefCell::new(vec![true, false].into_iter()));
+    ads1294
+        .read_data_timeout(&drdy, 20, &mut data_frame, MockDelay)
+        .unwrap();
+    assert_eq!(data_frame.data, [1, 0, 0, 0]);
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn cs_timing() {
+    /// Delay recording every wait
+    struct AllDelays<'a>(&'a RefCell<Vec<u32>>);
+
+    impl DelayUs<u32> for AllDelays<'_> {
+        fn delay_us(&mut self, us: u32) {
+            self.0.borrow_mut().push(us);
+        }
+    }
+
+    let delays = RefCell::new(Vec::new());
+    let expectations = [
+        SpiTransaction::write(vec![0x08]),
+        SpiTransaction::write(vec![0x08]),
+        SpiTransaction::transfer(vec![0x00; 15], {
+            let mut frame = vec![0x00; 15];
+            frame[0] = 0xC0;
+            frame
+        }),
+        SpiTransaction::write(vec![0x08]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+    assert_eq!(ads1294.cs_timing(), CsTiming::default());
+    ads1294.start_conv(AllDelays(&delays)).unwrap();
+    assert_eq!(*delays.borrow(), [40, 40, 20]);
+
+    delays.borrow_mut().clear();
+    ads1294.set_cs_timing(CsTiming {
+        pre_us:     1,
+        post_us:    0,
+        release_us: 3,
+    });
+    ads1294.start_conv(AllDelays(&delays)).unwrap();
+    let mut data_frame = DataFrame::new();
+    ads1294
+        .read_data(&mut data_frame, AllDelays(&delays))
+        .unwrap();
+    assert_eq!(*delays.borrow(), [1, 3, 1, 3]);
+
+    delays.borrow_mut().clear();
+    ads1294.set_cs_timing(CsTiming::ZERO);
+    ads1294.start_conv(AllDelays(&delays)).unwrap();
+    assert!(delays.borrow().is_empty());
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
@@ -6,8 +6,9 @@ use ads129x::ads1292::chan::*;
 use ads129x::ads1292::conf::*;
 use ads129x::ads1292::resp::*;
 use ads129x::ads1292::loff::*;
-// use ads129x::ads1292::gpio::*;
-use ads129x::Ads129x;
+use ads129x::ads1292::gpio::*;
+use ads129x::common::ClockSource;
+use ads129x::{Ads129x, Ads129xError};
 
 struct MockNcs;
 
@@ -53,8 +54,9 @@ fn test() {
 
     let spi = SpiMock::new(&expectations);
 
-    let mut ads1292 = Ads129x::new_ads1292(spi, ncs);
-    ads1292.set_command_mode(MockDelay).unwrap();
+    let mut ads1292 = Ads129x::new_ads1292r(spi, ncs)
+        .set_command_mode(MockDelay)
+        .unwrap();
 
     // Basic setup
     let config = Config {
@@ -92,6 +94,7 @@ fn test() {
         .unwrap();
 
     // LoffStatus
+    #[allow(deprecated)]
     ads1292.set_loff_status(LeadOffStatus{
         clk_div: ClkDiv::Div16,
         .. Default::default()
@@ -114,3 +117,830 @@ fn test() {
     let (mut spi, _) = ads1292.destroy();
     spi.done();
 }
+
+#[test]
+fn clk_div() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // Lead-off status bits aren't written
+        SpiTransaction::write(vec![0x48, 0x00, 0b0100_0000]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    // 512 kHz internal oscillator: only /4 yields 128 kHz
+    assert!(matches!(
+        ads1292.set_clk_div(ClkDiv::Div16, MockDelay),
+        Err(Ads129xError::ClockOutOfRange {
+            fclk_hz: 512_000,
+            fmod_hz: 32_000,
+        })
+    ));
+
+    ads1292.set_clock_source(ClockSource::ExternalClock { hz: 2_048_000 });
+    assert!(matches!(
+        ads1292.set_clk_div(ClkDiv::Div4, MockDelay),
+        Err(Ads129xError::ClockOutOfRange { .. })
+    ));
+    ads1292.set_clk_div(ClkDiv::Div16, MockDelay).unwrap();
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+
+    assert_eq!(ClkDiv::Div16.modulator_clock(1_940_000), Some(121_250));
+    assert_eq!(ClkDiv::Div16.modulator_clock(2_250_000), Some(140_625));
+    assert_eq!(ClkDiv::Div16.modulator_clock(2_400_000), None);
+}
+
+#[test]
+fn raw_register() {
+    use ads129x::ads1292::Register;
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x28, 0x00, 0xA5], vec![0x00, 0x00, 0b0001_0101]),
+        SpiTransaction::write(vec![0x4A, 0x00, 0x83]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    assert_eq!(
+        ads1292
+            .read_register(Register::LOFF_STAT, MockDelay)
+            .unwrap(),
+        0b0001_0101
+    );
+    ads1292
+        .write_register(Register::RESP2, 0x83, MockDelay)
+        .unwrap();
+    assert!(matches!(
+        ads1292.write_register(Register::ID, 0x00, MockDelay),
+        Err(Ads129xError::InvalidArgument(_))
+    ));
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn leadoff_control() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x43, 0x00, 0b0111_1001]),
+        SpiTransaction::transfer(vec![0x23, 0x00, 0xA5], vec![0x00, 0x00, 0b0001_0000]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    let loff = LeadOffControl {
+        frequency:            LeadOffFreq::AC,
+        magnitude:            LeadOffCurrentMagnitude::uA_6,
+        comparator_threshold: LeadOffCompThreshold::PositiveSide(CompPositiveSide::Pct_87_5),
+    };
+    ads1292.set_leadoff_control(loff, MockDelay).unwrap();
+    // Reset value
+    assert_eq!(
+        ads1292.leadoff_control(MockDelay).unwrap(),
+        LeadOffControl {
+            frequency:            LeadOffFreq::DC,
+            magnitude:            LeadOffCurrentMagnitude::nA_6,
+            comparator_threshold: LeadOffCompThreshold::PositiveSide(CompPositiveSide::Pct_95_5),
+        }
+    );
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn chan_read() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x24, 0x00, 0xA5], vec![0x00, 0x00, 0b0101_1001]),
+        SpiTransaction::transfer(vec![0x25, 0x00, 0xA5], vec![0x00, 0x00, 0b1000_0001]),
+        // Reserved mux code
+        SpiTransaction::transfer(vec![0x24, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_1010]),
+        // Reserved gain code
+        SpiTransaction::transfer(vec![0x25, 0x00, 0xA5], vec![0x00, 0x00, 0b0111_0000]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    assert_eq!(ads1292.chan_1(MockDelay).unwrap(), Chan::PowerUp {
+        input: ChannelInput::Channel3,
+        gain:  ChannelGain::X8,
+    });
+    assert_eq!(ads1292.chan_2(MockDelay).unwrap(), Chan::PowerDown);
+    assert!(matches!(
+        ads1292.chan_1(MockDelay),
+        Err(Ads129xError::ReadInterpret(0b0000_1010))
+    ));
+    assert!(matches!(
+        ads1292.chan_2(MockDelay),
+        Err(Ads129xError::ReadInterpret(0b0111_0000))
+    ));
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn rld_sense() {
+    use ads129x::ads1292::rld::*;
+    use ads129x::ads1292::ChopFrequency;
+    use std::convert::TryFrom;
+
+    let rld = RldSense {
+        chop: ChopFrequency::FmodDiv4,
+        rld_buffer_enable: true,
+        ch1_positive: true,
+        ch2_negative: true,
+        ..Default::default()
+    };
+    assert_eq!(RldSenseReg::from(rld).0, 0b1110_1001);
+    assert_eq!(RldSense::try_from(RldSenseReg(0b1110_1001)), Ok(rld));
+    // Chop 0b01 isn't a valid encoding
+    assert_eq!(
+        RldSense::try_from(RldSenseReg(0b0100_0000)),
+        Err(0b0100_0000)
+    );
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x46, 0x00, 0b1110_1001]),
+        SpiTransaction::transfer(vec![0x26, 0x00, 0xA5], vec![0x00, 0x00, 0b0001_0110]),
+        SpiTransaction::transfer(vec![0x26, 0x00, 0xA5], vec![0x00, 0x00, 0b0100_0000]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1292.set_rld_sense(rld, MockDelay).unwrap();
+    assert_eq!(ads1292.rld_sense(MockDelay).unwrap(), RldSense {
+        rld_loff_sense: true,
+        ch1_negative: true,
+        ch2_positive: true,
+        ..Default::default()
+    });
+    assert!(matches!(
+        ads1292.rld_sense(MockDelay),
+        Err(Ads129xError::ReadInterpret(0b0100_0000))
+    ));
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn leadoff_sense() {
+    use std::convert::TryFrom;
+
+    // Datasheet bit positions
+    let bit = |sense: LeadOffSense| LoffSense::from(sense).0;
+    assert_eq!(
+        bit(LeadOffSense {
+            ch1_positive: true,
+            ..Default::default()
+        }),
+        1 << 0
+    );
+    assert_eq!(
+        bit(LeadOffSense {
+            ch2_negative: true,
+            ..Default::default()
+        }),
+        1 << 3
+    );
+    assert_eq!(
+        bit(LeadOffSense {
+            ch1_flip: true,
+            ..Default::default()
+        }),
+        1 << 4
+    );
+    assert_eq!(
+        bit(LeadOffSense {
+            ch2_flip: true,
+            ..Default::default()
+        }),
+        1 << 5
+    );
+    for val in 0..0b100_0000 {
+        assert_eq!(bit(LeadOffSense::try_from(LoffSense(val)).unwrap()), val);
+    }
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x47, 0x00, 0b0010_0011]),
+        SpiTransaction::transfer(vec![0x27, 0x00, 0xA5], vec![0x00, 0x00, 0b0001_1100]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    let sense = LeadOffSense {
+        ch1_positive: true,
+        ch1_negative: true,
+        ch2_flip: true,
+        ..Default::default()
+    };
+    ads1292.set_leadoff_sense(sense, MockDelay).unwrap();
+    assert_eq!(ads1292.leadoff_sense(MockDelay).unwrap(), LeadOffSense {
+        ch2_positive: true,
+        ch2_negative: true,
+        ch1_flip: true,
+        ..Default::default()
+    });
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn leadoff_status() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x28, 0x00, 0xA5], vec![0x00, 0x00, 0b0101_0110]),
+        SpiTransaction::write(vec![0x48, 0x00, 0b0100_0000]),
+        SpiTransaction::write(vec![0x48, 0x00, 0b0000_0000]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    assert_eq!(ads1292.leadoff_status(MockDelay).unwrap(), LeadOffStatus {
+        ch1_positive_leadoff: false,
+        ch1_negative_leadoff: true,
+        ch2_positive_leadoff: true,
+        ch2_negative_leadoff: false,
+        rld_leadoff:          true,
+        clk_div:              ClkDiv::Div16,
+    });
+
+    ads1292.set_clock_source(ClockSource::ExternalClock { hz: 2_048_000 });
+    ads1292.set_clock_divider(ClkDiv::Div16, MockDelay).unwrap();
+    // No clock source check, unlike set_clock_divider
+    #[allow(deprecated)]
+    ads1292
+        .set_loff_status(
+            LeadOffStatus {
+                clk_div: ClkDiv::Div4,
+                ..Default::default()
+            },
+            MockDelay,
+        )
+        .unwrap();
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn resp_roundtrip() {
+    let resp = Resp1 {
+        clock:               RespClock::External,
+        phase:               RespPhase::RespPhase32kHz(RespPhase32kHz::Deg_135),
+        modulation_enable:   true,
+        demodulation_enable: false,
+    };
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x49, 0x00, 0b0111_0011]),
+        SpiTransaction::transfer(vec![0x29, 0x00, 0xA5], vec![0x00, 0x00, 0b0111_0011]),
+        SpiTransaction::transfer(vec![0x29, 0x00, 0xA5], vec![0x00, 0x00, 0b1001_1010]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292r(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1292.set_resp(resp, MockDelay).unwrap();
+    assert_eq!(ads1292.resp(MockDelay).unwrap(), resp);
+
+    // Phase is decoded in 32 kHz steps, 64 kHz values compare by encoding
+    assert_eq!(ads1292.resp(MockDelay).unwrap(), Resp1 {
+        clock:               RespClock::Internal,
+        phase:               RespPhase::RespPhase64kHz(RespPhase64kHz::Deg_135),
+        modulation_enable:   false,
+        demodulation_enable: true,
+    });
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn resp2() {
+    use std::convert::TryFrom;
+
+    // Reserved bit 0 is always written as 1
+    assert_eq!(RespControl2Reg::from(Resp2::default()).0, 0b0000_0011);
+    let resp2 = Resp2 {
+        rldref_internal:           false,
+        resp_freq_64khz:           true,
+        offset_calibration_enable: true,
+    };
+    assert_eq!(RespControl2Reg::from(resp2).0, 0b1000_0101);
+    assert_eq!(Resp2::try_from(RespControl2Reg(0b1000_0101)), Ok(resp2));
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x4A, 0x00, 0b1000_0101]),
+        SpiTransaction::transfer(vec![0x2A, 0x00, 0xA5], vec![0x00, 0x00, 0b1000_0101]),
+        // Reset value
+        SpiTransaction::transfer(vec![0x2A, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0010]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1292.set_resp2(resp2, MockDelay).unwrap();
+    assert_eq!(ads1292.resp2(MockDelay).unwrap(), resp2);
+    assert_eq!(ads1292.resp2(MockDelay).unwrap(), Resp2::default());
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn gpio() {
+    let gpio = Gpio {
+        mode: [GpioMode::Output, GpioMode::Input],
+        data: [true, false],
+    };
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x4B, 0x00, 0b0000_1001]),
+        SpiTransaction::transfer(vec![0x2B, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_1011]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1292.set_gpio(gpio, MockDelay).unwrap();
+    // GPIOD2 reads the pin state of the input
+    assert_eq!(ads1292.gpio(MockDelay).unwrap(), Gpio {
+        data: [true, true],
+        ..gpio
+    });
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn misc_config() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x22, 0x00, 0xA5], vec![0x00, 0x00, 0b1010_0011]),
+        SpiTransaction::transfer(vec![0x22, 0x00, 0xA5], vec![0x00, 0x00, 0b0010_0011]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    assert_eq!(ads1292.misc_config(MockDelay).unwrap(), MiscConfig {
+        test_signal_freq: TestSignalFreq::SquareWave_1Hz,
+        test_signal_enable: true,
+        ref_buffer_enable: true,
+        ..Default::default()
+    });
+    // Reserved bit 7 reads back as 0
+    assert!(matches!(
+        ads1292.misc_config(MockDelay),
+        Err(Ads129xError::ReadInterpret(0b0010_0011))
+    ));
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn read_data() {
+    use ads129x::data::DataFrame92;
+
+    let mut expectations = Vec::new();
+    let frames = [
+        // Sync, IN1N/IN2P off, GPIO2 high
+        [0xC3, 0x40, 0x00, 0x00, 0x12, 0x34, 0xFF, 0xFF, 0xFE],
+        [0xC0, 0x00, 0x00, 0x7F, 0xFF, 0xFF, 0x80, 0x00, 0x00],
+        // Sync mismatch
+        [0xA5, 0x12, 0x34, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01],
+    ];
+    for frame in frames.iter() {
+        expectations.push(SpiTransaction::transfer(
+            vec![0x00; frame.len()],
+            frame.to_vec(),
+        ));
+    }
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs);
+    let mut frame = DataFrame92::new();
+
+    ads1292.read_data(&mut frame, MockDelay).unwrap();
+    assert_eq!(frame.data, [0x1234, -2]);
+    let status = frame.status_word();
+    assert_eq!(status.sync(), 0b1100);
+    assert_eq!(status.loff_stat(), 0b00110);
+    assert_eq!(status.gpio(), 0b10);
+
+    ads1292.read_data(&mut frame, MockDelay).unwrap();
+    assert_eq!(frame.data, [0x7F_FFFF, -0x80_0000]);
+
+    assert!(matches!(
+        ads1292.read_data(&mut frame, MockDelay),
+        Err(Ads129xError::StatusWordMissmatch {
+            raw: [0xA5, 0x12, 0x34],
+        })
+    ));
+    assert_eq!(
+        Ads129xError::<()>::StatusWordMissmatch {
+            raw: [0xA5, 0x12, 0x34],
+        }
+        .to_string(),
+        "status word sync mismatch (got 0xA, expected 0xC)"
+    );
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn status_word92() {
+    use ads129x::data::DataStatusWord92;
+
+    // IN1N and IN2P off, GPIO2 high
+    let word = DataStatusWord92(0xC3_4000);
+    assert!(word.sync_ok());
+    assert_eq!(word.validate(), Ok(()));
+    assert!(!word.in1p_off());
+    assert!(word.in1n_off());
+    assert!(word.in2p_off());
+    assert!(!word.in2n_off());
+    assert!(!word.rld_off());
+    assert!(!word.gpio1());
+    assert!(word.gpio2());
+
+    // Lowest and highest flag bits
+    let word = DataStatusWord92(0xC0_A000);
+    assert!(word.in1p_off());
+    assert!(word.gpio1());
+    assert!(!word.in1n_off() && !word.gpio2());
+    let word = DataStatusWord92(0xC8_0000);
+    assert!(word.rld_off());
+    assert!(!word.in2n_off());
+
+    assert_eq!(DataStatusWord92(0x00_0000).validate(), Err(0b0000));
+    assert_eq!(DataStatusWord92(0xAF_E000).validate(), Err(0b1010));
+    assert!(!DataStatusWord92(0xAF_E000).sync_ok());
+}
+
+#[test]
+fn frame_status() {
+    use ads129x::data::{DataFrame92, FrameStatus92};
+
+    let mut frame = DataFrame92::new();
+    frame.status_word = [0xC0, 0x00, 0x00];
+    assert_eq!(frame.status(), FrameStatus92::default());
+
+    // One LOFF_STAT bit at a time, bits [19:15]
+    let flags = [
+        (0x00, 0x80, FrameStatus92 {
+            ch1_positive_leadoff: true,
+            ..Default::default()
+        }),
+        (0x01, 0x00, FrameStatus92 {
+            ch1_negative_leadoff: true,
+            ..Default::default()
+        }),
+        (0x02, 0x00, FrameStatus92 {
+            ch2_positive_leadoff: true,
+            ..Default::default()
+        }),
+        (0x04, 0x00, FrameStatus92 {
+            ch2_negative_leadoff: true,
+            ..Default::default()
+        }),
+        (0x08, 0x00, FrameStatus92 {
+            rld_leadoff: true,
+            ..Default::default()
+        }),
+        // GPIO bits [14:13]
+        (0x00, 0x20, FrameStatus92 {
+            gpio: [true, false],
+            ..Default::default()
+        }),
+        (0x00, 0x40, FrameStatus92 {
+            gpio: [false, true],
+            ..Default::default()
+        }),
+    ];
+    for (hi, mid, status) in flags.iter() {
+        frame.status_word = [0xC0 | hi, *mid, 0x00];
+        assert_eq!(frame.status(), *status);
+    }
+
+    frame.status_word = [0xCF, 0xE0, 0x00];
+    assert!(frame.ch1_positive_leadoff());
+    assert!(frame.ch1_negative_leadoff());
+    assert!(frame.ch2_positive_leadoff());
+    assert!(frame.ch2_negative_leadoff());
+    assert!(frame.rld_leadoff());
+    assert!(frame.gpio(0) && frame.gpio(1));
+    assert!(!frame.gpio(2));
+}
+
+#[test]
+fn read_data_ads1291() {
+    use ads129x::data::DataFrame92;
+
+    let mut expectations = Vec::new();
+    // Channel 2 slot is clocked out, but discarded
+    let frame = [0xC0, 0x00, 0x00, 0xFF, 0xFF, 0xFD, 0x12, 0x34, 0x56];
+    expectations.push(SpiTransaction::transfer(vec![0x00; 9], frame.to_vec()));
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1291 = Ads129x::new_ads1291(spi, MockNcs);
+    let mut frame = DataFrame92::new();
+    frame.data = [7, 7];
+
+    ads1291.read_data(&mut frame, MockDelay).unwrap();
+    assert_eq!(frame.data, [-3, 0]);
+
+    let (mut spi, _) = ads1291.destroy();
+    spi.done();
+}
+
+#[test]
+fn read_data_by_command() {
+    use ads129x::data::DataFrame92;
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(
+            vec![0x12, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            vec![0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x05, 0xFF, 0xFF, 0xFB],
+        ),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+    let mut frame = DataFrame92::new();
+
+    ads1292.read_data_by_command(&mut frame, MockDelay).unwrap();
+    assert_eq!(frame.data, [5, -5]);
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn frame_validation() {
+    use ads129x::data::{DataFrame92, FrameValidation};
+
+    let corrupted = [0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xFF, 0xFF, 0xFB];
+    let expectations = [
+        SpiTransaction::transfer(vec![0x00; 9], corrupted.to_vec()),
+        SpiTransaction::transfer(vec![0x00; 9], corrupted.to_vec()),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs);
+    let mut frame = DataFrame92::new();
+
+    assert!(matches!(
+        ads1292.read_data(&mut frame, MockDelay),
+        Err(Ads129xError::StatusWordMissmatch { raw: [0, 0, 0] })
+    ));
+
+    ads1292.set_frame_validation(FrameValidation::Lenient);
+    ads1292.read_data(&mut frame, MockDelay).unwrap();
+    assert!(!frame.sync_ok());
+    assert_eq!(frame.data, [5, -5]);
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn read_raw_frame() {
+    use ads129x::data::DataFrame92;
+
+    let frame = vec![0xC0, 0x00, 0x00, 0x00, 0x00, 0x05, 0xFF, 0xFF, 0xFB];
+    let expectations = [SpiTransaction::transfer(vec![0x00; 9], frame)];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs);
+
+    let mut buf = [0u8; 9];
+    ads1292.read_raw_frame(&mut buf, MockDelay).unwrap();
+    assert_eq!(DataFrame92::from_bytes(&buf).unwrap().data, [5, -5]);
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn read_registers() {
+    use ads129x::ads1292::Register;
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // RREG ID, count - 1 = 11, whole register file
+        SpiTransaction::write(vec![0x20, 0x0B]),
+        SpiTransaction::transfer(vec![0x00; 12], vec![
+            0x73, 0x02, 0xE0, 0xF0, 0x00, 0x60, 0x81, 0x2C, 0x00, 0x02, 0x07, 0x0C,
+        ]),
+        SpiTransaction::write(vec![0x24, 0x01]),
+        SpiTransaction::transfer(vec![0x00; 2], vec![0x60, 0x81]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    let mut buf = [0x00; 12];
+    ads1292
+        .read_registers(Register::ID, &mut buf, MockDelay)
+        .unwrap();
+    assert_eq!(buf[0], 0x73);
+    assert_eq!(buf[11], 0x0C);
+
+    assert_eq!(ads1292.channels(MockDelay).unwrap(), [
+        Chan::PowerUp {
+            gain:  ChannelGain::X12,
+            input: ChannelInput::Normal,
+        },
+        Chan::PowerDown,
+    ]);
+
+    assert!(matches!(
+        ads1292.read_registers(Register::CONFIG1, &mut buf, MockDelay),
+        Err(Ads129xError::InvalidArgument(_))
+    ));
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn dump_registers() {
+    let regs = [
+        0x73, 0x02, 0xE0, 0xF0, 0x00, 0x60, 0x81, 0x2C, 0x00, 0x02, 0x07, 0x0C,
+    ];
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x20, 0x0B]),
+        SpiTransaction::transfer(vec![0x00; 12], regs.to_vec()),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    assert_eq!(ads1292.dump_registers(MockDelay).unwrap(), regs);
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn set_all_channels() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x44, 0x01]),
+        SpiTransaction::write(vec![0x81, 0x81]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1292
+        .set_all_channels(Chan::PowerDown, MockDelay)
+        .unwrap();
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn respiration() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // Reference buffer on
+        SpiTransaction::transfer(vec![0x22, 0x00, 0xA5], vec![0x00, 0x00, 0x80]),
+        SpiTransaction::write(vec![0x42, 0x00, 0xA0]),
+        SpiTransaction::write(vec![0x44, 0x00, 0x30]),
+        // RESP_FREQ only, CALIB_ON kept
+        SpiTransaction::transfer(vec![0x2A, 0x00, 0xA5], vec![0x00, 0x00, 0x83]),
+        SpiTransaction::write(vec![0x4A, 0x00, 0x87]),
+        SpiTransaction::write(vec![0x49, 0x00, 0xDA]),
+        // Read back
+        SpiTransaction::write(vec![0x29, 0x01]),
+        SpiTransaction::transfer(vec![0x00; 2], vec![0xDA, 0x87]),
+        SpiTransaction::transfer(vec![0x24, 0x00, 0xA5], vec![0x00, 0x00, 0x30]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292r(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    let setup = RespirationSetup {
+        frequency: RespFrequency::KHz64,
+        phase: RespPhase::RespPhase64kHz(RespPhase64kHz::Deg_135),
+        modulation_enable: true,
+        demodulation_enable: true,
+        channel: Chan::PowerUp {
+            gain:  ChannelGain::X3,
+            input: ChannelInput::Normal,
+        },
+        ..Default::default()
+    };
+    ads1292.configure_respiration(setup, MockDelay).unwrap();
+    assert_eq!(ads1292.respiration_config(MockDelay).unwrap(), setup);
+
+    // 64 kHz phase table with the 32 kHz clock
+    let mismatch = RespirationSetup {
+        frequency: RespFrequency::KHz32,
+        ..setup
+    };
+    assert!(matches!(
+        ads1292.configure_respiration(mismatch, MockDelay),
+        Err(Ads129xError::InvalidArgument(_))
+    ));
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
+
+#[test]
+fn offset_calibration() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // Only CALIB_ON changes
+        SpiTransaction::transfer(vec![0x2A, 0x00, 0xA5], vec![0x00, 0x00, 0x07]),
+        SpiTransaction::write(vec![0x4A, 0x00, 0x87]),
+        // Modified from the written value
+        SpiTransaction::write(vec![0x4A, 0x00, 0x07]),
+        // Data rate read when CONFIG1 is unknown
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0x02]),
+        SpiTransaction::write(vec![0x1A]),
+        SpiTransaction::write(vec![0x41, 0x00, 0x01]),
+        SpiTransaction::write(vec![0x1A]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1292 = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1292.enable_offset_calibration(true, MockDelay).unwrap();
+    ads1292.enable_offset_calibration(false, MockDelay).unwrap();
+
+    ads1292.run_offset_calibration(MockDelay).unwrap();
+    let config = Config {
+        sample_rate: SampleRate::Sps250,
+        ..Default::default()
+    };
+    ads1292.set_config(config, MockDelay).unwrap();
+    ads1292.run_offset_calibration(MockDelay).unwrap();
+
+    let (mut spi, _) = ads1292.destroy();
+    spi.done();
+}
@@ -0,0 +1,229 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::digital::v2::{InputPin, OutputPin};
+use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+use ads129x::ads1298::conf::*;
+use ads129x::ads1298::{chan::*, gpio::*, loff::*, DeviceConfig};
+use ads129x::builder::{Ads129xBuilder, InitStep};
+use ads129x::common::id::DevModel;
+use ads129x::{timing, Ads1292Family, Ads1298Family, Ads129xError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Event {
+    Pin(bool),
+    Delay(u32),
+}
+
+type Log = Rc<RefCell<Vec<Event>>>;
+
+struct MockNcs;
+
+impl OutputPin for MockNcs {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// RESET pin recording its level changes
+struct RecPin(Log);
+
+impl OutputPin for RecPin {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.0.borrow_mut().push(Event::Pin(false));
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.0.borrow_mut().push(Event::Pin(true));
+        Ok(())
+    }
+}
+
+/// DRDY pin, idle high
+struct MockDrdy;
+
+impl InputPin for MockDrdy {
+    type Error = core::convert::Infallible;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+}
+
+/// Delay recording waits longer than the nCS framing ones
+struct RecDelay(Log);
+
+impl DelayUs<u32> for RecDelay {
+    fn delay_us(&mut self, us: u32) {
+        if ![20, 40].contains(&us) {
+            self.0.borrow_mut().push(Event::Delay(us));
+        }
+    }
+}
+
+#[test]
+fn build() {
+    let chan = Chan::PowerUp {
+        gain:  ChannelGain::X4,
+        input: ChannelInput::Normal,
+    };
+    let config = DeviceConfig {
+        config:                 Config {
+            mode:             Mode::LowPower(SampleRateLP::KSps1),
+            osc_clock_output: true,
+            daisy_chain:      false,
+        },
+        test_signal:            TestSignalConfig {
+            frequency: TestSignalFreq::PulsedAtFclk_div_2_20,
+            amplitude: TestSignalAmp::Mode_x2,
+            source: TestSignalSource::Internal,
+            ..Default::default()
+        },
+        rld:                    RldConfig {
+            ref_buffer_enable: true,
+            ..Default::default()
+        },
+        leadoff_control:        LeadOffControl {
+            frequency: LeadOffFreq::DC,
+            magnitude: LeadOffMagnitude::nA_24,
+            ..Default::default()
+        },
+        channels:               [chan, chan, Chan::PowerDown, chan],
+        leadoff_sense_positive: LeadOffSense {
+            ch1_enable: true,
+            ch2_enable: true,
+            ..Default::default()
+        },
+        leadoff_sense_negative: LeadOffSense {
+            ch8_enable: true,
+            ..Default::default()
+        },
+        gpio:                   Gpio {
+            mode: [GpioMode::Output; 4],
+            data: [false; 4],
+        },
+        misc:                   MiscConfig {
+            leadoff_comparator_enable: true,
+            ..Default::default()
+        },
+    };
+
+    let log = Log::default();
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x90]),
+        // CONFIG1..CH4SET
+        SpiTransaction::write(vec![0x41, 0x07]),
+        SpiTransaction::write(vec![
+            0b0110_0100,
+            0b0001_0101,
+            0b1100_0000,
+            0b0000_1111,
+            0b0100_0000,
+            0b0100_0000,
+            0b1000_0001,
+            0b0100_0000,
+        ]),
+        // LOFF_SENSP/N
+        SpiTransaction::write(vec![0x4F, 0x01]),
+        SpiTransaction::write(vec![0b0000_0011, 0b1000_0000]),
+        SpiTransaction::write(vec![0x54, 0x00, 0b0000_0000]),
+        SpiTransaction::write(vec![0x57, 0x00, 0b0000_0010]),
+        SpiTransaction::write(vec![0x10]),
+    ];
+    let spi = SpiMock::new(&expectations);
+
+    let (ads1294, drdy) = Ads129xBuilder::<_, _, Ads1298Family, 4>::new(spi, MockNcs)
+        .with_reset(RecPin(log.clone()))
+        .with_drdy(MockDrdy)
+        .config(config)
+        .fclk_hz(512_000)
+        .settle_us(150_000)
+        .build_with_drdy(RecDelay(log.clone()))
+        .unwrap();
+    assert_eq!(drdy.is_high(), Ok(true));
+
+    assert_eq!(*log.borrow(), [
+        Event::Delay(timing::POWER_UP_US),
+        Event::Pin(false),
+        Event::Delay(timing::RESET_PULSE_US),
+        Event::Pin(true),
+        Event::Delay(timing::RESET_RECOVERY_US),
+        Event::Delay(36 - timing::RESET_RECOVERY_US),
+        Event::Delay(150_000),
+    ]);
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn model_mismatch() {
+    let expectations = [
+        SpiTransaction::write(vec![0x06]),
+        SpiTransaction::write(vec![0x11]),
+        // ADS1298 behind an ADS1292 driver
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x92]),
+    ];
+    let mut spi = SpiMock::new(&expectations);
+
+    // The driver is dropped on failure, keep a handle to the mock
+    let err = Ads129xBuilder::<_, _, Ads1292Family, 2>::new(spi.clone(), MockNcs)
+        .power_on_wait_us(0)
+        .build(RecDelay(Log::default()))
+        .err()
+        .unwrap();
+
+    assert_eq!(err.step, InitStep::VerifyId);
+    assert!(matches!(err.error, Ads129xError::NotSupported {
+        model: Some(DevModel::Ads1298),
+        ..
+    }));
+    spi.done();
+}
+
+#[test]
+fn failing_step() {
+    let expectations = [
+        SpiTransaction::write(vec![0x06]),
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x00]),
+    ];
+    let mut spi = SpiMock::new(&expectations);
+
+    let err = Ads129xBuilder::<_, _, Ads1298Family, 8>::new(spi.clone(), MockNcs)
+        .build(RecDelay(Log::default()))
+        .err()
+        .unwrap();
+
+    assert_eq!(err.step, InitStep::VerifyId);
+    assert!(matches!(err.error, Ads129xError::IdRegRead(_)));
+
+    spi.done();
+
+    let mut spi = SpiMock::new(&[]);
+    let err = Ads129xBuilder::<_, _, Ads1298Family, 8>::new(spi.clone(), MockNcs)
+        .fclk_hz(0)
+        .build(RecDelay(Log::default()))
+        .err()
+        .unwrap();
+
+    assert_eq!(err.step, InitStep::Reset);
+    assert!(matches!(err.error, Ads129xError::InvalidArgument(_)));
+    spi.done();
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "bytemuck")]
+
+use core::mem::{align_of, offset_of, size_of};
+
+use ads129x::data::{DataFrame, DataFrame92, PodFrame, PodFrame92};
+
+// Layout, checked at compile time
+const _: () = {
+    assert!(size_of::<PodFrame92>() == 12);
+    assert!(align_of::<PodFrame92>() == 4);
+    assert!(offset_of!(PodFrame92, status_word) == 0);
+    assert!(offset_of!(PodFrame92, data) == 4);
+
+    assert!(size_of::<PodFrame<8>>() == 36);
+    assert!(align_of::<PodFrame<8>>() == 4);
+    assert!(offset_of!(PodFrame<8>, status_word) == 0);
+    assert!(offset_of!(PodFrame<8>, data) == 4);
+};
+
+#[test]
+fn round_trip() {
+    // Frames keep their struct literals
+    let frame = DataFrame::<4> {
+        status_word: [0xC0, 0x12, 0x34],
+        data:        [1, -2, 0x7F_FFFF, -0x80_0000],
+    };
+
+    let pod = PodFrame::from(frame);
+    let bytes = pod.as_bytes();
+    assert_eq!(&bytes[..4], &[0xC0, 0x12, 0x34, 0x00]);
+    assert_eq!(&bytes[4..8], &1i32.to_ne_bytes());
+
+    let cast: DataFrame<4> = bytemuck::pod_read_unaligned::<PodFrame<4>>(bytes).into();
+    assert_eq!(cast.status_word, frame.status_word);
+    assert_eq!(cast.data, frame.data);
+
+    let frame = DataFrame92 {
+        status_word: [0xC0, 0x00, 0x00],
+        data:        [-1, 2],
+    };
+
+    let pod = PodFrame92::from(frame);
+    let cast: &PodFrame92 = bytemuck::from_bytes(bytemuck::bytes_of(&pod));
+    let cast = DataFrame92::from(*cast);
+    assert_eq!(cast.status_word, frame.status_word);
+    assert_eq!(cast.data, frame.data);
+
+    let zeroed: PodFrame<8> = bytemuck::Zeroable::zeroed();
+    assert!(bytemuck::bytes_of(&zeroed).iter().all(|b| *b == 0));
+}
@@ -0,0 +1,40 @@
+use ads129x::data::{DataFrame, DataFrame92};
+
+#[test]
+fn data_frame() {
+    let mut frame = DataFrame::<4>::new();
+    frame.data = [1, -2, 0x7F_FFFF, -0x80_0000];
+
+    assert_eq!(frame[0], 1);
+    assert_eq!(frame[3], -0x80_0000);
+    assert_eq!(frame.get(1), Some(-2));
+    assert_eq!(frame.get(4), None);
+    assert!(frame.channels().eq([1, -2, 0x7F_FFFF, -0x80_0000]));
+    assert_eq!(frame.iter_indexed().nth(2), Some((2, 0x7F_FFFF)));
+    assert_eq!(frame.iter_indexed().count(), 4);
+}
+
+#[test]
+#[should_panic]
+fn data_frame_index_out_of_range() {
+    let frame = DataFrame::<4>::new();
+    let _ = frame[4];
+}
+
+#[test]
+fn data_frame92() {
+    let mut frame = DataFrame92::new();
+    frame.data = [-5, 7];
+
+    assert_eq!(frame[1], 7);
+    assert_eq!(frame.get(0), Some(-5));
+    assert_eq!(frame.get(2), None);
+    assert!(frame.iter_indexed().eq([(0, -5), (1, 7)]));
+}
+
+#[test]
+#[should_panic]
+fn data_frame92_index_out_of_range() {
+    let frame = DataFrame92::new();
+    let _ = frame[2];
+}
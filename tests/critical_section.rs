@@ -0,0 +1,142 @@
+#![cfg(feature = "critical-section")]
+
+use std::sync::{mpsc, Mutex};
+use std::time::Duration;
+
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+use ads129x::ads1298::conf::*;
+use ads129x::data::DataFrame;
+use ads129x::spi::CsTiming;
+use ads129x::Ads129x;
+
+/// Keeps the tests from probing each other's critical sections
+static SERIAL: Mutex<()> = Mutex::new(());
+
+/// Whether this thread holds the critical section of the `std` implementation
+///
+/// A probe thread blocks on entering it while held.
+fn in_critical_section() -> bool {
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || critical_section::with(|_| tx.send(()).unwrap()));
+    rx.recv_timeout(Duration::from_millis(50)).is_err()
+}
+
+/// nCS pin asserting it is only toggled inside the critical section
+struct MockNcs {
+    initialized: bool,
+}
+
+impl OutputPin for MockNcs {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        assert!(
+            in_critical_section(),
+            "nCS asserted outside critical section"
+        );
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        // Constructor releases nCS outside of any transaction
+        if self.initialized {
+            assert!(
+                in_critical_section(),
+                "nCS released outside critical section"
+            );
+        }
+        self.initialized = true;
+        Ok(())
+    }
+}
+
+/// Delay asserting it never runs inside the critical section
+struct MockDelay;
+
+impl DelayUs<u32> for MockDelay {
+    fn delay_us(&mut self, _us: u32) {
+        assert!(!in_critical_section(), "delay inside critical section");
+    }
+}
+
+/// Delay asserting the default nCS setup/hold waits run inside the critical
+/// section and the release wait outside
+struct CsDelay;
+
+impl DelayUs<u32> for CsDelay {
+    fn delay_us(&mut self, us: u32) {
+        let timing = CsTiming::default();
+        if us == timing.release_us {
+            assert!(
+                !in_critical_section(),
+                "release wait inside critical section"
+            );
+        } else {
+            assert!(in_critical_section(), "nCS wait outside critical section");
+        }
+    }
+}
+
+#[test]
+fn transactions_are_grouped() {
+    let _serial = SERIAL.lock().unwrap();
+    let mut expectations = vec![
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b1000_0110]),
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b1000_0110]),
+    ];
+    // Data frame: status word + 4 channels
+    let mut frame = vec![0x00; 15];
+    frame[0] = 0xC0;
+    expectations.push(SpiTransaction::write(vec![0x10]));
+    expectations.push(SpiTransaction::transfer(vec![0x00; 15], frame));
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads = Ads129x::new_ads1294(spi, MockNcs { initialized: false });
+    // Without waits nCS edges are part of the critical section
+    ads.set_cs_timing(CsTiming {
+        pre_us:     0,
+        post_us:    0,
+        release_us: 0,
+    });
+
+    let mut ads = ads.set_command_mode(MockDelay).unwrap();
+    let config = Config {
+        mode: Mode::HighResolution(SampleRateHR::Sps500),
+        ..Default::default()
+    };
+    ads.set_config(config, MockDelay).unwrap();
+    assert_eq!(ads.config(MockDelay).unwrap(), config);
+
+    let mut ads = ads.set_continuous_mode(MockDelay).unwrap();
+    let mut frame = DataFrame::<4>::new();
+    ads.read_data(&mut frame, MockDelay).unwrap();
+    assert!(!in_critical_section());
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn default_waits_grouped() {
+    let _serial = SERIAL.lock().unwrap();
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b1000_0110]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    // Default 40/40/20 µs waits, nCS edges checked by the pin, waits by the
+    // delay
+    let ads = Ads129x::new_ads1294(spi, MockNcs { initialized: false });
+
+    let mut ads = ads.set_command_mode(CsDelay).unwrap();
+    ads.config(CsDelay).unwrap();
+    assert!(!in_critical_section());
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
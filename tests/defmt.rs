@@ -0,0 +1,36 @@
+//! Compile-time check of the `defmt::Format` coverage
+#![cfg(feature = "defmt")]
+
+use ads129x::{ads1292, ads1298, data, Ads129xError};
+
+fn assert_format<T: defmt::Format>() {}
+
+#[derive(Debug)]
+struct SpiError;
+
+#[test]
+fn format_impls() {
+    // Spi error only needs Debug
+    assert_format::<Ads129xError<SpiError>>();
+
+    assert_format::<data::DataFrame<8>>();
+    assert_format::<data::DataFrame92>();
+    assert_format::<data::DataStatusWord>();
+    assert_format::<data::DataStatusWord92>();
+    assert_format::<data::Status>();
+    assert_format::<data::FrameStatus92>();
+
+    assert_format::<ads1292::conf::Config>();
+    assert_format::<ads1292::conf::MiscConfig>();
+    assert_format::<ads1292::chan::Chan>();
+    assert_format::<ads1292::loff::LeadOffControl>();
+    assert_format::<ads1292::resp::Resp1>();
+    assert_format::<ads1292::gpio::Gpio>();
+
+    assert_format::<ads1298::conf::Config>();
+    assert_format::<ads1298::conf::RldConfig>();
+    assert_format::<ads1298::chan::Chan>();
+    assert_format::<ads1298::loff::LeadOffSense>();
+    assert_format::<ads1298::wct::WctConfig>();
+    assert_format::<ads1298::DeviceConfig<8>>();
+}
@@ -0,0 +1,113 @@
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+use ads129x::ads1298::conf::*;
+use ads129x::common::id::DevModel;
+use ads129x::data::DataFrame;
+use ads129x::spi::CsTiming;
+use ads129x::Ads129x;
+
+struct MockNcs;
+
+impl OutputPin for MockNcs {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Delay keeping every wait
+#[derive(Default)]
+struct RecDelay(Vec<u32>);
+
+impl DelayUs<u32> for RecDelay {
+    fn delay_us(&mut self, us: u32) {
+        self.0.push(us);
+    }
+}
+
+#[test]
+fn stored_delay() {
+    let mut frame = vec![0x00; 15];
+    frame[0] = 0xC0;
+    frame[5] = 0x01;
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x90]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b0000_0110]),
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0110]),
+        SpiTransaction::write(vec![0x10]),
+        SpiTransaction::transfer(vec![0x00; 15], frame),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294_with_delay(spi, MockNcs, RecDelay::default());
+    // Driver settings through Deref
+    ads1294.set_cs_timing(CsTiming {
+        pre_us:     1,
+        post_us:    2,
+        release_us: 3,
+    });
+
+    let mut ads1294 = ads1294.set_command_mode().unwrap();
+    assert_eq!(ads1294.read_id().unwrap(), DevModel::Ads1294);
+    ads1294.set_config(Config::default()).unwrap();
+    assert_eq!(ads1294.config().unwrap(), Config::default());
+
+    let mut ads1294 = ads1294.set_continuous_mode().unwrap();
+    let mut data_frame = DataFrame::<4>::new();
+    ads1294.read_data(&mut data_frame).unwrap();
+    assert_eq!(data_frame.data, [1, 0, 0, 0]);
+
+    let (mut spi, _, delay) = ads1294.destroy();
+    spi.done();
+    assert_eq!(delay.0, [1, 2, 3].repeat(6));
+}
+
+/// Pin ignoring every level change
+struct NopPin;
+
+impl OutputPin for NopPin {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[test]
+fn stored_delay_pins_and_registers() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // Read-modify-write of CONFIG1
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0110]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b1000_0110]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let ads1298 = Ads129x::new_ads1298_with_delay(spi, MockNcs, RecDelay::default())
+        .with_start_pin(NopPin)
+        .with_reset_pin(NopPin)
+        .with_pwdn_pin(NopPin);
+
+    let mut ads1298 = ads1298.set_command_mode().unwrap();
+    ads1298
+        .modify_config(|config| config.mode = Mode::HighResolution(SampleRateHR::Sps500))
+        .unwrap();
+
+    let (ads1298, _) = ads1298.into_parts();
+    let (mut spi, _, pins) = ads1298.destroy_with_pins();
+    spi.done();
+    let (start, reset, pwdn) = pins.release();
+    assert!(start.is_some() && reset.is_some() && pwdn.is_some());
+}
@@ -0,0 +1,182 @@
+use ads129x::builder::{BuildError, InitStep};
+use ads129x::common::id::{DevModel, IdRegError};
+use ads129x::data::FrameError;
+use ads129x::pins::{ControlPin, PinError};
+use ads129x::timing::SclkError;
+use ads129x::Ads129xError;
+
+/// SPI error without `Debug` nor `Display`
+struct BusError;
+
+#[test]
+fn driver_errors() {
+    let cases: [(Ads129xError<BusError>, &str); 17] = [
+        (
+            Ads129xError::IdRegRead(IdRegError::Unsupported(0x94)),
+            "identification register read failed: unsupported device ID 0x94",
+        ),
+        (
+            Ads129xError::ReadInterpret(0xFF),
+            "invalid register value 0xff",
+        ),
+        (
+            Ads129xError::StatusWordMissmatch {
+                raw: [0x30, 0x00, 0x00],
+            },
+            "status word sync mismatch (got 0x3, expected 0xC)",
+        ),
+        (
+            Ads129xError::InvalidArgument("zero clock frequency"),
+            "invalid argument: zero clock frequency",
+        ),
+        (
+            Ads129xError::NotSupported {
+                model: None,
+                what:  "power-down without PWDN pin",
+            },
+            "not supported: power-down without PWDN pin",
+        ),
+        (
+            Ads129xError::NotSupported {
+                model: Some(DevModel::Ads1294),
+                what:  "respiration",
+            },
+            "not supported on Ads1294: respiration",
+        ),
+        (Ads129xError::Pin(ControlPin::Reset), "Reset pin failed"),
+        (
+            Ads129xError::NcsPin { high: true },
+            "nCS pin failed to go high",
+        ),
+        (
+            Ads129xError::NcsPin { high: false },
+            "nCS pin failed to go low",
+        ),
+        (Ads129xError::DrdyPin, "DRDY pin read failed"),
+        (Ads129xError::Timeout, "timed out"),
+        (
+            Ads129xError::WriteVerifyFailed {
+                register: 0x01,
+                wrote:    0x86,
+                read:     0x06,
+            },
+            "register 0x01 verify failed: wrote 0x86, read 0x06",
+        ),
+        (
+            Ads129xError::ClockOutOfRange {
+                fclk_hz: 8_192_000,
+                fmod_hz: 1_024_000,
+            },
+            "modulator clock 1024000 Hz out of range at fCLK 8192000 Hz",
+        ),
+        (
+            Ads129xError::DeviceMismatch {
+                expected_channels: 8,
+                found:             DevModel::Ads1294,
+            },
+            "found Ads1294, expected a 8 channel device",
+        ),
+        (
+            Ads129xError::VariantMismatch {
+                found: DevModel::Ads1298,
+            },
+            "found Ads1298, expected an R variant",
+        ),
+        (
+            Ads129xError::BusBusy,
+            "bus held by the other half of a split driver",
+        ),
+        (Ads129xError::Spi(BusError), "SPI error"),
+    ];
+
+    for (err, expected) in cases.iter() {
+        assert_eq!(err.to_string(), *expected);
+    }
+
+    let err = BuildError {
+        step:  InitStep::VerifyId,
+        error: Ads129xError::<BusError>::Timeout,
+    };
+    assert_eq!(err.to_string(), "VerifyId step failed: timed out");
+}
+
+#[test]
+fn decode_errors() {
+    assert_eq!(
+        IdRegError::ReservedFieldMismatch(0x00).to_string(),
+        "ID 0x00 reserved bits mismatch, expected 0b10"
+    );
+    assert_eq!(
+        IdRegError::Unsupported(0x94).to_string(),
+        "unsupported device ID 0x94"
+    );
+    assert_eq!(
+        FrameError::Sync(0x3).to_string(),
+        "status word sync mismatch (got 0x3, expected 0xC)"
+    );
+    assert_eq!(
+        FrameError::Length {
+            expected: 9,
+            actual:   8,
+        }
+        .to_string(),
+        "frame length 8, expected 9"
+    );
+    assert_eq!(
+        SclkError::TooSlow {
+            sclk_hz: 100_000,
+            min_hz:  110_000,
+            max_hz:  4_096_000,
+        }
+        .to_string(),
+        "SCLK 100000 Hz too slow, allowed 110000..=4096000 Hz"
+    );
+    assert_eq!(PinError.to_string(), "pin failed");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn log_errors() {
+    use ads129x::common::Family;
+    use ads129x::data::log::LogError;
+
+    let cases = [
+        (
+            LogError::BadMagic(*b"RIFF"),
+            "bad log magic [52, 49, 46, 46]",
+        ),
+        (LogError::UnsupportedVersion(2), "unsupported log version 2"),
+        (LogError::UnknownFamily(0x07), "unknown device family 0x07"),
+        (
+            LogError::FrameMismatch {
+                family:   Family::Ads1298,
+                channels: 8,
+            },
+            "frame doesn't match the Ads1298 log with 8 channels",
+        ),
+        (
+            LogError::BadRecord(12),
+            "record length 12 doesn't match the frame",
+        ),
+        (
+            LogError::ConfigTooLong(300),
+            "configuration of 300 bytes too long",
+        ),
+        (LogError::BadConfig, "malformed device configuration"),
+    ];
+
+    for (err, expected) in cases.iter() {
+        assert_eq!(err.to_string(), *expected);
+    }
+}
+
+#[cfg(feature = "dsp")]
+#[test]
+fn mapping_error() {
+    use ads129x::ecg::MappingError;
+
+    assert_eq!(
+        MappingError { channel: 8 }.to_string(),
+        "lead mapped to channel 8, past the frame"
+    );
+}
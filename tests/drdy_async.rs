@@ -0,0 +1,193 @@
+#![cfg(feature = "async")]
+
+use core::convert::Infallible;
+use core::future::Future;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_1::digital::{ErrorKind, ErrorType};
+use embedded_hal_async::digital::Wait;
+use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+use ads129x::data::DataFrame;
+use ads129x::{Ads129x, Ads129xError};
+
+struct MockNcs;
+
+impl OutputPin for MockNcs {
+    type Error = Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Future pending once before completing
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+/// DRDY pin counting awaited falling edges, each pending once
+#[derive(Default)]
+struct MockDrdy {
+    edges: usize,
+}
+
+impl ErrorType for MockDrdy {
+    type Error = Infallible;
+}
+
+impl Wait for MockDrdy {
+    async fn wait_for_high(&mut self) -> Result<(), Infallible> {
+        unreachable!()
+    }
+
+    async fn wait_for_low(&mut self) -> Result<(), Infallible> {
+        unreachable!()
+    }
+
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Infallible> {
+        unreachable!()
+    }
+
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Infallible> {
+        YieldOnce(false).await;
+        self.edges += 1;
+        Ok(())
+    }
+
+    async fn wait_for_any_edge(&mut self) -> Result<(), Infallible> {
+        unreachable!()
+    }
+}
+
+/// DRDY pin failing every wait
+struct BrokenDrdy;
+
+impl ErrorType for BrokenDrdy {
+    type Error = ErrorKind;
+}
+
+impl Wait for BrokenDrdy {
+    async fn wait_for_high(&mut self) -> Result<(), ErrorKind> {
+        Err(ErrorKind::Other)
+    }
+
+    async fn wait_for_low(&mut self) -> Result<(), ErrorKind> {
+        Err(ErrorKind::Other)
+    }
+
+    async fn wait_for_rising_edge(&mut self) -> Result<(), ErrorKind> {
+        Err(ErrorKind::Other)
+    }
+
+    async fn wait_for_falling_edge(&mut self) -> Result<(), ErrorKind> {
+        Err(ErrorKind::Other)
+    }
+
+    async fn wait_for_any_edge(&mut self) -> Result<(), ErrorKind> {
+        Err(ErrorKind::Other)
+    }
+}
+
+fn frame_expectations(frames: &[[u8; 15]]) -> Vec<SpiTransaction> {
+    let mut expectations = Vec::new();
+    for frame in frames.iter() {
+        expectations.push(SpiTransaction::transfer(
+            vec![0x00; frame.len()],
+            frame.to_vec(),
+        ));
+    }
+    expectations
+}
+
+const FRAME: [u8; 15] = [
+    0xC0, 0x00, 0x00, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFE, 0x7F, 0xFF, 0xFF, 0x80, 0x00, 0x00,
+];
+
+#[test]
+fn read_data_on_drdy() {
+    let spi = SpiMock::new(&frame_expectations(&[FRAME]));
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+    let mut drdy = MockDrdy::default();
+
+    let mut data_frame = DataFrame::<4>::new();
+    pollster::block_on(ads1294.read_data_on_drdy(&mut drdy, &mut data_frame)).unwrap();
+    assert_eq!(drdy.edges, 1);
+    assert_eq!(data_frame.data, [1, -2, 0x7F_FFFF, -0x80_0000]);
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn stream_on_drdy() {
+    let spi = SpiMock::new(&frame_expectations(&[FRAME; 5]));
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+    let mut drdy = MockDrdy::default();
+
+    // Frame count
+    let mut samples = Vec::new();
+    let read = pollster::block_on(ads1294.stream_on_drdy(&mut drdy, 3, |frame| {
+        samples.push(frame.data[0]);
+        ControlFlow::Continue(())
+    }))
+    .unwrap();
+    assert_eq!((read, drdy.edges), (3, 3));
+    assert_eq!(samples, [1, 1, 1]);
+
+    // Cancelled by the callback
+    let mut seen = 0;
+    let read = pollster::block_on(ads1294.stream_on_drdy(&mut drdy, 10, |_| {
+        seen += 1;
+        if seen == 2 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }))
+    .unwrap();
+    assert_eq!((read, drdy.edges), (2, 5));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn drdy_wait_failure() {
+    let spi = SpiMock::new(&[]);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+
+    let mut data_frame = DataFrame::<4>::new();
+    assert!(matches!(
+        pollster::block_on(ads1294.read_data_on_drdy(&mut BrokenDrdy, &mut data_frame)),
+        Err(Ads129xError::DrdyPin)
+    ));
+    assert!(matches!(
+        pollster::block_on(
+            ads1294.stream_on_drdy(&mut BrokenDrdy, 3, |_| { ControlFlow::Continue(()) })
+        ),
+        Err(Ads129xError::DrdyPin)
+    ));
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
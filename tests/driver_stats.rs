@@ -0,0 +1,143 @@
+#![cfg(feature = "stats")]
+
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+use ads129x::ads1298::conf::*;
+use ads129x::data::{DataFrame, FrameValidation};
+use ads129x::stats::DriverStats;
+use ads129x::{Ads129x, Ads129xError};
+
+struct MockNcs;
+
+impl OutputPin for MockNcs {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+struct MockDelay;
+
+impl DelayUs<u32> for MockDelay {
+    fn delay_us(&mut self, _us: u32) {}
+}
+
+#[test]
+fn counters() {
+    let mut expectations = vec![
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b0000_0110]),
+        SpiTransaction::write(vec![0x10]),
+    ];
+    let frames = [
+        [
+            0xC0, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x02, 0x00, 0x00, 0x03, 0x00, 0x00,
+            0x04,
+        ],
+        // Sync mismatch
+        [
+            0x40, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x02, 0x00, 0x00, 0x03, 0x00, 0x00,
+            0x04,
+        ],
+    ];
+    for frame in frames.iter() {
+        expectations.push(SpiTransaction::transfer(
+            vec![0x00; frame.len()],
+            frame.to_vec(),
+        ));
+    }
+    // Multiple readback from the written CONFIG1, read back still in
+    // daisy-chain mode
+    expectations.extend([
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b0100_0110]),
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0110]),
+    ]);
+
+    let spi = SpiMock::new(&expectations);
+    let ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+    assert_eq!(ads1294.stats(), &DriverStats::default());
+
+    let mut ads1294 = ads1294.set_command_mode(MockDelay).unwrap();
+    ads1294.set_config(Config::default(), MockDelay).unwrap();
+    let mut ads1294 = ads1294.set_continuous_mode(MockDelay).unwrap();
+    let mut data_frame = DataFrame::<4>::new();
+    ads1294.read_data(&mut data_frame, MockDelay).unwrap();
+    assert!(matches!(
+        ads1294.read_data(&mut data_frame, MockDelay),
+        Err(Ads129xError::StatusWordMissmatch {
+            raw: [0x40, 0x00, 0x00],
+        })
+    ));
+    assert_eq!(ads1294.stats(), &DriverStats {
+        transactions:          5,
+        bytes:                 1 + 3 + 1 + 15 + 15,
+        frames:                2,
+        sync_failures:         1,
+        last_error_status:     Some([0x40, 0x00, 0x00]),
+        write_verify_failures: 0,
+    });
+
+    let mut devices = [ads1294.set_command_mode(MockDelay).unwrap()];
+    devices[0].reset_stats();
+    assert!(Ads129x::configure_multiple_readback_all(&mut devices, MockDelay).is_err());
+    assert_eq!(devices[0].stats(), &DriverStats {
+        transactions:          2,
+        bytes:                 6,
+        frames:                0,
+        sync_failures:         0,
+        last_error_status:     None,
+        write_verify_failures: 1,
+    });
+
+    let [ads1294] = devices;
+    ads1294.destroy().0.done();
+}
+
+#[test]
+fn lenient_counters() {
+    let frame = |sync: u8| {
+        let mut frame = vec![0x00; 15];
+        frame[0] = sync;
+        frame[1] = sync;
+        frame
+    };
+    let expectations = [
+        SpiTransaction::transfer(vec![0x00; 15], frame(0xC0)),
+        SpiTransaction::transfer(vec![0x00; 15], frame(0x20)),
+        // Burst of three
+        SpiTransaction::transfer(vec![0x00; 15], frame(0x40)),
+        SpiTransaction::transfer(vec![0x00; 15], frame(0xC0)),
+        SpiTransaction::transfer(vec![0x00; 15], frame(0x80)),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+    ads1294.set_frame_validation(FrameValidation::Lenient);
+
+    // Slipped frames are returned, only counted
+    let mut data_frame = DataFrame::<4>::new();
+    ads1294.read_data(&mut data_frame, MockDelay).unwrap();
+    ads1294.read_data(&mut data_frame, MockDelay).unwrap();
+    assert_eq!(ads1294.stats().frames, 2);
+    assert_eq!(ads1294.stats().sync_failures, 1);
+    assert_eq!(ads1294.stats().last_error_status, Some([0x20, 0x20, 0x00]));
+
+    let mut frames = [DataFrame::<4>::new(); 3];
+    assert_eq!(ads1294.read_frames(&mut frames, MockDelay).unwrap(), 3);
+    assert_eq!(ads1294.stats().frames, 5);
+    assert_eq!(ads1294.stats().sync_failures, 3);
+    assert_eq!(ads1294.stats().last_error_status, Some([0x80, 0x80, 0x00]));
+
+    ads1294.reset_stats();
+    assert_eq!(ads1294.stats(), &DriverStats::default());
+
+    ads1294.destroy().0.done();
+}
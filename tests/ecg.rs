@@ -0,0 +1,85 @@
+#![cfg(feature = "dsp")]
+
+use ads129x::data::DataFrame;
+use ads129x::ecg::{derive_leads, LeadMapping, MappingError, TwelveLead};
+
+fn frame(data: [i32; 8]) -> DataFrame<8> {
+    let mut frame = DataFrame::<8>::new();
+    frame.data = data;
+    frame
+}
+
+#[test]
+fn textbook() {
+    // I = 0.5 mV, II = 1.0 mV: normal axis
+    let leads = derive_leads(
+        &frame([500, 1000, 1, 2, 3, 4, 5, 6]),
+        &LeadMapping::default(),
+    )
+    .unwrap();
+    assert_eq!(leads, TwelveLead {
+        i:   500,
+        ii:  1000,
+        iii: 500,
+        avr: -750,
+        avl: 0,
+        avf: 750,
+        v:   [1, 2, 3, 4, 5, 6],
+    });
+
+    // I = 1.2 mV, II = -0.3 mV: left axis deviation
+    let leads = derive_leads(
+        &frame([1200, -300, 0, 0, 0, 0, 0, 0]),
+        &LeadMapping::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        (leads.iii, leads.avr, leads.avl, leads.avf),
+        (-1500, -450, 1350, -900)
+    );
+    // Einthoven's law and Goldberger's sum
+    assert_eq!(leads.i + leads.iii, leads.ii);
+    assert_eq!(leads.avr + leads.avl + leads.avf, 0);
+}
+
+#[test]
+fn mapping() {
+    // TI front-end order: V6, I, II, V2, V3, V4, V5, V1
+    let mapping = LeadMapping {
+        lead_i:  1,
+        lead_ii: 2,
+        v:       [7, 3, 4, 5, 6, 0],
+    };
+    let leads = derive_leads(&frame([16, 100, 300, 12, 13, 14, 15, 11]), &mapping).unwrap();
+    assert_eq!((leads.i, leads.ii, leads.iii), (100, 300, 200));
+    assert_eq!(leads.v, [11, 12, 13, 14, 15, 16]);
+
+    // Channel past the frame
+    let mapping = LeadMapping {
+        v: [2, 3, 4, 5, 6, 8],
+        ..LeadMapping::default()
+    };
+    assert_eq!(
+        derive_leads(&frame([0; 8]), &mapping),
+        Err(MappingError { channel: 8 })
+    );
+}
+
+#[test]
+fn saturation() {
+    let leads = derive_leads(
+        &frame([i32::MIN, i32::MAX, 0, 0, 0, 0, 0, 0]),
+        &LeadMapping::default(),
+    )
+    .unwrap();
+    assert_eq!(leads.iii, i32::MAX);
+    assert_eq!(leads.avl, i32::MIN);
+    assert_eq!(leads.avf, i32::MAX);
+
+    let leads = derive_leads(
+        &frame([i32::MIN, i32::MIN, 0, 0, 0, 0, 0, 0]),
+        &LeadMapping::default(),
+    )
+    .unwrap();
+    assert_eq!(leads.avr, i32::MAX);
+}
@@ -0,0 +1,145 @@
+use ads129x::data::{DataFrame, DataFrame92, FrameError};
+
+#[test]
+fn data_frame() {
+    // Same vectors as the read_data_with mock
+    let bytes = [
+        0xC0, 0x12, 0x34, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFE, 0x7F, 0xFF, 0xFF, 0x80, 0x00, 0x00,
+    ];
+    let frame = DataFrame::<4>::from_bytes(&bytes).unwrap();
+    assert_eq!(frame.status_word, [0xC0, 0x12, 0x34]);
+    assert_eq!(frame.data, [1, -2, 0x7F_FFFF, -0x80_0000]);
+
+    let bytes = [
+        0x40, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01,
+    ];
+    assert_eq!(
+        DataFrame::<4>::from_bytes(&bytes).err(),
+        Some(FrameError::Sync(0b0100))
+    );
+
+    assert_eq!(
+        DataFrame::<8>::from_bytes(&bytes).err(),
+        Some(FrameError::Length {
+            expected: 27,
+            actual:   15,
+        })
+    );
+}
+
+#[test]
+fn data_frame92() {
+    // Same vectors as the ADS1292 read_data mock
+    let bytes = [0xC3, 0x40, 0x00, 0x00, 0x12, 0x34, 0xFF, 0xFF, 0xFE];
+    let frame = DataFrame92::from_bytes(&bytes).unwrap();
+    assert_eq!(frame.data, [0x1234, -2]);
+    assert_eq!(frame.status_word().loff_stat(), 0b00110);
+
+    let bytes = [0xA0, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01];
+    assert_eq!(
+        DataFrame92::from_bytes(&bytes).err(),
+        Some(FrameError::Sync(0b1010))
+    );
+    assert_eq!(
+        DataFrame92::from_bytes(&bytes[..8]).err(),
+        Some(FrameError::Length {
+            expected: 9,
+            actual:   8,
+        })
+    );
+    assert_eq!(
+        FrameError::Length {
+            expected: 9,
+            actual:   8,
+        }
+        .to_string(),
+        "frame length 8, expected 9"
+    );
+}
+
+#[test]
+fn status() {
+    use ads129x::data::Status;
+
+    let mut frame = DataFrame::<8>::new();
+    frame.status_word = [0xC0, 0x00, 0x00];
+    assert_eq!(frame.status(), Status {
+        sync_ok: true,
+        ..Default::default()
+    });
+
+    // IN1P and IN8P, IN1N and IN8N, GPIO1 and GPIO4
+    frame.status_word = [0xC8, 0x18, 0x19];
+    let status = frame.status();
+    assert_eq!(status.leadoff_positive, [
+        true, false, false, false, false, false, false, true
+    ]);
+    assert_eq!(status.leadoff_negative, [
+        true, false, false, false, false, false, false, true
+    ]);
+    assert_eq!(status.gpio, [true, false, false, true]);
+
+    // Sync mismatch, channels past CH stay clear
+    let mut frame = DataFrame::<4>::new();
+    frame.status_word = [0x4F, 0xFF, 0xFF];
+    let status = frame.status();
+    assert!(!status.sync_ok);
+    assert_eq!(status.leadoff_positive, [
+        true, true, true, true, false, false, false, false
+    ]);
+    assert_eq!(status.leadoff_negative, [
+        true, true, true, true, false, false, false, false
+    ]);
+    assert_eq!(status.gpio, [true; 4]);
+}
+
+#[test]
+fn parse_frame() {
+    use ads129x::data::parse_frame;
+
+    assert_eq!(DataFrame::<4>::raw_frame_len(), 15);
+    assert_eq!(DataFrame::<8>::raw_frame_len(), 27);
+    assert_eq!(DataFrame92::raw_frame_len(), 9);
+
+    let bytes = [
+        0xC0, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x80, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x80, 0x00, 0x01,
+    ];
+    let mut frame = DataFrame::<4>::new();
+    parse_frame(&bytes, &mut frame).unwrap();
+    assert_eq!(frame.data, [-1, -0x80_0000, -0x01_0000, -0x7F_FFFF]);
+
+    // Frame is filled even when the sync is off
+    let bytes = [
+        0x80, 0x00, 0x00, 0xFF, 0xFF, 0xFE, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFD, 0x00, 0x00, 0x03,
+    ];
+    assert_eq!(
+        parse_frame(&bytes, &mut frame),
+        Err(FrameError::Sync(0b1000))
+    );
+    assert_eq!(frame.data, [-2, 1, -3, 3]);
+
+    assert_eq!(
+        parse_frame(&bytes[..14], &mut frame),
+        Err(FrameError::Length {
+            expected: 15,
+            actual:   14,
+        })
+    );
+    assert_eq!(frame.data, [-2, 1, -3, 3]);
+}
+
+#[test]
+fn parse_frame_with() {
+    use ads129x::data::{parse_frame_with, SampleFormat};
+
+    let bytes = [
+        0xC0, 0x00, 0x00, 0x80, 0x00, 0x00, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x01,
+    ];
+    let mut frame = DataFrame::<4>::new();
+    parse_frame_with(&bytes, &mut frame, SampleFormat::SignExtended).unwrap();
+    assert_eq!(frame.data, [-0x80_0000, 0x7F_FFFF, -1, 1]);
+    parse_frame_with(&bytes, &mut frame, SampleFormat::LeftJustified).unwrap();
+    assert_eq!(frame.data, [i32::MIN, 0x7FFF_FF00, -0x100, 0x100]);
+
+    assert_eq!(SampleFormat::default(), SampleFormat::SignExtended);
+}
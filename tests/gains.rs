@@ -0,0 +1,192 @@
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+use ads129x::ads1298::chan::*;
+use ads129x::data::DataFrame;
+use ads129x::Ads129x;
+
+struct MockNcs;
+
+impl OutputPin for MockNcs {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+struct MockDelay;
+
+impl DelayUs<u32> for MockDelay {
+    fn delay_us(&mut self, _us: u32) {}
+}
+
+const VREF_2V4: u32 = 2_400_000;
+
+fn chan(gain: ChannelGain) -> Chan {
+    Chan::PowerUp {
+        gain,
+        input: ChannelInput::Normal,
+    }
+}
+
+#[test]
+fn writes() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x45, 0x03]),
+        SpiTransaction::write(vec![0x10, 0x10, 0x10, 0x10]),
+        SpiTransaction::write(vec![0x46, 0x00, 0x60]),
+        SpiTransaction::write(vec![0x47, 0x01]),
+        SpiTransaction::write(vec![0x20, 0x81]),
+        SpiTransaction::write(vec![0x06]),
+        SpiTransaction::write(vec![0x11]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+    assert_eq!(ads1294.channel_gains(), [ChannelGain::X6; 4]);
+
+    ads1294
+        .set_all_channels(chan(ChannelGain::X1), MockDelay)
+        .unwrap();
+    ads1294
+        .set_chan_2(chan(ChannelGain::X12), MockDelay)
+        .unwrap();
+    ads1294
+        .write_chans_range(2, &[chan(ChannelGain::X2), Chan::PowerDown], MockDelay)
+        .unwrap();
+    assert_eq!(ads1294.channel_gains(), [
+        ChannelGain::X1,
+        ChannelGain::X12,
+        ChannelGain::X2,
+        ChannelGain::X6
+    ]);
+
+    let mut frame = DataFrame::<4>::new();
+    frame.data = [0x7F_FFFF, 25166, -20971, 1];
+    assert_eq!(ads1294.frame_to_microvolts(&frame, VREF_2V4), [
+        2_400_000, 600, -3000, 0
+    ]);
+
+    // Reset values again
+    ads1294.reset_device(MockDelay).unwrap();
+    assert_eq!(ads1294.channel_gains(), [ChannelGain::X6; 4]);
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn reads() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x27, 0x00, 0xA5], vec![0x00, 0x00, 0x40]),
+        SpiTransaction::write(vec![0x25, 0x03]),
+        SpiTransaction::transfer(vec![0x00; 4], vec![0x30, 0x50, 0x40, 0x00]),
+        SpiTransaction::write(vec![0x26, 0x00]),
+        SpiTransaction::transfer(vec![0x00], vec![0x10]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1294.chan_3(MockDelay).unwrap();
+    assert_eq!(ads1294.channel_gains()[2], ChannelGain::X4);
+
+    ads1294.channels(MockDelay).unwrap();
+    assert_eq!(ads1294.channel_gains(), [
+        ChannelGain::X3,
+        ChannelGain::X8,
+        ChannelGain::X4,
+        ChannelGain::X6
+    ]);
+
+    let mut chans = [Chan::default(); 1];
+    ads1294.read_chans_range(1, &mut chans, MockDelay).unwrap();
+    assert_eq!(ads1294.channel_gains()[1], ChannelGain::X1);
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn ads1291() {
+    use ads129x::ads1292::chan::{Chan, ChannelGain, ChannelInput};
+    use ads129x::data::DataFrame92;
+
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x44, 0x00, 0x60]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1291 = Ads129x::new_ads1291(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    let chan = Chan::PowerUp {
+        gain:  ChannelGain::X12,
+        input: ChannelInput::Normal,
+    };
+    ads1291.set_chan_1(chan, MockDelay).unwrap();
+    assert_eq!(ads1291.channel_gains(), [ChannelGain::X12]);
+
+    let mut frame = DataFrame92::new();
+    frame.data = [25166, 0];
+    assert_eq!(ads1291.frame_to_microvolts(&frame, VREF_2V4), [600, 0]);
+
+    let (mut spi, _) = ads1291.destroy();
+    spi.done();
+}
+
+#[test]
+fn reads_not_replayed() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x45, 0x00, 0x40]),
+        SpiTransaction::transfer(vec![0x26, 0x00, 0xA5], vec![0x00, 0x00, 0x30]),
+        // Restoring reset writes back CH1SET only
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x45, 0x00, 0x40]),
+        SpiTransaction::transfer(vec![0x25, 0x00, 0xA5], vec![0x00, 0x00, 0x40]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs)
+        .with_reset_pin(MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    ads1294
+        .set_chan_1(chan(ChannelGain::X4), MockDelay)
+        .unwrap();
+    ads1294.chan_2(MockDelay).unwrap();
+    assert_eq!(ads1294.channel_gains(), [
+        ChannelGain::X4,
+        ChannelGain::X3,
+        ChannelGain::X6,
+        ChannelGain::X6
+    ]);
+
+    // CH2SET is back to its reset value
+    ads1294.hard_reset(true, MockDelay).unwrap();
+    assert_eq!(ads1294.channel_gains(), [
+        ChannelGain::X4,
+        ChannelGain::X6,
+        ChannelGain::X6,
+        ChannelGain::X6
+    ]);
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
@@ -0,0 +1,91 @@
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::digital::v2::{InputPin, OutputPin};
+use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+use ads129x::{Ads129x, Ads129xError};
+
+struct MockNcs;
+
+impl OutputPin for MockNcs {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+struct MockDelay;
+
+impl DelayUs<u32> for MockDelay {
+    fn delay_us(&mut self, _us: u32) {}
+}
+
+#[test]
+fn set_clear_read() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // RESP, respiration off
+        SpiTransaction::transfer(vec![0x36, 0x00, 0xA5], vec![0x00, 0x00, 0x20]),
+        // GPIO1 high, the other pins stay inputs
+        SpiTransaction::transfer(vec![0x34, 0x00, 0xA5], vec![0x00, 0x00, 0x0F]),
+        SpiTransaction::write(vec![0x54, 0x00, 0x1E]),
+        SpiTransaction::write(vec![0x54, 0x00, 0x0E]),
+        SpiTransaction::transfer(vec![0x34, 0x00, 0xA5], vec![0x00, 0x00, 0x2E]),
+        SpiTransaction::transfer(vec![0x34, 0x00, 0xA5], vec![0x00, 0x00, 0x2E]),
+        SpiTransaction::write(vec![0x54, 0x00, 0x0F]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    {
+        let port = ads1298.gpio_pins(MockDelay).unwrap();
+        let [mut gpio1, gpio2, _, _] = port.pins();
+        gpio1.set_high().unwrap();
+        gpio1.set_low().unwrap();
+        assert!(gpio2.is_high().unwrap());
+        assert!(!gpio2.is_low().unwrap());
+        gpio1.set_input().unwrap();
+    }
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn respiration_conflict() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // RESP, user-generated respiration
+        SpiTransaction::transfer(vec![0x36, 0x00, 0xA5], vec![0x00, 0x00, 0x23]),
+        SpiTransaction::transfer(vec![0x34, 0x00, 0xA5], vec![0x00, 0x00, 0x1F]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(MockDelay)
+        .unwrap();
+
+    {
+        let port = ads1298.gpio_pins(MockDelay).unwrap();
+        let [gpio1, mut gpio2, _, gpio4] = port.pins();
+        assert!(matches!(
+            gpio2.set_high(),
+            Err(Ads129xError::NotSupported { .. })
+        ));
+        assert!(matches!(
+            gpio4.is_high(),
+            Err(Ads129xError::NotSupported { .. })
+        ));
+        assert!(gpio1.is_high().unwrap());
+    }
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
@@ -0,0 +1,27 @@
+use ads129x::common::id::{DevModel, IdReg};
+use ads129x::common::Family;
+use core::convert::TryFrom;
+
+#[test]
+fn capabilities() {
+    // (ID register, model, channels, respiration, family, max data rate)
+    let table = [
+        (0x52, DevModel::Ads1291, 1, false, Family::Ads1292, 8_000),
+        (0x53, DevModel::Ads1292, 2, false, Family::Ads1292, 8_000),
+        (0x73, DevModel::Ads1292R, 2, true, Family::Ads1292, 8_000),
+        (0x90, DevModel::Ads1294, 4, false, Family::Ads1298, 32_000),
+        (0x91, DevModel::Ads1296, 6, false, Family::Ads1298, 32_000),
+        (0x92, DevModel::Ads1298, 8, false, Family::Ads1298, 32_000),
+        (0xD0, DevModel::Ads1294R, 4, true, Family::Ads1298, 32_000),
+        (0xD1, DevModel::Ads1296R, 6, true, Family::Ads1298, 32_000),
+        (0xD2, DevModel::Ads1298R, 8, true, Family::Ads1298, 32_000),
+    ];
+
+    for &(id, model, channels, respiration, family, max_sps) in table.iter() {
+        assert_eq!(DevModel::try_from(IdReg(id)).unwrap(), model);
+        assert_eq!(model.channel_count(), channels, "{:?}", model);
+        assert_eq!(model.is_respiration_variant(), respiration, "{:?}", model);
+        assert_eq!(model.family(), family, "{:?}", model);
+        assert_eq!(model.max_sample_rate_sps(), max_sps, "{:?}", model);
+    }
+}
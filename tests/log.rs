@@ -0,0 +1,152 @@
+#![cfg(feature = "std")]
+
+use ads129x::common::Family;
+use ads129x::data::log::{LogError, Reader, Writer, MAGIC, VERSION};
+use ads129x::data::{DataFrame, DataFrame92};
+use ads129x::{ads1292, ads1298};
+
+fn frame(seed: i32) -> DataFrame<4> {
+    let mut frame = DataFrame::<4>::new();
+    frame.status_word = [0xC0, 0x00, 0x00];
+    frame.data = [seed, -seed, 0x7F_FFFF, -0x80_0000];
+    frame
+}
+
+#[test]
+fn round_trip() {
+    use ads1298::chan::*;
+
+    let mut config = ads1298::DeviceConfig::<4>::default();
+    config.channels[1] = Chan::PowerUp {
+        gain:  ChannelGain::X12,
+        input: ChannelInput::Normal,
+    };
+
+    let mut writer = Writer::new(Vec::new(), 4, &config).unwrap();
+    for seed in 0..3 {
+        writer.write_frame(&frame(seed)).unwrap();
+    }
+    let buf = writer.finish().unwrap();
+
+    // Header with 4 + 4 + 4 configuration bytes + 3 records of
+    // (2 + 3 + 4 * 3) bytes
+    assert_eq!(&buf[..4], &MAGIC);
+    assert_eq!(buf.len(), 8 + 12 + 3 * 17);
+
+    let mut reader = Reader::new(&buf[..]).unwrap();
+    assert_eq!(reader.family(), Family::Ads1298);
+    assert_eq!(reader.channels(), 4);
+    assert_eq!(reader.config::<ads1298::DeviceConfig<4>>().unwrap(), config);
+    assert!(matches!(
+        reader.config::<ads1298::DeviceConfig<8>>(),
+        Err(LogError::FrameMismatch { channels: 4, .. })
+    ));
+
+    let frames = reader.frames::<4>().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(frames.len(), 3);
+    for (seed, read) in frames.iter().enumerate() {
+        let written = frame(seed as i32);
+        assert_eq!(read.status_word, written.status_word);
+        assert_eq!(read.data, written.data);
+    }
+}
+
+#[test]
+fn round_trip_92() {
+    let mut frame = DataFrame92::new();
+    frame.status_word = [0xC0, 0x00, 0x00];
+    frame.data = [-1, 1234];
+
+    let config = ads1292::DeviceConfig::default();
+    let mut writer = Writer::new(Vec::new(), 1, &config).unwrap();
+    writer.write_frame92(&frame).unwrap();
+    let buf = writer.finish().unwrap();
+
+    let mut reader = Reader::new(&buf[..]).unwrap();
+    assert_eq!(reader.channels(), 1);
+    assert_eq!(reader.config::<ads1292::DeviceConfig>().unwrap(), config);
+    let read = reader.read_frame92().unwrap().unwrap();
+    assert_eq!(read.data, frame.data);
+    assert!(reader.read_frame92().unwrap().is_none());
+}
+
+#[test]
+fn version_mismatch() {
+    let config = ads1298::DeviceConfig::<8>::default();
+    let writer = Writer::new(Vec::new(), 8, &config).unwrap();
+    let mut buf = writer.finish().unwrap();
+    buf[4] = VERSION + 1;
+
+    match Reader::new(&buf[..]) {
+        Err(LogError::UnsupportedVersion(v)) => assert_eq!(v, VERSION + 1),
+        _ => panic!("version mismatch not detected"),
+    }
+}
+
+#[test]
+fn frame_mismatch() {
+    let config = ads1298::DeviceConfig::<8>::default();
+    let mut writer = Writer::new(Vec::new(), 8, &config).unwrap();
+    assert!(matches!(
+        writer.write_frame(&frame(0)),
+        Err(LogError::FrameMismatch { channels: 8, .. })
+    ));
+    assert!(matches!(
+        writer.write_frame92(&DataFrame92::new()),
+        Err(LogError::FrameMismatch { channels: 8, .. })
+    ));
+    // Frames past 8 channels are rejected, not sliced
+    assert!(matches!(
+        writer.write_frame(&DataFrame::<16>::new()),
+        Err(LogError::FrameMismatch { channels: 8, .. })
+    ));
+
+    // ADS1292 header with more channels than the frame holds
+    let config = ads1292::DeviceConfig::default();
+    assert!(matches!(
+        Writer::new(Vec::new(), 3, &config),
+        Err(LogError::FrameMismatch { channels: 3, .. })
+    ));
+    let mut buf = Writer::new(Vec::new(), 2, &config)
+        .unwrap()
+        .finish()
+        .unwrap();
+    buf[6] = 3;
+    let mut reader = Reader::new(&buf[..]).unwrap();
+    assert!(matches!(
+        reader.read_frame92(),
+        Err(LogError::FrameMismatch { channels: 3, .. })
+    ));
+}
+
+#[test]
+fn wide_frames() {
+    let config = ads1298::DeviceConfig::<16>::default();
+    let mut frame = DataFrame::<16>::new();
+    frame.data[15] = -42;
+
+    let mut writer = Writer::new(Vec::new(), 16, &config).unwrap();
+    writer.write_frame(&frame).unwrap();
+    let buf = writer.finish().unwrap();
+
+    let mut reader = Reader::new(&buf[..]).unwrap();
+    assert_eq!(reader.read_frame::<16>().unwrap().unwrap().data, frame.data);
+}
+
+#[test]
+fn bad_config() {
+    let config = ads1298::DeviceConfig::<4>::default();
+    let mut buf = Writer::new(Vec::new(), 4, &config)
+        .unwrap()
+        .finish()
+        .unwrap();
+    // Truncated configuration
+    buf[7] -= 1;
+    buf.pop();
+
+    let reader = Reader::new(&buf[..]).unwrap();
+    assert!(matches!(
+        reader.config::<ads1298::DeviceConfig<4>>(),
+        Err(LogError::BadConfig)
+    ));
+}
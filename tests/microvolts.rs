@@ -0,0 +1,84 @@
+use ads129x::ads1292::chan::ChannelGain as Gain92;
+use ads129x::ads1298::chan::ChannelGain;
+use ads129x::data::{code_to_microvolts, DataFrame, DataFrame92};
+
+const VREF_2V4: u32 = 2_400_000;
+const VREF_4V: u32 = 4_000_000;
+
+#[test]
+fn code_to_uv() {
+    // Gain 1
+    assert_eq!(code_to_microvolts(0, ChannelGain::X1, VREF_2V4), 0);
+    assert_eq!(code_to_microvolts(1, ChannelGain::X1, VREF_2V4), 0);
+    assert_eq!(code_to_microvolts(3495, ChannelGain::X1, VREF_2V4), 1000);
+    assert_eq!(code_to_microvolts(-3495, ChannelGain::X1, VREF_2V4), -1000);
+    assert_eq!(
+        code_to_microvolts(0x7F_FFFF, ChannelGain::X1, VREF_2V4),
+        2_400_000
+    );
+    assert_eq!(
+        code_to_microvolts(-0x80_0000, ChannelGain::X1, VREF_2V4),
+        -2_400_000
+    );
+    assert_eq!(
+        code_to_microvolts(0x40_0000, ChannelGain::X1, VREF_4V),
+        2_000_000
+    );
+    assert_eq!(
+        code_to_microvolts(0x7F_FFFF, ChannelGain::X1, VREF_4V),
+        4_000_000
+    );
+    assert_eq!(
+        code_to_microvolts(-0x80_0000, ChannelGain::X1, VREF_4V),
+        -4_000_000
+    );
+
+    // Gain 6
+    assert_eq!(code_to_microvolts(20971, ChannelGain::X6, VREF_2V4), 1000);
+    assert_eq!(code_to_microvolts(-20971, ChannelGain::X6, VREF_2V4), -1000);
+    assert_eq!(
+        code_to_microvolts(0x7F_FFFF, ChannelGain::X6, VREF_2V4),
+        400_000
+    );
+    assert_eq!(
+        code_to_microvolts(0x7F_FFFF, ChannelGain::X6, VREF_4V),
+        666_667
+    );
+    assert_eq!(
+        code_to_microvolts(-0x80_0000, ChannelGain::X6, VREF_4V),
+        -666_667
+    );
+
+    // Gain 12
+    assert_eq!(
+        code_to_microvolts(-0x80_0000, ChannelGain::X12, VREF_2V4),
+        -200_000
+    );
+    assert_eq!(code_to_microvolts(25166, ChannelGain::X12, VREF_4V), 1000);
+    assert_eq!(code_to_microvolts(-25166, ChannelGain::X12, VREF_4V), -1000);
+    assert_eq!(
+        code_to_microvolts(0x7F_FFFF, ChannelGain::X12, VREF_4V),
+        333_333
+    );
+}
+
+#[test]
+fn frame_to_uv() {
+    let mut frame = DataFrame::<4>::new();
+    frame.data = [0x7F_FFFF, -20971, 25166, -1];
+    let gains = [
+        ChannelGain::X1,
+        ChannelGain::X6,
+        ChannelGain::X12,
+        ChannelGain::X12,
+    ];
+    let mut uv = [0; 4];
+    frame.to_microvolts(&gains, VREF_2V4, &mut uv);
+    assert_eq!(uv, [2_400_000, -1000, 600, 0]);
+
+    let mut frame = DataFrame92::new();
+    frame.data = [-0x80_0000, 25166];
+    let mut uv = [0; 2];
+    frame.to_microvolts(&[Gain92::X6, Gain92::X12], VREF_4V, &mut uv);
+    assert_eq!(uv, [-666_667, 1000]);
+}
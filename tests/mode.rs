@@ -0,0 +1,258 @@
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+use ads129x::ads1298::conf::*;
+use ads129x::ads1298::Register;
+use ads129x::common::id::DevModel;
+use ads129x::data::DataFrame;
+use ads129x::mode::AccessPolicy;
+use ads129x::Ads129x;
+
+struct MockNcs;
+
+impl OutputPin for MockNcs {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+struct MockDelay;
+
+impl DelayUs<u32> for MockDelay {
+    fn delay_us(&mut self, _us: u32) {}
+}
+
+fn frame() -> SpiTransaction {
+    let mut rx = vec![0x00; 15];
+    rx[0] = 0xC0;
+    rx[5] = 0x01;
+    SpiTransaction::transfer(vec![0x00; 15], rx)
+}
+
+#[test]
+fn transitions() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b0000_0110]),
+        SpiTransaction::write(vec![0x10]),
+        frame(),
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0110]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+
+    let mut ads1294 = ads1294.set_command_mode(MockDelay).unwrap();
+    ads1294.set_config(Config::default(), MockDelay).unwrap();
+
+    let mut ads1294 = ads1294.set_continuous_mode(MockDelay).unwrap();
+    let mut data_frame = DataFrame::<4>::new();
+    ads1294.read_data(&mut data_frame, MockDelay).unwrap();
+    assert_eq!(data_frame.data, [1, 0, 0, 0]);
+
+    let mut ads1294 = ads1294.set_command_mode(MockDelay).unwrap();
+    assert_eq!(ads1294.config(MockDelay).unwrap(), Config::default());
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn reset_keeps_mode() {
+    let expectations = [
+        SpiTransaction::write(vec![0x06]),
+        SpiTransaction::write(vec![0x11]),
+        // Command mode re-enters SDATAC after the reset
+        SpiTransaction::write(vec![0x06]),
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x10]),
+        SpiTransaction::write(vec![0x06]),
+        // Continuous mode identifies, then returns to RDATAC
+        SpiTransaction::write(vec![0x06]),
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x92]),
+        SpiTransaction::write(vec![0x10]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs);
+    ads1298.reset_device(MockDelay).unwrap();
+
+    let mut ads1298 = ads1298.set_command_mode(MockDelay).unwrap();
+    ads1298.reset_device(MockDelay).unwrap();
+
+    let mut ads1298 = ads1298.set_continuous_mode(MockDelay).unwrap();
+    ads1298.reset_device(MockDelay).unwrap();
+    assert_eq!(
+        ads1298.reset_and_init(None, MockDelay).unwrap(),
+        DevModel::Ads1298
+    );
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
+
+#[test]
+fn dynamic() {
+    let expectations = [
+        SpiTransaction::write(vec![0x06]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b0000_0110]),
+        frame(),
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x10]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs).into_dynamic();
+    ads1294.set_access_policy(AccessPolicy::Manual);
+
+    // Neither restored nor stopped for register access
+    ads1294.reset_device(MockDelay).unwrap();
+    ads1294.set_config(Config::default(), MockDelay).unwrap();
+    let mut data_frame = DataFrame::<4>::new();
+    ads1294.read_data(&mut data_frame, MockDelay).unwrap();
+    ads1294.set_command_mode(MockDelay).unwrap();
+    ads1294.set_continuous_mode(MockDelay).unwrap();
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn dynamic_stop() {
+    let expectations = [
+        // Register access in continuous mode stops it first
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b0000_0110]),
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0110]),
+        SpiTransaction::write(vec![0x10]),
+        frame(),
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x90]),
+        // Raw SDATAC is followed too
+        SpiTransaction::write(vec![0x06]),
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b0000_0110]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs).into_dynamic();
+    assert!(ads1294.is_continuous());
+
+    ads1294.set_config(Config::default(), MockDelay).unwrap();
+    assert!(!ads1294.is_continuous());
+    assert_eq!(ads1294.config(MockDelay).unwrap(), Config::default());
+
+    ads1294.set_continuous_mode(MockDelay).unwrap();
+    let mut data_frame = DataFrame::<4>::new();
+    ads1294.read_data(&mut data_frame, MockDelay).unwrap();
+    assert_eq!(ads1294.read_id(MockDelay).unwrap(), DevModel::Ads1294);
+
+    ads1294.reset_device(MockDelay).unwrap();
+    assert!(ads1294.is_continuous());
+    ads1294.send_command_raw(0x11, MockDelay).unwrap();
+    ads1294.set_config(Config::default(), MockDelay).unwrap();
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn dynamic_stop_and_resume() {
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b0000_0110]),
+        // Read back inside the stopped access
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0110]),
+        SpiTransaction::write(vec![0x10]),
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x25, 0x01]),
+        SpiTransaction::transfer(vec![0x00; 2], vec![0b0000_0000, 0b1000_0001]),
+        SpiTransaction::write(vec![0x10]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1294 = Ads129x::new_ads1294(spi, MockNcs);
+    ads1294.set_write_verification(true);
+    let mut ads1294 = ads1294.into_dynamic();
+    ads1294.set_access_policy(AccessPolicy::StopAndResume);
+
+    ads1294.set_config(Config::default(), MockDelay).unwrap();
+    let mut regs = [0x00; 2];
+    ads1294
+        .read_registers(Register::CH1SET, &mut regs, MockDelay)
+        .unwrap();
+    assert!(ads1294.is_continuous());
+
+    let (mut spi, _) = ads1294.destroy();
+    spi.done();
+}
+
+#[test]
+fn dynamic_burst_access() {
+    use ads129x::ads1298::chan::*;
+    use ads129x::ads1298::wct::WctConfig;
+
+    let expectations = [
+        // Channel burst write, verified register by register
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x45, 0x01]),
+        SpiTransaction::write(vec![0x40, 0x40]),
+        SpiTransaction::transfer(vec![0x25, 0x00, 0xA5], vec![0x00, 0x00, 0x40]),
+        SpiTransaction::transfer(vec![0x26, 0x00, 0xA5], vec![0x00, 0x00, 0x40]),
+        SpiTransaction::write(vec![0x10]),
+        // Channel burst read
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x26, 0x00]),
+        SpiTransaction::transfer(vec![0x00], vec![0x40]),
+        SpiTransaction::write(vec![0x10]),
+        // WCT1..WCT2
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x58, 0x01]),
+        SpiTransaction::write(vec![0x00, 0x00]),
+        SpiTransaction::transfer(vec![0x38, 0x00, 0xA5], vec![0x00, 0x00, 0x00]),
+        SpiTransaction::transfer(vec![0x39, 0x00, 0xA5], vec![0x00, 0x00, 0x00]),
+        SpiTransaction::write(vec![0x10]),
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x38, 0x01]),
+        SpiTransaction::transfer(vec![0x00; 2], vec![0x00, 0x00]),
+        SpiTransaction::write(vec![0x10]),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let mut ads1298 = Ads129x::new_ads1298(spi, MockNcs);
+    ads1298.set_write_verification(true);
+    let mut ads1298 = ads1298.into_dynamic();
+    ads1298.set_access_policy(AccessPolicy::StopAndResume);
+
+    let chan = Chan::PowerUp {
+        gain:  ChannelGain::X4,
+        input: ChannelInput::Normal,
+    };
+    ads1298
+        .write_chans_range(0, &[chan, chan], MockDelay)
+        .unwrap();
+    let mut chans = [Chan::default(); 1];
+    ads1298.read_chans_range(1, &mut chans, MockDelay).unwrap();
+    assert_eq!(chans, [chan]);
+
+    ads1298
+        .apply_wct_config(WctConfig::default(), MockDelay)
+        .unwrap();
+    assert_eq!(
+        ads1298.read_wct_config(MockDelay).unwrap(),
+        WctConfig::default()
+    );
+    assert!(ads1298.is_continuous());
+
+    let (mut spi, _) = ads1298.destroy();
+    spi.done();
+}
@@ -0,0 +1,86 @@
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::blocking::spi::{Transfer, Write};
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal::spi::FullDuplex;
+
+use ads129x::ads1298::conf::*;
+use ads129x::{Ads129x, Ads129xError};
+
+/// SPI error without `Debug`
+struct BusError;
+
+/// Bus recording written bytes, failing once `fail` is set
+#[derive(Default)]
+struct Bus {
+    written: Vec<u8>,
+    fail:    bool,
+}
+
+impl Write<u8> for Bus {
+    type Error = BusError;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        if self.fail {
+            return Err(BusError);
+        }
+        self.written.extend_from_slice(words);
+        Ok(())
+    }
+}
+
+impl Transfer<u8> for Bus {
+    type Error = BusError;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        Ok(words)
+    }
+}
+
+impl FullDuplex<u8> for Bus {
+    type Error = BusError;
+
+    fn read(&mut self) -> nb::Result<u8, Self::Error> {
+        Ok(0x00)
+    }
+
+    fn send(&mut self, _word: u8) -> nb::Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+struct MockNcs;
+
+impl OutputPin for MockNcs {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+struct MockDelay;
+
+impl DelayUs<u32> for MockDelay {
+    fn delay_us(&mut self, _us: u32) {}
+}
+
+#[test]
+fn non_debug_error() {
+    let ads1298 = Ads129x::new_ads1298(Bus::default(), MockNcs);
+    let mut ads1298 = ads1298.set_command_mode(MockDelay).ok().unwrap();
+    assert!(ads1298.set_config(Config::default(), MockDelay).is_ok());
+
+    let (mut bus, ncs) = ads1298.destroy();
+    assert_eq!(bus.written, [0x11, 0x41, 0x00, 0b0000_0110]);
+
+    bus.fail = true;
+    let mut ads1298 = Ads129x::new_ads1298(bus, ncs);
+    assert!(matches!(
+        ads1298.start_conv(MockDelay),
+        Err(Ads129xError::Spi(BusError))
+    ));
+}
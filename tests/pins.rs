@@ -0,0 +1,769 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+use ads129x::common::{ClockSource, RegistersLost};
+use ads129x::pins::{ControlPin, ControlPins};
+use ads129x::spi::CsTiming;
+use ads129x::{timing, Ads129x, Ads129xError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Event {
+    Pin(&'static str, bool),
+    Delay(u32),
+}
+
+type Log = Rc<RefCell<Vec<Event>>>;
+
+struct MockNcs;
+
+impl OutputPin for MockNcs {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Pin recording its level changes
+struct RecPin {
+    name: &'static str,
+    log:  Log,
+}
+
+impl OutputPin for RecPin {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.log.borrow_mut().push(Event::Pin(self.name, false));
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.log.borrow_mut().push(Event::Pin(self.name, true));
+        Ok(())
+    }
+}
+
+/// Pin failing every level change
+struct BrokenPin;
+
+impl OutputPin for BrokenPin {
+    type Error = ();
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Err(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Err(())
+    }
+}
+
+/// Delay recording waits longer than the nCS framing ones
+struct RecDelay(Log);
+
+impl DelayUs<u32> for RecDelay {
+    fn delay_us(&mut self, us: u32) {
+        if ![20, 40].contains(&us) {
+            self.0.borrow_mut().push(Event::Delay(us));
+        }
+    }
+}
+
+fn pin(name: &'static str, log: &Log) -> RecPin {
+    RecPin {
+        name,
+        log: log.clone(),
+    }
+}
+
+#[test]
+fn start_stop_pin() {
+    let log = Log::default();
+    let spi = SpiMock::new(&[]);
+    let pins = ControlPins::new().with_start(pin("START", &log));
+    let mut ads = Ads129x::new_ads1298(spi, MockNcs).with_control_pins(pins);
+
+    ads.start_conv(RecDelay(log.clone())).unwrap();
+    ads.stop_conv(RecDelay(log.clone())).unwrap();
+
+    assert_eq!(
+        *log.borrow(),
+        [
+            Event::Pin("START", true),
+            Event::Delay(timing::START_SETUP_US),
+            Event::Pin("START", false),
+        ]
+    );
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn settings_with_pins() {
+    let log = Log::default();
+    let spi = SpiMock::new(&[]);
+    let pins = ControlPins::new().with_reset(pin("RESET", &log));
+    let mut ads = Ads129x::new_ads1298(spi, MockNcs).with_control_pins(pins);
+
+    ads.set_clock_source(ClockSource::ExternalClock { hz: 2_048_000 });
+    assert_eq!(ads.clock_source(), ClockSource::ExternalClock {
+        hz: 2_048_000,
+    });
+    let timing = CsTiming {
+        pre_us:     1,
+        post_us:    2,
+        release_us: 3,
+    };
+    ads.set_cs_timing(timing);
+    assert_eq!(ads.cs_timing(), timing);
+    ads.reset_stats();
+    let _ = ads.stats();
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn start_pin_hw() {
+    let log = Log::default();
+    let expectations = [
+        SpiTransaction::write(vec![0x08]),
+        SpiTransaction::write(vec![0x0A]),
+    ];
+    let spi = SpiMock::new(&expectations);
+    let mut ads = Ads129x::new_ads1298(spi, MockNcs).with_start_pin(pin("START", &log));
+
+    ads.start_conv(RecDelay(log.clone())).unwrap();
+    ads.stop_conv(RecDelay(log.clone())).unwrap();
+    ads.start_conv(RecDelay(log.clone())).unwrap();
+
+    assert_eq!(*log.borrow(), [
+        Event::Pin("START", true),
+        Event::Delay(timing::START_SETUP_US),
+        Event::Pin("START", false),
+        Event::Pin("START", true),
+        Event::Delay(timing::START_SETUP_US),
+    ]);
+
+    let (spi, ncs, pins) = ads.destroy_with_pins();
+    let (start, reset, pwdn) = pins.release();
+    assert!(start.is_some() && reset.is_none() && pwdn.is_none());
+
+    // Opcodes without the pin
+    let mut ads = Ads129x::new_ads1298(spi, ncs);
+    ads.start_conv(RecDelay(log.clone())).unwrap();
+    ads.stop_conv(RecDelay(log.clone())).unwrap();
+    assert_eq!(log.borrow().len(), 5);
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn reset_pulse() {
+    let log = Log::default();
+    let spi = SpiMock::new(&[]);
+    let pins = ControlPins::new().with_reset(pin("RESET", &log));
+    let mut ads = Ads129x::new_ads1294(spi, MockNcs).with_control_pins(pins);
+
+    ads.reset_device(RecDelay(log.clone())).unwrap();
+
+    assert_eq!(
+        *log.borrow(),
+        [
+            Event::Pin("RESET", false),
+            Event::Delay(timing::RESET_PULSE_US),
+            Event::Pin("RESET", true),
+            Event::Delay(timing::RESET_RECOVERY_US),
+        ]
+    );
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn power_cycle() {
+    let log = Log::default();
+    let spi = SpiMock::new(&[]);
+    let pins = ControlPins::new()
+        .with_reset(pin("RESET", &log))
+        .with_pwdn(pin("PWDN", &log));
+    let mut ads = Ads129x::new_ads1292(spi, MockNcs).with_control_pins(pins);
+
+    ads.power_down().unwrap();
+    let RegistersLost = ads.power_up(RecDelay(log.clone())).unwrap();
+
+    assert_eq!(
+        *log.borrow(),
+        [
+            Event::Pin("PWDN", false),
+            Event::Pin("PWDN", true),
+            Event::Delay(timing::POWER_UP_US),
+            Event::Pin("RESET", false),
+            Event::Delay(timing::RESET_PULSE_US),
+            Event::Pin("RESET", true),
+            Event::Delay(timing::RESET_RECOVERY_US),
+        ]
+    );
+
+    let (mut spi, _, pins) = ads.destroy_with_pins();
+    spi.done();
+    let (start, reset, pwdn) = pins.release();
+    assert!(start.is_none() && reset.is_some() && pwdn.is_some());
+}
+
+#[test]
+fn pwdn_pin() {
+    use ads129x::ads1298::conf::*;
+
+    let log = Log::default();
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b1000_0110]),
+        // Reset and SDATAC after power-up
+        SpiTransaction::write(vec![0x06]),
+        SpiTransaction::write(vec![0x11]),
+        // Written values are forgotten
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0110]),
+    ];
+    let spi = SpiMock::new(&expectations);
+    let mut ads = Ads129x::new_ads1298(spi, MockNcs)
+        .with_pwdn_pin(pin("PWDN", &log))
+        .set_command_mode(RecDelay(log.clone()))
+        .unwrap();
+    let config = Config {
+        mode: Mode::HighResolution(SampleRateHR::Sps500),
+        ..Default::default()
+    };
+    ads.set_config(config, RecDelay(log.clone())).unwrap();
+
+    ads.power_down().unwrap();
+    let RegistersLost = ads.power_up(RecDelay(log.clone())).unwrap();
+    ads.modify_config(|_| {}, RecDelay(log.clone())).unwrap();
+
+    assert_eq!(*log.borrow(), [
+        Event::Pin("PWDN", false),
+        Event::Pin("PWDN", true),
+        Event::Delay(timing::POWER_UP_US),
+        Event::Delay(timing::RESET_RECOVERY_US),
+    ]);
+
+    let (mut spi, _, pins) = ads.destroy_with_pins();
+    spi.done();
+    assert!(pins.release().2.is_some());
+}
+
+#[test]
+fn opcode_fallback() {
+    let log = Log::default();
+    let expectations = [
+        SpiTransaction::write(vec![0x08]),
+        SpiTransaction::write(vec![0x0A]),
+        // Power-up resets through the opcode without RESET pin
+        SpiTransaction::write(vec![0x06]),
+    ];
+    let spi = SpiMock::new(&expectations);
+    let pins = ControlPins::new().with_pwdn(pin("PWDN", &log));
+    let mut ads = Ads129x::new_ads1298(spi, MockNcs).with_control_pins(pins);
+
+    ads.start_conv(RecDelay(log.clone())).unwrap();
+    ads.stop_conv(RecDelay(log.clone())).unwrap();
+    let RegistersLost = ads.power_up(RecDelay(log.clone())).unwrap();
+
+    assert_eq!(
+        *log.borrow(),
+        [
+            Event::Pin("PWDN", true),
+            Event::Delay(timing::POWER_UP_US),
+            Event::Delay(timing::RESET_RECOVERY_US),
+        ]
+    );
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn calibrate_offset() {
+    let log = Log::default();
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x1A]),
+    ];
+    let spi = SpiMock::new(&expectations);
+    let mut ads = Ads129x::new_ads1292(spi, MockNcs)
+        .set_command_mode(RecDelay(log.clone()))
+        .unwrap();
+
+    assert!(matches!(
+        ads.calibrate_offset(0, RecDelay(log.clone())),
+        Err(Ads129xError::InvalidArgument(_))
+    ));
+    ads.calibrate_offset(500, RecDelay(log.clone())).unwrap();
+
+    assert_eq!(timing::offset_calibration_time_us(500), 32_009);
+    assert_eq!(*log.borrow(), [Event::Delay(32_009)]);
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn calibrate_offset_while_continuous() {
+    let log = Log::default();
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x1A]),
+    ];
+    let spi = SpiMock::new(&expectations);
+    let mut ads = Ads129x::new_ads1292(spi, MockNcs).into_dynamic();
+
+    // Power-on continuous mode, nothing is sent
+    assert!(matches!(
+        ads.calibrate_offset(500, RecDelay(log.clone())),
+        Err(Ads129xError::NotSupported { .. })
+    ));
+    assert!(matches!(
+        ads.run_offset_calibration(RecDelay(log.clone())),
+        Err(Ads129xError::NotSupported { .. })
+    ));
+    assert!(log.borrow().is_empty());
+
+    ads.set_command_mode(RecDelay(log.clone())).unwrap();
+    ads.calibrate_offset(500, RecDelay(log.clone())).unwrap();
+    assert_eq!(*log.borrow(), [Event::Delay(32_009)]);
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn reset_and_init() {
+    use ads129x::common::id::DevModel;
+
+    let log = Log::default();
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x06]),
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x92]),
+        SpiTransaction::write(vec![0x06]),
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x73]),
+    ];
+    let spi = SpiMock::new(&expectations);
+    let mut ads = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(RecDelay(log.clone()))
+        .unwrap();
+
+    assert_eq!(
+        ads.reset_and_init(None, RecDelay(log.clone())).unwrap(),
+        DevModel::Ads1298
+    );
+    // 512 kHz: 18 tCLK take 36 µs
+    assert_eq!(
+        ads.reset_and_init(Some(512_000), RecDelay(log.clone()))
+            .unwrap(),
+        DevModel::Ads1292R
+    );
+    assert_eq!(*log.borrow(), [
+        Event::Delay(timing::RESET_RECOVERY_US),
+        Event::Delay(timing::RESET_RECOVERY_US),
+        Event::Delay(36 - timing::RESET_RECOVERY_US),
+    ]);
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn power_up_sequence() {
+    use ads129x::common::{id::DevModel, PowerUpOptions};
+
+    let log = Log::default();
+    let expectations = [
+        SpiTransaction::write(vec![0x06]),
+        SpiTransaction::write(vec![0x11]),
+        // CONFIG3, PD_REFBUF
+        SpiTransaction::write(vec![0x43, 0x00, 0b1100_0000]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x92]),
+        // Back to continuous mode
+        SpiTransaction::write(vec![0x10]),
+    ];
+    let spi = SpiMock::new(&expectations);
+    let mut ads = Ads129x::new_ads1298(spi, MockNcs);
+
+    let model = ads
+        .power_up_sequence(PowerUpOptions::default(), RecDelay(log.clone()))
+        .unwrap();
+    assert_eq!(model, DevModel::Ads1298);
+    assert_eq!(*log.borrow(), [
+        Event::Delay(timing::POWER_UP_US),
+        Event::Delay(timing::RESET_RECOVERY_US),
+        Event::Delay(timing::REFERENCE_SETTLE_US),
+    ]);
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+
+    // External reference, no reference buffer write
+    let log = Log::default();
+    let expectations = [
+        SpiTransaction::write(vec![0x06]),
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x73]),
+        SpiTransaction::write(vec![0x10]),
+    ];
+    let spi = SpiMock::new(&expectations);
+    let mut ads = Ads129x::new_ads1292(spi, MockNcs);
+
+    let options = PowerUpOptions {
+        power_on_wait_us: 500_000,
+        internal_reference: false,
+        ..Default::default()
+    };
+    let model = ads
+        .power_up_sequence(options, RecDelay(log.clone()))
+        .unwrap();
+    assert_eq!(model, DevModel::Ads1292R);
+    assert_eq!(*log.borrow(), [
+        Event::Delay(500_000),
+        Event::Delay(timing::RESET_RECOVERY_US),
+    ]);
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn hard_reset() {
+    use ads129x::ads1298::conf::*;
+
+    let log = Log::default();
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b1000_0110]),
+        // Restoring reset
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b1000_0110]),
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b1000_0110]),
+        SpiTransaction::write(vec![0x10]),
+        // Plain reset
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x10]),
+    ];
+    let spi = SpiMock::new(&expectations);
+    let mut ads = Ads129x::new_ads1298(spi, MockNcs)
+        .with_reset_pin(pin("RESET", &log))
+        .into_dynamic();
+    ads.set_config(
+        Config {
+            mode: Mode::HighResolution(SampleRateHR::Sps500),
+            ..Default::default()
+        },
+        RecDelay(log.clone()),
+    )
+    .unwrap();
+
+    ads.hard_reset(true, RecDelay(log.clone())).unwrap();
+    assert!(!ads.is_continuous());
+    ads.set_continuous_mode(RecDelay(log.clone())).unwrap();
+    ads.hard_reset(false, RecDelay(log.clone())).unwrap();
+    ads.set_continuous_mode(RecDelay(log.clone())).unwrap();
+
+    let pulse = [
+        Event::Pin("RESET", false),
+        Event::Delay(timing::RESET_PULSE_US),
+        Event::Pin("RESET", true),
+        Event::Delay(timing::RESET_RECOVERY_US),
+    ];
+    assert_eq!(*log.borrow(), pulse.repeat(2));
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn pin_builders_chain() {
+    let log = Log::default();
+    let spi = SpiMock::new(&[]);
+    let ads = Ads129x::new_ads1298(spi, MockNcs)
+        .with_start_pin(pin("START", &log))
+        .with_reset_pin(pin("RESET", &log))
+        .with_pwdn_pin(pin("PWDN", &log));
+
+    let (mut spi, _, pins) = ads.destroy_with_pins();
+    spi.done();
+    let (start, reset, pwdn) = pins.release();
+    assert!(start.is_some() && reset.is_some() && pwdn.is_some());
+}
+
+#[test]
+fn hard_reset_pin_errors() {
+    let spi = SpiMock::new(&[]);
+    let mut ads = Ads129x::new_ads1292(spi, MockNcs);
+    assert!(matches!(
+        ads.hard_reset(false, RecDelay(Log::default())),
+        Err(Ads129xError::NotSupported { model: None, .. })
+    ));
+
+    let mut ads = ads.with_reset_pin(BrokenPin);
+    assert!(matches!(
+        ads.hard_reset(true, RecDelay(Log::default())),
+        Err(Ads129xError::Pin(ControlPin::Reset))
+    ));
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn pwdn_missing() {
+    let spi = SpiMock::new(&[]);
+    let mut ads = Ads129x::new_ads1298(spi, MockNcs);
+
+    assert!(matches!(
+        ads.power_down(),
+        Err(Ads129xError::NotSupported { model: None, .. })
+    ));
+    // Conversions aren't stopped either
+    assert!(matches!(
+        ads.deep_power_down(RecDelay(Log::default())),
+        Err(Ads129xError::NotSupported { model: None, .. })
+    ));
+    assert!(matches!(
+        ads.power_up(RecDelay(Log::default())),
+        Err(Ads129xError::NotSupported { model: None, .. })
+    ));
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn pin_selector() {
+    use ads129x::pins::ControlPinSet;
+
+    let log = Log::default();
+    let pins = ControlPins::new().with_start(pin("START", &log));
+    assert!(pins.is_connected(ControlPin::Start));
+    assert!(!pins.is_connected(ControlPin::Reset));
+    assert!(!pins.is_connected(ControlPin::Pwdn));
+}
+
+#[test]
+fn power_up_and_restore() {
+    use ads129x::ads1298::chan::*;
+    use ads129x::ads1298::conf::*;
+
+    let log = Log::default();
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // Configuration before power-down
+        SpiTransaction::write(vec![0x41, 0x00, 0b1000_0110]),
+        SpiTransaction::write(vec![0x46, 0x01]),
+        SpiTransaction::write(vec![0b0100_0000, 0b0100_0000]),
+        // Resume: SDATAC, then write and verify each register
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b1000_0110]),
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b1000_0110]),
+        SpiTransaction::write(vec![0x46, 0x00, 0b0100_0000]),
+        SpiTransaction::transfer(vec![0x26, 0x00, 0xA5], vec![0x00, 0x00, 0b0100_0000]),
+        SpiTransaction::write(vec![0x47, 0x00, 0b0100_0000]),
+        SpiTransaction::transfer(vec![0x27, 0x00, 0xA5], vec![0x00, 0x00, 0b0100_0000]),
+    ];
+    let spi = SpiMock::new(&expectations);
+    let pins = ControlPins::new()
+        .with_start(pin("START", &log))
+        .with_reset(pin("RESET", &log))
+        .with_pwdn(pin("PWDN", &log));
+    let mut ads = Ads129x::new_ads1298(spi, MockNcs)
+        .with_control_pins(pins)
+        .set_command_mode(RecDelay(log.clone()))
+        .unwrap();
+
+    let config = Config {
+        mode: Mode::HighResolution(SampleRateHR::Sps500),
+        ..Default::default()
+    };
+    ads.set_config(config, RecDelay(log.clone())).unwrap();
+    let chan = Chan::PowerUp {
+        gain:  ChannelGain::X4,
+        input: ChannelInput::Normal,
+    };
+    ads.write_chans_range(1, &[chan, chan], RecDelay(log.clone())).unwrap();
+
+    ads.deep_power_down(RecDelay(log.clone())).unwrap();
+    ads.power_up_and_restore(RecDelay(log.clone())).unwrap();
+
+    assert_eq!(
+        *log.borrow(),
+        [
+            Event::Pin("START", false),
+            Event::Pin("PWDN", false),
+            Event::Pin("PWDN", true),
+            Event::Delay(timing::POWER_UP_US),
+            Event::Pin("RESET", false),
+            Event::Delay(timing::RESET_PULSE_US),
+            Event::Pin("RESET", true),
+            Event::Delay(timing::RESET_RECOVERY_US),
+        ]
+    );
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn power_up_and_restore_mismatch() {
+    use ads129x::ads1292::conf::*;
+
+    let log = Log::default();
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b0000_0010]),
+        // No START pin: conversions stopped through the opcode
+        SpiTransaction::write(vec![0x0A]),
+        // No RESET pin: power-up resets through the opcode
+        SpiTransaction::write(vec![0x06]),
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b0000_0010]),
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0011]),
+    ];
+    let spi = SpiMock::new(&expectations);
+    let pins = ControlPins::new().with_pwdn(pin("PWDN", &log));
+    let mut ads = Ads129x::new_ads1292(spi, MockNcs)
+        .with_control_pins(pins)
+        .set_command_mode(RecDelay(log.clone()))
+        .unwrap();
+
+    ads.set_config(Config::default(), RecDelay(log.clone())).unwrap();
+    ads.deep_power_down(RecDelay(log.clone())).unwrap();
+
+    match ads.power_up_and_restore(RecDelay(log.clone())) {
+        Err(Ads129xError::WriteVerifyFailed {
+            register: 0x01,
+            wrote: 0b0000_0010,
+            read: 0b0000_0011,
+        }) => (),
+        res => panic!("unexpected {:?}", res),
+    }
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn single_shot_pin() {
+    use ads129x::ads1298::conf::*;
+    use ads129x::data::DataFrame;
+
+    let log = Log::default();
+    let mut expectations = vec![
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b1000_0110]),
+    ];
+    // RDATA followed by status word + 4 channels
+    let mut tx = vec![0x00; 16];
+    tx[0] = 0x12;
+    let rx = [0x00, 0xC0, 0x00, 0x00]
+        .iter()
+        .chain([0x00, 0x00, 0x01].iter().cycle().take(12))
+        .copied()
+        .collect();
+    expectations.push(SpiTransaction::transfer(tx, rx));
+    let spi = SpiMock::new(&expectations);
+    let pins = ControlPins::new().with_start(pin("START", &log));
+    let mut ads = Ads129x::new_ads1294(spi, MockNcs)
+        .with_control_pins(pins)
+        .set_command_mode(RecDelay(log.clone()))
+        .unwrap();
+
+    let config = Config {
+        mode: Mode::HighResolution(SampleRateHR::Sps500),
+        ..Default::default()
+    };
+    ads.set_config(config, RecDelay(log.clone())).unwrap();
+
+    let mut frame = DataFrame::<4>::new();
+    ads.trigger_single_shot_pin(&mut frame, RecDelay(log.clone())).unwrap();
+    assert_eq!(frame.data, [1; 4]);
+
+    let log = log.borrow();
+    let (pulse_us, wait_us) = match log[..] {
+        [
+            Event::Pin("START", true),
+            Event::Delay(pulse_us),
+            Event::Pin("START", false),
+            Event::Delay(wait_us),
+        ] => (pulse_us, wait_us),
+        _ => panic!("unexpected {:?}", log),
+    };
+    // Conversion done before the frame is read back
+    assert!(wait_us >= 4 * 2_000);
+    // >= 2 tCLK at 2.048 MHz, well below a data period
+    assert!(pulse_us * 1_000 >= 2 * 489);
+    assert!(pulse_us < 2_000);
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn single_shot_pin_missing() {
+    use ads129x::data::DataFrame;
+
+    let spi = SpiMock::new(&[SpiTransaction::write(vec![0x11])]);
+    let mut ads = Ads129x::new_ads1298(spi, MockNcs)
+        .set_command_mode(RecDelay(Log::default()))
+        .unwrap();
+
+    let mut frame = DataFrame::<8>::new();
+    assert!(matches!(
+        ads.trigger_single_shot_pin(&mut frame, RecDelay(Log::default())),
+        Err(Ads129xError::NotSupported { model: None, .. })
+    ));
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn not_supported_display() {
+    use ads129x::data::DataFrame;
+
+    let spi = SpiMock::new(&[SpiTransaction::write(vec![0x11])]);
+    let mut ads = Ads129x::new_ads1296(spi, MockNcs)
+        .set_command_mode(RecDelay(Log::default()))
+        .unwrap();
+
+    let err = ads.power_down().unwrap_err();
+    assert_eq!(err.to_string(), "not supported: power-down without PWDN pin");
+
+    let mut frame = DataFrame::<6>::new();
+    let err = ads
+        .trigger_single_shot_pin(&mut frame, RecDelay(Log::default()))
+        .unwrap_err();
+    assert!(matches!(err, Ads129xError::NotSupported { model: None, .. }));
+    assert_eq!(err.to_string(), "not supported: single-shot trigger without START pin");
+
+    let err: Ads129xError<()> = Ads129xError::NotSupported {
+        model: Some(ads129x::common::id::DevModel::Ads1294),
+        what:  "respiration",
+    };
+    assert_eq!(err.to_string(), "not supported on Ads1294: respiration");
+
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
@@ -0,0 +1,45 @@
+use ads129x::common::{Access, RegisterInfo};
+use ads129x::{ads1292, ads1298};
+
+#[test]
+fn ads1292_table() {
+    assert_eq!(ads1292::Register::CONFIG2.info(), &RegisterInfo {
+        name:   "CONFIG2",
+        access: Access::ReadWrite,
+        reset:  0x80,
+        mask:   0xFF,
+    });
+    assert_eq!(ads1292::Register::ID.info().access, Access::ReadOnly);
+    assert_eq!(ads1292::Register::LOFF_STAT.info().mask, 0x40);
+    assert_eq!(ads1292::Register::GPIO.info().reset, 0x0F);
+
+    // Every register exactly once, in address order
+    assert_eq!(ads1292::REGISTERS.len(), ads1292::Register::ALL.len());
+    for (addr, (reg, info)) in ads1292::Register::iter().enumerate() {
+        assert_eq!(reg as usize, addr);
+        assert_eq!(info.name, format!("{:?}", reg));
+    }
+}
+
+#[test]
+fn ads1298_table() {
+    assert_eq!(ads1298::Register::CONFIG1.info(), &RegisterInfo {
+        name:   "CONFIG1",
+        access: Access::ReadWrite,
+        reset:  0x06,
+        mask:   0xFF,
+    });
+    assert_eq!(
+        ads1298::Register::LOFF_STATN.info().access,
+        Access::ReadOnly
+    );
+    assert_eq!(ads1298::Register::CONFIG3.info().mask, 0xFE);
+    assert_eq!(ads1298::Register::WCT2.info().name, "WCT2");
+
+    // Every register exactly once, in address order
+    assert_eq!(ads1298::REGISTERS.len(), ads1298::Register::ALL.len());
+    for (addr, (reg, info)) in ads1298::Register::iter().enumerate() {
+        assert_eq!(reg as usize, addr);
+        assert_eq!(info.name, format!("{:?}", reg));
+    }
+}
@@ -0,0 +1,70 @@
+use ads129x::ads1292::conf::SampleRate;
+use ads129x::ads1298::conf::{Mode, SampleRateHR, SampleRateLP};
+
+#[test]
+fn high_resolution() {
+    let table = [
+        (SampleRateHR::KSps32, 32_000),
+        (SampleRateHR::KSps16, 16_000),
+        (SampleRateHR::Sps8k, 8_000),
+        (SampleRateHR::Sps4k, 4_000),
+        (SampleRateHR::Sps2k, 2_000),
+        (SampleRateHR::Sps1k, 1_000),
+        (SampleRateHR::Sps500, 500),
+    ];
+
+    for (rate, sps) in table {
+        assert_eq!(rate.as_sps(), sps);
+        assert_eq!(Mode::HighResolution(rate).as_sps(), sps);
+        assert_eq!(SampleRateHR::try_from_sps(sps), Ok(rate));
+    }
+    assert_eq!(SampleRateHR::try_from_sps(250), Err(250));
+}
+
+#[test]
+fn low_power() {
+    let table = [
+        (SampleRateLP::KSps16, 16_000),
+        (SampleRateLP::KSps8, 8_000),
+        (SampleRateLP::KSps4, 4_000),
+        (SampleRateLP::KSps2, 2_000),
+        (SampleRateLP::KSps1, 1_000),
+        (SampleRateLP::Sps500, 500),
+        (SampleRateLP::Sps250, 250),
+    ];
+
+    for (rate, sps) in table {
+        assert_eq!(rate.as_sps(), sps);
+        assert_eq!(Mode::LowPower(rate).as_sps(), sps);
+        assert_eq!(SampleRateLP::try_from_sps(sps), Ok(rate));
+    }
+    assert_eq!(SampleRateLP::try_from_sps(32_000), Err(32_000));
+}
+
+#[test]
+fn ads1292() {
+    let table = [
+        (SampleRate::Sps125, 125),
+        (SampleRate::Sps250, 250),
+        (SampleRate::Sps500, 500),
+        (SampleRate::KSps1, 1_000),
+        (SampleRate::KSps2, 2_000),
+        (SampleRate::KSps4, 4_000),
+        (SampleRate::KSps8, 8_000),
+    ];
+
+    for (rate, sps) in table {
+        assert_eq!(rate.as_sps(), sps);
+        assert_eq!(SampleRate::try_from_sps(sps), Ok(rate));
+    }
+    assert_eq!(SampleRate::try_from_sps(0), Err(0));
+    assert_eq!(SampleRate::try_from_sps(16_000), Err(16_000));
+}
+
+#[test]
+fn const_eval() {
+    const PERIOD_US: u32 = 1_000_000 / SampleRateLP::Sps250.as_sps();
+    const RATE: Result<SampleRateHR, u32> = SampleRateHR::try_from_sps(1_000);
+    assert_eq!(PERIOD_US, 4_000);
+    assert_eq!(RATE, Ok(SampleRateHR::Sps1k));
+}
@@ -0,0 +1,98 @@
+//! Serde round trips through postcard
+#![cfg(feature = "serde")]
+
+use ads129x::ads1292;
+use ads129x::ads1298::chan::{Chan, ChannelGain, ChannelInput};
+use ads129x::ads1298::conf::{Config, Mode, RldConfig, SampleRateHR};
+use ads129x::ads1298::gpio::{Gpio, GpioMode};
+use ads129x::ads1298::loff::LeadOffSense;
+use ads129x::ads1298::DeviceConfig;
+use ads129x::data::{DataFrame, DataFrame92};
+use serde::de::value::Error;
+use serde::de::IntoDeserializer;
+use serde::Deserialize;
+
+#[test]
+fn device_config() {
+    let chan = Chan::PowerUp {
+        input: ChannelInput::TestSig,
+        gain:  ChannelGain::X12,
+    };
+    let config = DeviceConfig {
+        config: Config {
+            mode:             Mode::HighResolution(SampleRateHR::Sps2k),
+            osc_clock_output: true,
+            daisy_chain:      false,
+        },
+        rld: RldConfig {
+            ref_buffer_enable: true,
+            ..Default::default()
+        },
+        channels: [
+            chan,
+            Chan::PowerDown,
+            chan,
+            chan,
+            chan,
+            chan,
+            chan,
+            Chan::PowerDown,
+        ],
+        leadoff_sense_positive: LeadOffSense {
+            ch1_enable: true,
+            ch8_enable: true,
+            ..Default::default()
+        },
+        gpio: Gpio {
+            mode: [
+                GpioMode::Output,
+                GpioMode::Input,
+                GpioMode::Input,
+                GpioMode::Output,
+            ],
+            data: [true, false, false, true],
+        },
+        ..Default::default()
+    };
+
+    let mut buf = [0u8; 128];
+    let bytes = postcard::to_slice(&config, &mut buf).unwrap();
+    let decoded: DeviceConfig<8> = postcard::from_bytes(bytes).unwrap();
+    assert_eq!(decoded, config);
+}
+
+#[test]
+fn frames() {
+    let mut frame = DataFrame::<8>::new();
+    frame.status_word = [0xC0, 0x12, 0x34];
+    frame.data = [1, -1, 0x7F_FFFF, -0x80_0000, 0, 2, -2, 3];
+
+    let mut buf = [0u8; 64];
+    let bytes = postcard::to_slice(&frame, &mut buf).unwrap();
+    let decoded: DataFrame<8> = postcard::from_bytes(bytes).unwrap();
+    assert_eq!(decoded.status_word, frame.status_word);
+    assert_eq!(decoded.data, frame.data);
+
+    let mut frame = DataFrame92::new();
+    frame.status_word = [0xC0, 0x80, 0x00];
+    frame.data = [-0x80_0000, 0x7F_FFFF];
+
+    let bytes = postcard::to_slice(&frame, &mut buf).unwrap();
+    let decoded: DataFrame92 = postcard::from_bytes(bytes).unwrap();
+    assert_eq!(decoded.status_word, frame.status_word);
+    assert_eq!(decoded.data, frame.data);
+}
+
+#[test]
+fn enum_names() {
+    let gain = ChannelGain::deserialize("X12".into_deserializer());
+    assert_eq!(gain, Ok::<_, Error>(ChannelGain::X12));
+    let rate = SampleRateHR::deserialize("Sps500".into_deserializer());
+    assert_eq!(rate, Ok::<_, Error>(SampleRateHR::Sps500));
+    let gain = ads1292::chan::ChannelGain::deserialize("X6".into_deserializer());
+    assert_eq!(gain, Ok::<_, Error>(ads1292::chan::ChannelGain::X6));
+
+    // Discriminants aren't accepted as names
+    let gain: Result<ChannelGain, Error> = ChannelGain::deserialize("110".into_deserializer());
+    assert!(gain.is_err());
+}
@@ -0,0 +1,52 @@
+use ads129x::data::{DataFrame, DataFrame92, FrameError};
+
+#[test]
+fn data_frame() {
+    let mut frame = DataFrame::<2>::new();
+    frame.status_word = [0xC0, 0x12, 0x34];
+    frame.data = [1, -0x80_0000];
+
+    assert_eq!(DataFrame::<2>::wire_len(), 11);
+    let mut buf = [0xAA; 12];
+    assert_eq!(frame.write_to(&mut buf), Ok(11));
+    assert_eq!(buf, [
+        0xC0, 0x12, 0x34, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0xFF, 0xAA,
+    ]);
+
+    let read = DataFrame::<2>::read_from(&buf).unwrap();
+    assert_eq!(read.status_word, frame.status_word);
+    assert_eq!(read.data, frame.data);
+
+    assert_eq!(
+        frame.write_to(&mut buf[..10]),
+        Err(FrameError::Length {
+            expected: 11,
+            actual:   10,
+        })
+    );
+    assert_eq!(
+        DataFrame::<2>::read_from(&buf[..3]).err(),
+        Some(FrameError::Length {
+            expected: 11,
+            actual:   3,
+        })
+    );
+}
+
+#[test]
+fn data_frame92() {
+    let mut frame = DataFrame92::new();
+    frame.status_word = [0xC3, 0x40, 0x00];
+    frame.data = [0x1234, -2];
+
+    assert_eq!(DataFrame92::wire_len(), 11);
+    let mut buf = [0x00; 11];
+    assert_eq!(frame.write_to(&mut buf), Ok(11));
+    assert_eq!(buf, [
+        0xC3, 0x40, 0x00, 0x34, 0x12, 0x00, 0x00, 0xFE, 0xFF, 0xFF, 0xFF,
+    ]);
+
+    let read = DataFrame92::read_from(&buf).unwrap();
+    assert_eq!(read.status_word, frame.status_word);
+    assert_eq!(read.data, frame.data);
+}
@@ -0,0 +1,94 @@
+use std::cell::RefCell;
+
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+use ads129x::ads1298::conf::*;
+use ads129x::common::id::DevModel;
+use ads129x::spi::{BorrowedBus, SharedBus};
+use ads129x::Ads129x;
+
+/// nCS recording its level changes as `(name, high)`
+struct RecNcs<'a> {
+    name: &'static str,
+    log:  &'a RefCell<Vec<(&'static str, bool)>>,
+}
+
+impl OutputPin for RecNcs<'_> {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.log.borrow_mut().push((self.name, false));
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.log.borrow_mut().push((self.name, true));
+        Ok(())
+    }
+}
+
+struct MockDelay;
+
+impl DelayUs<u32> for MockDelay {
+    fn delay_us(&mut self, _us: u32) {}
+}
+
+#[test]
+fn shared_bus() {
+    let log = RefCell::new(Vec::new());
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x92]),
+        SpiTransaction::transfer(vec![0x20, 0x00, 0xA5], vec![0x00, 0x00, 0x73]),
+        SpiTransaction::write(vec![0x41, 0x00, 0b0000_0110]),
+    ];
+    let bus = RefCell::new(SpiMock::new(&expectations));
+
+    let ecg = Ads129x::new_ads1298(SharedBus::new(&bus), RecNcs {
+        name: "ECG",
+        log:  &log,
+    });
+    let resp = Ads129x::new_ads1292(SharedBus::new(&bus), RecNcs {
+        name: "RESP",
+        log:  &log,
+    });
+    log.borrow_mut().clear();
+
+    let mut ecg = ecg.set_command_mode(MockDelay).unwrap();
+    let mut resp = resp.set_command_mode(MockDelay).unwrap();
+    assert_eq!(ecg.read_id(MockDelay).unwrap(), DevModel::Ads1298);
+    assert_eq!(resp.read_id(MockDelay).unwrap(), DevModel::Ads1292R);
+    ecg.set_config(Config::default(), MockDelay).unwrap();
+
+    // Each transaction selects one device only
+    for pair in log.borrow().chunks(2) {
+        assert_eq!(pair[0].0, pair[1].0);
+        assert_eq!((pair[0].1, pair[1].1), (false, true));
+    }
+    let names: Vec<_> = log.borrow().iter().step_by(2).map(|e| e.0).collect();
+    assert_eq!(names, ["ECG", "RESP", "ECG", "RESP", "ECG"]);
+
+    let _ = ecg.destroy();
+    let _ = resp.destroy();
+    bus.into_inner().done();
+}
+
+#[test]
+fn borrowed_bus() {
+    let log = RefCell::new(Vec::new());
+    let expectations = [SpiTransaction::write(vec![0x0A])];
+    let mut spi = SpiMock::new(&expectations);
+
+    let mut ads1298 = Ads129x::new_ads1298(BorrowedBus(&mut spi), RecNcs {
+        name: "ECG",
+        log:  &log,
+    });
+    ads1298.stop_conv(MockDelay).unwrap();
+    let _ = ads1298.destroy();
+
+    // The bus is back for other users
+    spi.done();
+}
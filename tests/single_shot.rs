@@ -0,0 +1,220 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::blocking::spi::{Transfer, Write};
+use embedded_hal::digital::v2::{InputPin, OutputPin};
+use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+use ads129x::ads1298::conf::*;
+use ads129x::data::DataFrame;
+use ads129x::{timing, Ads129x};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Event {
+    /// First byte clocked out
+    Spi(u8),
+    Delay(u32),
+    Drdy,
+}
+
+type Log = Rc<RefCell<Vec<Event>>>;
+
+/// Bus recording the first byte of every transaction
+struct RecSpi {
+    mock: SpiMock,
+    log:  Log,
+}
+
+impl Write<u8> for RecSpi {
+    type Error = embedded_hal_mock::MockError;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        self.log.borrow_mut().push(Event::Spi(words[0]));
+        self.mock.write(words)
+    }
+}
+
+impl Transfer<u8> for RecSpi {
+    type Error = embedded_hal_mock::MockError;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        self.log.borrow_mut().push(Event::Spi(words[0]));
+        self.mock.transfer(words)
+    }
+}
+
+struct MockNcs;
+
+impl OutputPin for MockNcs {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// DRDY low on the first poll
+struct Drdy(Log);
+
+impl InputPin for Drdy {
+    type Error = core::convert::Infallible;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        self.0.borrow_mut().push(Event::Drdy);
+        Ok(false)
+    }
+
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        self.is_high().map(|high| !high)
+    }
+}
+
+/// DRDY never asserted
+struct StuckDrdy;
+
+impl InputPin for StuckDrdy {
+    type Error = core::convert::Infallible;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+}
+
+/// Delay recording waits other than the nCS framing ones
+struct RecDelay(Log);
+
+impl DelayUs<u32> for RecDelay {
+    fn delay_us(&mut self, us: u32) {
+        if ![20, 40].contains(&us) {
+            self.0.borrow_mut().push(Event::Delay(us));
+        }
+    }
+}
+
+fn rdata() -> SpiTransaction {
+    let mut tx = vec![0x00; 16];
+    tx[0] = 0x12;
+    let mut rx = vec![0x00, 0xC0, 0x00, 0x00];
+    rx.extend([0x00, 0x00, 0x01].iter().cycle().take(12));
+    SpiTransaction::transfer(tx, rx)
+}
+
+#[test]
+fn convert_once() {
+    let log = Log::default();
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        // CONFIG4 and CONFIG1 unknown, read first
+        SpiTransaction::transfer(vec![0x37, 0x00, 0xA5], vec![0x00, 0x00, 0x02]),
+        SpiTransaction::write(vec![0x57, 0x00, 0x0A]),
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b1000_0101]),
+        SpiTransaction::write(vec![0x08]),
+        rdata(),
+        // CONFIG1 known from the write
+        SpiTransaction::write(vec![0x41, 0x00, 0b1000_0110]),
+        SpiTransaction::write(vec![0x08]),
+        rdata(),
+    ];
+    let spi = RecSpi {
+        mock: SpiMock::new(&expectations),
+        log:  log.clone(),
+    };
+    let mut ads = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(RecDelay(log.clone()))
+        .unwrap();
+
+    let mut frame = DataFrame::<4>::new();
+    ads.convert_once(&mut frame, RecDelay(log.clone())).unwrap();
+    assert_eq!(frame.data, [1; 4]);
+
+    let config = Config {
+        mode: Mode::HighResolution(SampleRateHR::Sps500),
+        ..Default::default()
+    };
+    ads.set_config(config, RecDelay(log.clone())).unwrap();
+    ads.convert_once(&mut frame, RecDelay(log.clone())).unwrap();
+
+    assert_eq!(log.borrow()[..], [
+        Event::Spi(0x11),
+        Event::Spi(0x37),
+        Event::Spi(0x57),
+        Event::Spi(0x21),
+        Event::Spi(0x08),
+        // HR 1 kSPS
+        Event::Delay(timing::settling_time_us(1_000)),
+        Event::Spi(0x12),
+        Event::Spi(0x41),
+        Event::Spi(0x08),
+        Event::Delay(timing::settling_time_us(500)),
+        Event::Spi(0x12),
+    ]);
+
+    let (mut spi, _) = ads.destroy();
+    spi.mock.done();
+}
+
+#[test]
+fn convert_once_when_ready() {
+    let log = Log::default();
+    let expectations = [
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::write(vec![0x57, 0x00, 0x08]),
+        // CONFIG1 unknown, read for the DRDY timeout
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b1000_0101]),
+        SpiTransaction::write(vec![0x08]),
+        rdata(),
+        // DRDY stays high
+        SpiTransaction::transfer(vec![0x21, 0x00, 0xA5], vec![0x00, 0x00, 0b1000_0101]),
+        SpiTransaction::write(vec![0x08]),
+    ];
+    let spi = RecSpi {
+        mock: SpiMock::new(&expectations),
+        log:  log.clone(),
+    };
+    let mut ads = Ads129x::new_ads1294(spi, MockNcs)
+        .set_command_mode(RecDelay(log.clone()))
+        .unwrap();
+
+    // CONFIG4 known from the write
+    let misc = MiscConfig {
+        single_shot_mode: true,
+        ..Default::default()
+    };
+    ads.set_misc_config(misc, RecDelay(log.clone())).unwrap();
+
+    let mut frame = DataFrame::<4>::new();
+    ads.convert_once_when_ready(&Drdy(log.clone()), &mut frame, RecDelay(log.clone()))
+        .unwrap();
+    assert_eq!(log.borrow()[2..], [
+        Event::Spi(0x21),
+        Event::Spi(0x08),
+        Event::Drdy,
+        Event::Spi(0x12)
+    ]);
+
+    // HR 1 kSPS
+    assert!(matches!(
+        ads.convert_once_when_ready(&StuckDrdy, &mut frame, RecDelay(log.clone())),
+        Err(ads129x::Ads129xError::Timeout)
+    ));
+    let polls = log.borrow()[8..]
+        .iter()
+        .filter(|e| **e == Event::Delay(timing::DRDY_POLL_US))
+        .count() as u32;
+    assert_eq!(
+        polls,
+        timing::settling_time_us(1_000).div_ceil(timing::DRDY_POLL_US)
+    );
+
+    let (mut spi, _) = ads.destroy();
+    spi.mock.done();
+}
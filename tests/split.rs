@@ -0,0 +1,112 @@
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+use ads129x::ads1298::Register;
+use ads129x::data::DataFrame;
+use ads129x::spi::DelayRef;
+use ads129x::split::SharedDevice;
+use ads129x::{Ads129x, Ads129xError};
+
+struct MockNcs;
+
+impl OutputPin for MockNcs {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+struct MockDelay;
+
+impl DelayUs<u32> for MockDelay {
+    fn delay_us(&mut self, _us: u32) {}
+}
+
+fn frame(sample: u8) -> SpiTransaction {
+    let mut rx = vec![0x00; 15];
+    rx[0] = 0xC0;
+    rx[5] = sample;
+    SpiTransaction::transfer(vec![0x00; 15], rx)
+}
+
+#[test]
+fn interleaved_access() {
+    let expectations = [
+        frame(0x01),
+        // Lead-off status read between two frames
+        SpiTransaction::write(vec![0x11]),
+        SpiTransaction::transfer(vec![0x32, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0001]),
+        SpiTransaction::transfer(vec![0x33, 0x00, 0xA5], vec![0x00, 0x00, 0b0000_0000]),
+        SpiTransaction::write(vec![0x10]),
+        frame(0x02),
+    ];
+
+    let spi = SpiMock::new(&expectations);
+    let device = SharedDevice::new();
+    let (mut reader, mut port) = Ads129x::new_ads1294(spi, MockNcs)
+        .split(&device)
+        .ok()
+        .unwrap();
+
+    // DRDY interrupt
+    let mut data_frame = DataFrame::<4>::new();
+    reader.read_data(&mut data_frame, MockDelay).unwrap();
+    assert_eq!(data_frame.data, [1, 0, 0, 0]);
+
+    // Configuring task, a frame read during the access finds the bus taken
+    let (positive, negative) = port
+        .access(MockDelay, |ads, delay| {
+            let positive = ads.read_register(Register::LOFF_STATP, DelayRef(delay))?;
+            assert!(matches!(
+                reader.read_data(&mut data_frame, MockDelay),
+                Err(Ads129xError::BusBusy)
+            ));
+            let negative = ads.read_register(Register::LOFF_STATN, DelayRef(delay))?;
+            Ok((positive, negative))
+        })
+        .unwrap();
+    assert_eq!((positive, negative), (0b0000_0001, 0b0000_0000));
+
+    reader.read_data(&mut data_frame, MockDelay).unwrap();
+    assert_eq!(data_frame.data, [2, 0, 0, 0]);
+
+    let ads = port.join(reader).ok().unwrap();
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+}
+
+#[test]
+fn join_foreign_reader() {
+    let (first, second) = (SharedDevice::new(), SharedDevice::new());
+    let split = |device| {
+        Ads129x::new_ads1294(SpiMock::new(&[]), MockNcs)
+            .split(device)
+            .ok()
+            .unwrap()
+    };
+    let (reader_a, port_a) = split(&first);
+    let (reader_b, port_b) = split(&second);
+
+    // Occupied device
+    let ads = Ads129x::new_ads1294(SpiMock::new(&[]), MockNcs)
+        .split(&first)
+        .err()
+        .unwrap();
+    let (mut spi, _) = ads.destroy();
+    spi.done();
+
+    let (port_a, reader_b) = port_a.join(reader_b).err().unwrap();
+    for ads in [
+        port_a.join(reader_a).ok().unwrap(),
+        port_b.join(reader_b).ok().unwrap(),
+    ] {
+        let (mut spi, _) = ads.destroy();
+        spi.done();
+    }
+}
@@ -0,0 +1,109 @@
+use ads129x::common::ChannelGain;
+use ads129x::data::{ChannelStats, DataFrame};
+
+fn frame(data: [i32; 2]) -> DataFrame<2> {
+    let mut frame = DataFrame::<2>::new();
+    frame.data = data;
+    frame
+}
+
+#[test]
+fn empty() {
+    let stats = ChannelStats::<2>::new();
+    assert_eq!(stats.count(), 0);
+    assert_eq!(stats.snapshot().count, 0);
+    assert_eq!(stats.snapshot().min, [0, 0]);
+    assert_eq!(stats.snapshot().rms, [0, 0]);
+}
+
+#[test]
+fn constant() {
+    let mut stats = ChannelStats::<2>::new();
+    for _ in 0..100 {
+        stats.update(&frame([1_000, -8_388_608]));
+    }
+
+    let report = stats.snapshot();
+    assert_eq!(report.count, 100);
+    assert_eq!(report.mean, [1_000, -8_388_608]);
+    assert_eq!(report.min, [1_000, -8_388_608]);
+    assert_eq!(report.max, [1_000, -8_388_608]);
+    assert_eq!(report.rms, [1_000, 8_388_608]);
+}
+
+#[test]
+fn ramp() {
+    let mut stats = ChannelStats::<2>::new();
+    // 0..=100 and -100..=0
+    for i in 0..=100 {
+        stats.update(&frame([i, i - 100]));
+    }
+
+    let report = stats.snapshot();
+    assert_eq!(report.mean, [50, -50]);
+    assert_eq!(report.min, [0, -100]);
+    assert_eq!(report.max, [100, 0]);
+    // sqrt(sum(i^2) / 101) = sqrt(3350)
+    assert_eq!(report.rms, [57, 57]);
+
+    stats.reset();
+    assert_eq!(stats.count(), 0);
+    assert_eq!(stats.snapshot().max, [0, 0]);
+}
+
+#[test]
+fn alternating() {
+    let mut stats = ChannelStats::<2>::new();
+    for i in 0..1000 {
+        let sign = if i % 2 == 0 { 1 } else { -1 };
+        stats.update(&frame([sign * 8_388_607, sign * 3 + 1]));
+    }
+
+    let report = stats.snapshot();
+    assert_eq!(report.mean, [0, 1]);
+    assert_eq!(report.min, [-8_388_607, -2]);
+    assert_eq!(report.max, [8_388_607, 4]);
+    // sqrt((16 + 4) / 2)
+    assert_eq!(report.rms, [8_388_607, 3]);
+}
+
+#[test]
+fn full_scale_no_overflow() {
+    let mut stats = ChannelStats::<2>::new();
+    for _ in 0..(1 << 20) {
+        stats.update(&frame([-8_388_608, 8_388_607]));
+    }
+
+    let report = stats.snapshot();
+    assert_eq!(report.mean, [-8_388_608, 8_388_607]);
+    assert_eq!(report.rms, [8_388_608, 8_388_607]);
+}
+
+#[test]
+fn microvolts() {
+    let mut stats = ChannelStats::<2>::new();
+    stats.update(&frame([0x10_0000, -0x10_0000]));
+
+    // 2.4 V reference: 1/16 of full scale is 300 mV at gain 1, 50 mV at gain 6
+    let report = stats
+        .snapshot()
+        .to_microvolts(2_400_000, &[ChannelGain::X1, ChannelGain::X6]);
+    assert_eq!(report.mean, [300_000.0, -50_000.0]);
+    assert_eq!(report.rms, [300_000.0, 50_000.0]);
+}
+
+#[test]
+fn std_dev() {
+    let mut stats = ChannelStats::<2>::new();
+    assert_eq!(stats.std_dev(), [0, 0]);
+
+    // 10k full scale frames, as at 32 kSPS
+    for i in 0..10_000 {
+        if i % 2 == 0 {
+            stats.update(&frame([8_388_607, 1_003]));
+        } else {
+            stats.update(&frame([-8_388_608, 997]));
+        }
+    }
+    assert_eq!(stats.std_dev(), [8_388_607, 3]);
+}
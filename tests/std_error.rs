@@ -0,0 +1,74 @@
+#![cfg(feature = "std")]
+
+use std::error::Error;
+use std::fmt;
+
+use ads129x::builder::{BuildError, InitStep};
+use ads129x::common::id::IdRegError;
+use ads129x::data::FrameError;
+use ads129x::pins::PinError;
+use ads129x::timing::SclkParams;
+use ads129x::Ads129xError;
+
+#[derive(Debug)]
+struct BusError;
+
+impl fmt::Display for BusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bus fault")
+    }
+}
+
+impl Error for BusError {}
+
+fn fails(err: Ads129xError<BusError>) -> Result<(), Box<dyn Error>> {
+    Err(err)?;
+    Ok(())
+}
+
+#[test]
+fn boxed_errors() {
+    let err = fails(Ads129xError::Spi(BusError)).unwrap_err();
+    assert_eq!(err.to_string(), "SPI error");
+    let source = err.source().unwrap();
+    assert!(source.downcast_ref::<BusError>().is_some());
+    assert_eq!(source.to_string(), "bus fault");
+
+    let err = fails(Ads129xError::IdRegRead(IdRegError::Unsupported(0x94))).unwrap_err();
+    assert!(err.source().unwrap().is::<IdRegError>());
+    assert!(fails(Ads129xError::Timeout).unwrap_err().source().is_none());
+
+    let err: Box<dyn Error> = Box::new(BuildError {
+        step:  InitStep::Reset,
+        error: Ads129xError::Spi(BusError),
+    });
+    let mut chain = vec![err.to_string()];
+    let mut source = err.source();
+    while let Some(e) = source {
+        chain.push(e.to_string());
+        source = e.source();
+    }
+    assert_eq!(chain, [
+        "Reset step failed: SPI error",
+        "SPI error",
+        "bus fault"
+    ]);
+
+    let errors: [Box<dyn Error>; 3] = [
+        FrameError::Sync(0x3).into(),
+        PinError.into(),
+        SclkParams {
+            fclk_hz:       2_048_000,
+            data_rate_sps: 500,
+            channels:      8,
+            daisy_depth:   1,
+        }
+        .validate(100_000_000)
+        .unwrap_err()
+        .into(),
+    ];
+    assert_eq!(errors[1].to_string(), "pin failed");
+    assert!(errors[2]
+        .to_string()
+        .starts_with("SCLK 100000000 Hz too fast"));
+}
@@ -0,0 +1,87 @@
+use ads129x::ads1298::conf::*;
+use ads129x::timing::{SclkError, SclkParams, FCLK_NOMINAL_HZ};
+
+#[test]
+fn sclk_range() {
+    // (data rate, channels, daisy depth, (min, max))
+    let table = [
+        (500, 8, 1, (108_106, 20_000_000)),
+        (32_000, 8, 1, (7_372_800, 20_000_000)),
+        (250, 4, 2, (60_030, 20_000_000)),
+        (1_000, 2, 1, (72_141, 20_000_000)),
+    ];
+
+    for (data_rate_sps, channels, daisy_depth, range) in table.iter() {
+        let params = SclkParams {
+            fclk_hz:       FCLK_NOMINAL_HZ,
+            data_rate_sps: *data_rate_sps,
+            channels:      *channels,
+            daisy_depth:   *daisy_depth,
+        };
+        assert_eq!(params.sclk_range(), *range, "{:?}", params);
+    }
+}
+
+#[test]
+fn validate_sclk() {
+    let config = Config {
+        mode: Mode::HighResolution(SampleRateHR::Sps500),
+        daisy_chain: false,
+        ..Default::default()
+    };
+
+    // (sclk, result)
+    let table = [
+        (1_000_000, Ok(())),
+        // Above 2 fCLK, fine for the readback
+        (5_000_000, Ok(())),
+        (
+            100_000,
+            Err(SclkError::TooSlow {
+                sclk_hz: 100_000,
+                min_hz:  108_106,
+                max_hz:  20_000_000,
+            }),
+        ),
+        (
+            25_000_000,
+            Err(SclkError::TooFast {
+                sclk_hz: 25_000_000,
+                min_hz:  108_106,
+                max_hz:  20_000_000,
+            }),
+        ),
+    ];
+
+    for (sclk_hz, res) in table.iter() {
+        // Daisy depth ignored in multiple readback mode
+        assert_eq!(config.validate_sclk(*sclk_hz, FCLK_NOMINAL_HZ, 8, 3), *res);
+    }
+
+    // 32 kSPS with 8 channels needs more than 2 fCLK, reachable for the
+    // readback only
+    let config = Config {
+        mode: Mode::HighResolution(SampleRateHR::KSps32),
+        ..Default::default()
+    };
+    assert_eq!(
+        config.validate_sclk(8_000_000, FCLK_NOMINAL_HZ, 8, 1),
+        Ok(())
+    );
+    assert!(matches!(
+        config.validate_sclk(4_000_000, FCLK_NOMINAL_HZ, 8, 1),
+        Err(SclkError::TooSlow { .. })
+    ));
+}
+
+#[test]
+fn command_sclk_max() {
+    let params = |fclk_hz| SclkParams {
+        fclk_hz,
+        data_rate_sps: 500,
+        channels: 8,
+        daisy_depth: 1,
+    };
+    assert_eq!(params(FCLK_NOMINAL_HZ).command_sclk_max_hz(), 4_096_000);
+    assert_eq!(params(16_000_000).command_sclk_max_hz(), 20_000_000);
+}
@@ -0,0 +1,81 @@
+use std::collections::VecDeque;
+
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::blocking::spi::{Transfer, Write};
+use embedded_hal::digital::v2::OutputPin;
+
+use ads129x::common::id::DevModel;
+use ads129x::data::DataFrame92;
+use ads129x::Ads129x;
+
+/// Bus with the blocking traits only, like `linux_embedded_hal::Spidev`
+#[derive(Default)]
+struct Spidev {
+    written: Vec<u8>,
+    replies: VecDeque<Vec<u8>>,
+}
+
+impl Write<u8> for Spidev {
+    type Error = core::convert::Infallible;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        self.written.extend_from_slice(words);
+        Ok(())
+    }
+}
+
+impl Transfer<u8> for Spidev {
+    type Error = core::convert::Infallible;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        self.written.extend_from_slice(words);
+        words.copy_from_slice(&self.replies.pop_front().unwrap());
+        Ok(words)
+    }
+}
+
+struct MockNcs;
+
+impl OutputPin for MockNcs {
+    type Error = core::convert::Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+struct MockDelay;
+
+impl DelayUs<u32> for MockDelay {
+    fn delay_us(&mut self, _us: u32) {}
+}
+
+#[test]
+fn transfer_and_write_only() {
+    let mut frame = vec![0x00; 9];
+    frame[0] = 0xC0;
+    frame[5] = 0x01;
+    let spi = Spidev {
+        replies: vec![vec![0x00, 0x00, 0x53], frame].into(),
+        ..Default::default()
+    };
+
+    let ads1292 = Ads129x::new_ads1292(spi, MockNcs);
+    let mut ads1292 = ads1292.set_command_mode(MockDelay).unwrap();
+    assert_eq!(ads1292.read_id(MockDelay).unwrap(), DevModel::Ads1292);
+
+    let mut ads1292 = ads1292.set_continuous_mode(MockDelay).unwrap();
+    let mut data_frame = DataFrame92::new();
+    ads1292.read_data(&mut data_frame, MockDelay).unwrap();
+    assert_eq!(data_frame.data, [1, 0]);
+
+    let (spi, _) = ads1292.destroy();
+    let mut written = vec![0x11, 0x20, 0x00, 0xA5, 0x10];
+    written.extend_from_slice(&[0x00; 9]);
+    assert_eq!(spi.written, written);
+    assert!(spi.replies.is_empty());
+}
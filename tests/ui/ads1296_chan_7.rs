@@ -0,0 +1,25 @@
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::blocking::spi::{Transfer, Write};
+use embedded_hal::digital::v2::OutputPin;
+
+use ads129x::ads1298::chan::Chan;
+use ads129x::spi::DelayRef;
+use ads129x::Ads129x;
+
+fn configure<SPI, NCS, E, D>(spi: SPI, ncs: NCS, mut delay: D)
+where
+    SPI: Write<u8, Error = E> + Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    E: core::fmt::Debug,
+    D: DelayUs<u32>,
+{
+    let mut ads = Ads129x::new_ads1296(spi, ncs)
+        .set_command_mode(DelayRef(&mut delay))
+        .unwrap();
+    ads.set_chan_4(Chan::default(), DelayRef(&mut delay))
+        .unwrap();
+    ads.set_chan_7(Chan::default(), DelayRef(&mut delay))
+        .unwrap();
+}
+
+fn main() {}